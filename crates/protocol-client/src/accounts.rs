@@ -0,0 +1,55 @@
+use anchor_lang::{AccountDeserialize, Result};
+use protocol::state::contest::TokenDraftContest;
+use protocol::state::credit::TokenDraftContestCredits;
+use protocol::state::entry::TokenDraftContestEntry;
+use protocol::state::metadata::ContestMetadata;
+
+// Decodes raw account data as fetched over RPC, checking the account discriminator
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_contest_metadata(data: &[u8]) -> Result<ContestMetadata> {
+    decode(data)
+}
+
+pub fn decode_token_draft_contest(data: &[u8]) -> Result<TokenDraftContest> {
+    decode(data)
+}
+
+pub fn decode_token_draft_contest_entry(data: &[u8]) -> Result<TokenDraftContestEntry> {
+    decode(data)
+}
+
+pub fn decode_token_draft_contest_credits(data: &[u8]) -> Result<TokenDraftContestCredits> {
+    decode(data)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreditRow {
+    pub segments: Vec<Vec<i8>>,
+    pub captain: Option<u8>,
+    pub team_id: Option<u32>,
+}
+
+// Splits the flat credits account into one row per entry, in entry id order
+pub fn decode_credit_rows(
+    contest: &TokenDraftContest,
+    credits: &TokenDraftContestCredits,
+) -> Vec<CreditRow> {
+    let num_tokens = contest.token_feed_ids.len();
+    let num_segments = contest.num_segments();
+    credits
+        .credit_allocations
+        .chunks(contest.credit_row_len())
+        .enumerate()
+        .map(|(i, row)| {
+            let (allocs, captain) = row.split_at(num_tokens * num_segments);
+            CreditRow {
+                segments: allocs.chunks(num_tokens).map(|v| v.to_vec()).collect(),
+                captain: captain.first().map(|&v| v as u8),
+                team_id: credits.entry_teams.get(i).copied(),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,1193 @@
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use ephemeral_rollups_sdk::consts::{MAGIC_CONTEXT_ID, MAGIC_PROGRAM_ID};
+use ephemeral_rollups_sdk::pda::{
+    delegate_buffer_pda_from_delegated_account_and_owner_program,
+    delegation_metadata_pda_from_delegated_account, delegation_record_pda_from_delegated_account,
+};
+use protocol::accounts as ix_accounts;
+use protocol::instruction as ix;
+
+use crate::pda;
+
+// Price update accounts are appended as read-only remaining accounts, in the same order as the
+// contest's token feed ids
+fn build(
+    accounts: impl ToAccountMetas,
+    args: impl InstructionData,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let mut metas = accounts.to_account_metas(None);
+    metas.extend(
+        feed_accounts
+            .iter()
+            .map(|&feed_account| AccountMeta::new_readonly(feed_account, false)),
+    );
+    Instruction {
+        program_id: protocol::ID,
+        accounts: metas,
+        data: args.data(),
+    }
+}
+
+pub fn init_config(signer: &Pubkey, mint: &Pubkey, args: ix::InitConfig) -> Instruction {
+    let accounts = ix_accounts::InitConfigs {
+        signer: *signer,
+        config: pda::config(),
+        contest_metadata: pda::contest_metadata(),
+        mint: *mint,
+        system_program: system_program::ID,
+    };
+    build(accounts, args, &[])
+}
+
+pub fn init_token_accounts(signer: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Instruction {
+    let accounts = ix_accounts::InitTokenAccounts {
+        signer: *signer,
+        config: pda::config(),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::InitTokenAccounts {}, &[])
+}
+
+pub fn init_feed_registry(signer: &Pubkey, args: ix::InitFeedRegistry) -> Instruction {
+    let accounts = ix_accounts::InitFeedRegistry {
+        signer: *signer,
+        config: pda::config(),
+        feed_registry: pda::feed_registry(),
+        system_program: system_program::ID,
+    };
+    build(accounts, args, &[])
+}
+
+pub fn set_feed_registry_curator(signer: &Pubkey, args: ix::SetFeedRegistryCurator) -> Instruction {
+    let accounts = ix_accounts::SetFeedRegistryCurator {
+        signer: *signer,
+        config: pda::config(),
+        feed_registry: pda::feed_registry(),
+    };
+    build(accounts, args, &[])
+}
+
+pub fn add_registry_feed(signer: &Pubkey, args: ix::AddRegistryFeed) -> Instruction {
+    let accounts = ix_accounts::AddRegistryFeed {
+        signer: *signer,
+        feed_registry: pda::feed_registry(),
+        system_program: system_program::ID,
+    };
+    build(accounts, args, &[])
+}
+
+pub fn update_registry_feed(signer: &Pubkey, args: ix::UpdateRegistryFeed) -> Instruction {
+    let accounts = ix_accounts::UpdateRegistryFeed {
+        signer: *signer,
+        feed_registry: pda::feed_registry(),
+    };
+    build(accounts, args, &[])
+}
+
+// The contest id is the token draft contest count read from the metadata account
+pub fn create_token_draft_contest(
+    signer: &Pubkey,
+    contest_id: u64,
+    feed_accounts: &[Pubkey],
+    args: ix::CreateTokenDraftContest,
+) -> Instruction {
+    let contest = pda::token_draft_contest(contest_id, signer);
+    let accounts = ix_accounts::CreateTokenDraftContest {
+        signer: *signer,
+        contest_metadata: pda::contest_metadata(),
+        contest,
+        contest_credits: pda::token_draft_contest_credits(&contest),
+        feed_registry: pda::feed_registry(),
+        system_program: system_program::ID,
+    };
+    build(accounts, args, feed_accounts)
+}
+
+pub fn enter_token_draft_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+    team: Option<Pubkey>,
+    invite_signer: Option<Pubkey>,
+    args: ix::EnterTokenDraftContest,
+) -> Instruction {
+    let accounts = ix_accounts::EnterTokenDraftContest {
+        signer: *signer,
+        config: pda::config(),
+        contest: *contest,
+        contest_entry: pda::token_draft_contest_entry(contest, signer),
+        contest_credits: pda::token_draft_contest_credits(contest),
+        team,
+        invite_signer,
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, args, &[])
+}
+
+pub fn post_token_draft_contest_prices(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let accounts = ix_accounts::PostTokenDraftContestPrices {
+        signer: *signer,
+        contest: *contest,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::PostTokenDraftContestPrices {}, feed_accounts)
+}
+
+pub fn resolve_token_draft_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let accounts = ix_accounts::ResolveTokenDraftContest {
+        signer: *signer,
+        contest_metadata: pda::contest_metadata(),
+        contest: *contest,
+        contest_credits: pda::token_draft_contest_credits(contest),
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::ResolveTokenDraftContest {}, feed_accounts)
+}
+
+pub fn claim_token_draft_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+    team: Option<Pubkey>,
+) -> Instruction {
+    let accounts = ix_accounts::ClaimTokenDraftContest {
+        signer: *signer,
+        config: pda::config(),
+        contest: *contest,
+        contest_metadata: pda::contest_metadata(),
+        contest_entry: pda::token_draft_contest_entry(contest, signer),
+        team,
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::ClaimTokenDraftContest {}, &[])
+}
+
+// Entries can be closed by anyone on behalf of the entry's user, the rent returns to the user
+pub fn close_token_draft_contest_entry(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    user: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::CloseTokenDraftContestEntry {
+        signer: *signer,
+        user: *user,
+        contest: *contest,
+        contest_credits: pda::token_draft_contest_credits(contest),
+        contest_entry: pda::token_draft_contest_entry(contest, user),
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::CloseTokenDraftContestEntry {}, &[])
+}
+
+pub fn close_token_draft_contest(signer: &Pubkey, contest: &Pubkey) -> Instruction {
+    let accounts = ix_accounts::CloseTokenDraftContest {
+        signer: *signer,
+        contest: *contest,
+        contest_credits: pda::token_draft_contest_credits(contest),
+    };
+    build(accounts, ix::CloseTokenDraftContest {}, &[])
+}
+
+pub fn close_team(signer: &Pubkey, contest: &Pubkey) -> Instruction {
+    let accounts = ix_accounts::CloseTeam {
+        signer: *signer,
+        contest: *contest,
+        team: pda::team(contest, signer),
+    };
+    build(accounts, ix::CloseTeam {}, &[])
+}
+
+// One claim per contest, team is required for team contests only
+pub struct ContestClaim {
+    pub contest: Pubkey,
+    pub team: Option<Pubkey>,
+}
+
+pub fn claim_many_token_draft_contests(
+    signer: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+    claims: &[ContestClaim],
+) -> Instruction {
+    let accounts = ix_accounts::ClaimManyTokenDraftContests {
+        signer: *signer,
+        config: pda::config(),
+        contest_metadata: pda::contest_metadata(),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+    };
+    let mut ix = build(accounts, ix::ClaimManyTokenDraftContests {}, &[]);
+    for claim in claims {
+        let entry = pda::token_draft_contest_entry(&claim.contest, signer);
+        ix.accounts.push(AccountMeta::new(claim.contest, false));
+        ix.accounts.push(AccountMeta::new(entry, false));
+        if let Some(team) = claim.team {
+            ix.accounts.push(AccountMeta::new_readonly(team, false));
+        }
+    }
+    ix
+}
+
+// Accounts paid by the distribute crank, team is required for team contests only
+pub struct WinnerPayout {
+    pub user: Pubkey,
+    pub team: Option<Pubkey>,
+    pub token_account: Pubkey,
+}
+
+pub fn distribute_token_draft_contest_rewards(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+    winners: &[WinnerPayout],
+) -> Instruction {
+    let accounts = ix_accounts::DistributeTokenDraftContestRewards {
+        signer: *signer,
+        contest: *contest,
+        contest_metadata: pda::contest_metadata(),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+    };
+    let mut ix = build(accounts, ix::DistributeTokenDraftContestRewards {}, &[]);
+    for winner in winners {
+        let entry = pda::token_draft_contest_entry(contest, &winner.user);
+        ix.accounts.push(AccountMeta::new(entry, false));
+        if let Some(team) = winner.team {
+            ix.accounts.push(AccountMeta::new_readonly(team, false));
+        }
+        ix.accounts
+            .push(AccountMeta::new(winner.token_account, false));
+    }
+    ix
+}
+
+pub fn cancel_token_draft_contest(signer: &Pubkey, contest: &Pubkey) -> Instruction {
+    let accounts = ix_accounts::CancelTokenDraftContest {
+        signer: *signer,
+        contest: *contest,
+    };
+    build(accounts, ix::CancelTokenDraftContest {}, &[])
+}
+
+pub fn refund_token_draft_contest_entry(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::RefundTokenDraftContestEntry {
+        signer: *signer,
+        config: pda::config(),
+        contest: *contest,
+        contest_entry: pda::token_draft_contest_entry(contest, signer),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+    };
+    build(accounts, ix::RefundTokenDraftContestEntry {}, &[])
+}
+
+// Token accounts must belong to the contest's price poster and resolver
+pub fn pay_token_draft_contest_keeper_bounties(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    price_poster_token_account: &Pubkey,
+    resolver_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::PayTokenDraftContestKeeperBounties {
+        signer: *signer,
+        contest: *contest,
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        price_poster_token_account: *price_poster_token_account,
+        resolver_token_account: *resolver_token_account,
+        token_program: *token_program,
+    };
+    build(accounts, ix::PayTokenDraftContestKeeperBounties {}, &[])
+}
+
+// Season is required when the contest belongs to one
+pub fn sweep_token_draft_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    season: Option<Pubkey>,
+) -> Instruction {
+    let accounts = ix_accounts::SweepTokenDraftContest {
+        signer: *signer,
+        contest: *contest,
+        contest_metadata: pda::contest_metadata(),
+        season,
+    };
+    build(accounts, ix::SweepTokenDraftContest {}, &[])
+}
+
+pub fn create_head_to_head_contest(
+    signer: &Pubkey,
+    contest_id: u64,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+    feed_accounts: &[Pubkey],
+    args: ix::CreateHeadToHeadContest,
+) -> Instruction {
+    let accounts = ix_accounts::CreateHeadToHeadContest {
+        signer: *signer,
+        config: pda::config(),
+        contest_metadata: pda::contest_metadata(),
+        contest: pda::head_to_head_contest(contest_id, signer),
+        feed_registry: pda::feed_registry(),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, args, feed_accounts)
+}
+
+pub fn accept_head_to_head_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+    args: ix::AcceptHeadToHeadContest,
+) -> Instruction {
+    let accounts = ix_accounts::AcceptHeadToHeadContest {
+        signer: *signer,
+        config: pda::config(),
+        contest: *contest,
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, args, &[])
+}
+
+pub fn cancel_head_to_head_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::CancelHeadToHeadContest {
+        signer: *signer,
+        config: pda::config(),
+        contest: *contest,
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::CancelHeadToHeadContest {}, &[])
+}
+
+pub fn refund_head_to_head_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::RefundHeadToHeadContest {
+        signer: *signer,
+        config: pda::config(),
+        contest: *contest,
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+    };
+    build(accounts, ix::RefundHeadToHeadContest {}, &[])
+}
+
+pub fn post_head_to_head_contest_prices(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let accounts = ix_accounts::PostHeadToHeadContestPrices {
+        signer: *signer,
+        contest: *contest,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::PostHeadToHeadContestPrices {}, feed_accounts)
+}
+
+pub fn resolve_head_to_head_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let accounts = ix_accounts::ResolveHeadToHeadContest {
+        signer: *signer,
+        contest_metadata: pda::contest_metadata(),
+        contest: *contest,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::ResolveHeadToHeadContest {}, feed_accounts)
+}
+
+pub fn claim_head_to_head_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::ClaimHeadToHeadContest {
+        signer: *signer,
+        config: pda::config(),
+        contest_metadata: pda::contest_metadata(),
+        contest: *contest,
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::ClaimHeadToHeadContest {}, &[])
+}
+
+pub fn create_over_under_contest(
+    signer: &Pubkey,
+    contest_id: u64,
+    feed_accounts: &[Pubkey],
+    args: ix::CreateOverUnderContest,
+) -> Instruction {
+    let accounts = ix_accounts::CreateOverUnderContest {
+        signer: *signer,
+        contest_metadata: pda::contest_metadata(),
+        contest: pda::over_under_contest(contest_id, signer),
+        feed_registry: pda::feed_registry(),
+        system_program: system_program::ID,
+    };
+    build(accounts, args, feed_accounts)
+}
+
+pub fn enter_over_under_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+    args: ix::EnterOverUnderContest,
+) -> Instruction {
+    let accounts = ix_accounts::EnterOverUnderContest {
+        signer: *signer,
+        config: pda::config(),
+        contest: *contest,
+        contest_entry: pda::over_under_entry(contest, signer),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, args, &[])
+}
+
+pub fn resolve_over_under_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let accounts = ix_accounts::ResolveOverUnderContest {
+        signer: *signer,
+        contest_metadata: pda::contest_metadata(),
+        contest: *contest,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::ResolveOverUnderContest {}, feed_accounts)
+}
+
+pub fn claim_over_under_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::ClaimOverUnderContest {
+        signer: *signer,
+        config: pda::config(),
+        contest_metadata: pda::contest_metadata(),
+        contest: *contest,
+        contest_entry: pda::over_under_entry(contest, signer),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::ClaimOverUnderContest {}, &[])
+}
+
+pub fn create_parimutuel_contest(
+    signer: &Pubkey,
+    contest_id: u64,
+    feed_accounts: &[Pubkey],
+    args: ix::CreateParimutuelContest,
+) -> Instruction {
+    let accounts = ix_accounts::CreateParimutuelContest {
+        signer: *signer,
+        contest_metadata: pda::contest_metadata(),
+        contest: pda::parimutuel_contest(contest_id, signer),
+        feed_registry: pda::feed_registry(),
+        system_program: system_program::ID,
+    };
+    build(accounts, args, feed_accounts)
+}
+
+pub fn enter_parimutuel_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+    args: ix::EnterParimutuelContest,
+) -> Instruction {
+    let accounts = ix_accounts::EnterParimutuelContest {
+        signer: *signer,
+        config: pda::config(),
+        contest: *contest,
+        contest_entry: pda::parimutuel_entry(contest, signer),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, args, &[])
+}
+
+pub fn post_parimutuel_contest_prices(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let accounts = ix_accounts::PostParimutuelContestPrices {
+        signer: *signer,
+        contest: *contest,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::PostParimutuelContestPrices {}, feed_accounts)
+}
+
+pub fn cancel_parimutuel_contest(signer: &Pubkey, contest: &Pubkey) -> Instruction {
+    let accounts = ix_accounts::CancelParimutuelContest {
+        signer: *signer,
+        contest: *contest,
+    };
+    build(accounts, ix::CancelParimutuelContest {}, &[])
+}
+
+pub fn resolve_parimutuel_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let accounts = ix_accounts::ResolveParimutuelContest {
+        signer: *signer,
+        contest_metadata: pda::contest_metadata(),
+        contest: *contest,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::ResolveParimutuelContest {}, feed_accounts)
+}
+
+pub fn claim_parimutuel_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::ClaimParimutuelContest {
+        signer: *signer,
+        config: pda::config(),
+        contest_metadata: pda::contest_metadata(),
+        contest: *contest,
+        contest_entry: pda::parimutuel_entry(contest, signer),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::ClaimParimutuelContest {}, &[])
+}
+
+pub fn create_range_contest(
+    signer: &Pubkey,
+    contest_id: u64,
+    feed_accounts: &[Pubkey],
+    args: ix::CreateRangeContest,
+) -> Instruction {
+    let contest = pda::range_contest(contest_id, signer);
+    let accounts = ix_accounts::CreateRangeContest {
+        signer: *signer,
+        contest_metadata: pda::contest_metadata(),
+        contest,
+        contest_predictions: pda::range_contest_predictions(&contest),
+        feed_registry: pda::feed_registry(),
+        system_program: system_program::ID,
+    };
+    build(accounts, args, feed_accounts)
+}
+
+pub fn enter_range_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+    args: ix::EnterRangeContest,
+) -> Instruction {
+    let accounts = ix_accounts::EnterRangeContest {
+        signer: *signer,
+        config: pda::config(),
+        contest: *contest,
+        contest_entry: pda::range_contest_entry(contest, signer),
+        contest_predictions: pda::range_contest_predictions(contest),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, args, &[])
+}
+
+pub fn post_range_contest_price(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let accounts = ix_accounts::PostRangeContestPrice {
+        signer: *signer,
+        contest: *contest,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::PostRangeContestPrice {}, feed_accounts)
+}
+
+pub fn resolve_range_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let accounts = ix_accounts::ResolveRangeContest {
+        signer: *signer,
+        contest_metadata: pda::contest_metadata(),
+        contest: *contest,
+        contest_predictions: pda::range_contest_predictions(contest),
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::ResolveRangeContest {}, feed_accounts)
+}
+
+pub fn claim_range_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::ClaimRangeContest {
+        signer: *signer,
+        config: pda::config(),
+        contest_metadata: pda::contest_metadata(),
+        contest: *contest,
+        contest_entry: pda::range_contest_entry(contest, signer),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::ClaimRangeContest {}, &[])
+}
+
+pub fn create_survivor_contest(
+    signer: &Pubkey,
+    contest_id: u64,
+    feed_accounts: &[Pubkey],
+    args: ix::CreateSurvivorContest,
+) -> Instruction {
+    let contest = pda::survivor_contest(contest_id, signer);
+    let accounts = ix_accounts::CreateSurvivorContest {
+        signer: *signer,
+        contest_metadata: pda::contest_metadata(),
+        contest,
+        contest_credits: pda::survivor_contest_credits(&contest),
+        feed_registry: pda::feed_registry(),
+        system_program: system_program::ID,
+    };
+    build(accounts, args, feed_accounts)
+}
+
+pub fn enter_survivor_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+    args: ix::EnterSurvivorContest,
+) -> Instruction {
+    let accounts = ix_accounts::EnterSurvivorContest {
+        signer: *signer,
+        config: pda::config(),
+        contest: *contest,
+        contest_entry: pda::survivor_contest_entry(contest, signer),
+        contest_credits: pda::survivor_contest_credits(contest),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, args, &[])
+}
+
+pub fn redraft_survivor_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    args: ix::RedraftSurvivorContest,
+) -> Instruction {
+    let accounts = ix_accounts::RedraftSurvivorContest {
+        signer: *signer,
+        contest: *contest,
+        contest_entry: pda::survivor_contest_entry(contest, signer),
+        contest_credits: pda::survivor_contest_credits(contest),
+        system_program: system_program::ID,
+    };
+    build(accounts, args, &[])
+}
+
+pub fn refund_survivor_contest_entry(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::RefundSurvivorContestEntry {
+        signer: *signer,
+        config: pda::config(),
+        contest: *contest,
+        contest_entry: pda::survivor_contest_entry(contest, signer),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+    };
+    build(accounts, ix::RefundSurvivorContestEntry {}, &[])
+}
+
+pub fn post_survivor_round_prices(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let accounts = ix_accounts::PostSurvivorRoundPrices {
+        signer: *signer,
+        contest: *contest,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::PostSurvivorRoundPrices {}, feed_accounts)
+}
+
+pub fn resolve_survivor_round(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let accounts = ix_accounts::ResolveSurvivorRound {
+        signer: *signer,
+        contest_metadata: pda::contest_metadata(),
+        contest: *contest,
+        contest_credits: pda::survivor_contest_credits(contest),
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::ResolveSurvivorRound {}, feed_accounts)
+}
+
+pub fn claim_survivor_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::ClaimSurvivorContest {
+        signer: *signer,
+        config: pda::config(),
+        contest_metadata: pda::contest_metadata(),
+        contest: *contest,
+        contest_entry: pda::survivor_contest_entry(contest, signer),
+        contest_credits: pda::survivor_contest_credits(contest),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::ClaimSurvivorContest {}, &[])
+}
+
+// The season id is the season count read from the metadata account
+pub fn create_season(signer: &Pubkey, season_id: u64, args: ix::CreateSeason) -> Instruction {
+    let accounts = ix_accounts::CreateSeason {
+        signer: *signer,
+        config: pda::config(),
+        contest_metadata: pda::contest_metadata(),
+        season: pda::season(season_id),
+        system_program: system_program::ID,
+    };
+    build(accounts, args, &[])
+}
+
+pub fn add_season_contest(signer: &Pubkey, season: &Pubkey, contest: &Pubkey) -> Instruction {
+    let accounts = ix_accounts::AddSeasonContest {
+        signer: *signer,
+        config: pda::config(),
+        season: *season,
+        contest: *contest,
+    };
+    build(accounts, ix::AddSeasonContest {}, &[])
+}
+
+pub fn settle_season_contest(signer: &Pubkey, season: &Pubkey, contest: &Pubkey) -> Instruction {
+    let accounts = ix_accounts::SettleSeasonContest {
+        signer: *signer,
+        season: *season,
+        contest: *contest,
+    };
+    build(accounts, ix::SettleSeasonContest {}, &[])
+}
+
+// Points can be awarded by anyone on behalf of the entry's user
+pub fn award_season_points(
+    signer: &Pubkey,
+    season: &Pubkey,
+    contest: &Pubkey,
+    user: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::AwardSeasonPoints {
+        signer: *signer,
+        season: *season,
+        contest: *contest,
+        contest_entry: pda::token_draft_contest_entry(contest, user),
+        season_standing: pda::season_standing(season, user),
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::AwardSeasonPoints {}, &[])
+}
+
+pub fn finalize_season(signer: &Pubkey, season: &Pubkey) -> Instruction {
+    let accounts = ix_accounts::FinalizeSeason {
+        signer: *signer,
+        config: pda::config(),
+        season: *season,
+    };
+    build(accounts, ix::FinalizeSeason {}, &[])
+}
+
+pub fn claim_season_prize(
+    signer: &Pubkey,
+    season: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::ClaimSeasonPrize {
+        signer: *signer,
+        config: pda::config(),
+        season: *season,
+        season_standing: pda::season_standing(season, signer),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::ClaimSeasonPrize {}, &[])
+}
+
+pub fn create_team(signer: &Pubkey, contest: &Pubkey, args: ix::CreateTeam) -> Instruction {
+    let accounts = ix_accounts::CreateTeam {
+        signer: *signer,
+        contest: *contest,
+        team: pda::team(contest, signer),
+        system_program: system_program::ID,
+    };
+    build(accounts, args, &[])
+}
+
+pub fn post_token_draft_contest_checkpoint(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let accounts = ix_accounts::PostTokenDraftContestCheckpoint {
+        signer: *signer,
+        contest: *contest,
+        system_program: system_program::ID,
+    };
+    build(
+        accounts,
+        ix::PostTokenDraftContestCheckpoint {},
+        feed_accounts,
+    )
+}
+
+pub fn rebalance_token_draft_contest_entry(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    args: ix::RebalanceTokenDraftContestEntry,
+) -> Instruction {
+    let accounts = ix_accounts::RebalanceTokenDraftContestEntry {
+        signer: *signer,
+        contest: *contest,
+        contest_entry: pda::token_draft_contest_entry(contest, signer),
+        contest_credits: pda::token_draft_contest_credits(contest),
+        system_program: system_program::ID,
+    };
+    build(accounts, args, &[])
+}
+
+pub fn create_snake_draft_contest(
+    signer: &Pubkey,
+    contest_id: u64,
+    feed_accounts: &[Pubkey],
+    args: ix::CreateSnakeDraftContest,
+) -> Instruction {
+    let accounts = ix_accounts::CreateSnakeDraftContest {
+        signer: *signer,
+        contest_metadata: pda::contest_metadata(),
+        contest: pda::snake_draft_contest(contest_id, signer),
+        feed_registry: pda::feed_registry(),
+        system_program: system_program::ID,
+    };
+    build(accounts, args, feed_accounts)
+}
+
+pub fn join_snake_draft_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::JoinSnakeDraftContest {
+        signer: *signer,
+        config: pda::config(),
+        contest: *contest,
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::JoinSnakeDraftContest {}, &[])
+}
+
+pub fn refund_snake_draft_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::RefundSnakeDraftContest {
+        signer: *signer,
+        config: pda::config(),
+        contest: *contest,
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+    };
+    build(accounts, ix::RefundSnakeDraftContest {}, &[])
+}
+
+pub fn pick_snake_draft_token(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    args: ix::PickSnakeDraftToken,
+) -> Instruction {
+    let accounts = ix_accounts::PickSnakeDraftToken {
+        signer: *signer,
+        contest: *contest,
+    };
+    build(accounts, args, &[])
+}
+
+pub fn auto_pick_snake_draft_token(signer: &Pubkey, contest: &Pubkey) -> Instruction {
+    let accounts = ix_accounts::AutoPickSnakeDraftToken {
+        signer: *signer,
+        contest: *contest,
+    };
+    build(accounts, ix::AutoPickSnakeDraftToken {}, &[])
+}
+
+pub fn post_snake_draft_contest_prices(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let accounts = ix_accounts::PostSnakeDraftContestPrices {
+        signer: *signer,
+        contest: *contest,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::PostSnakeDraftContestPrices {}, feed_accounts)
+}
+
+pub fn resolve_snake_draft_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let accounts = ix_accounts::ResolveSnakeDraftContest {
+        signer: *signer,
+        contest_metadata: pda::contest_metadata(),
+        contest: *contest,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::ResolveSnakeDraftContest {}, feed_accounts)
+}
+
+pub fn claim_snake_draft_contest(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    signer_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::ClaimSnakeDraftContest {
+        signer: *signer,
+        config: pda::config(),
+        contest_metadata: pda::contest_metadata(),
+        contest: *contest,
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        signer_token_account: *signer_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::ClaimSnakeDraftContest {}, &[])
+}
+
+pub fn withdraw_fee(
+    signer: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    withdrawal_token_account: &Pubkey,
+) -> Instruction {
+    let accounts = ix_accounts::WithdrawFee {
+        signer: *signer,
+        config: pda::config(),
+        contest_metadata: pda::contest_metadata(),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        withdrawal_token_account: *withdrawal_token_account,
+        token_program: *token_program,
+        system_program: system_program::ID,
+    };
+    build(accounts, ix::WithdrawFee {}, &[])
+}
+
+// Delegates the contest and the metadata account to the ephemeral rollup
+pub fn delegate_er(signer: &Pubkey, contest: &Pubkey) -> Instruction {
+    let contest_metadata = pda::contest_metadata();
+    let accounts = ix_accounts::DelegateEr {
+        signer: *signer,
+        buffer_contest_metadata: delegate_buffer_pda_from_delegated_account_and_owner_program(
+            &contest_metadata,
+            &protocol::ID,
+        ),
+        delegation_record_contest_metadata: delegation_record_pda_from_delegated_account(
+            &contest_metadata,
+        ),
+        delegation_metadata_contest_metadata: delegation_metadata_pda_from_delegated_account(
+            &contest_metadata,
+        ),
+        contest_metadata,
+        buffer_contest: delegate_buffer_pda_from_delegated_account_and_owner_program(
+            contest,
+            &protocol::ID,
+        ),
+        delegation_record_contest: delegation_record_pda_from_delegated_account(contest),
+        delegation_metadata_contest: delegation_metadata_pda_from_delegated_account(contest),
+        contest: *contest,
+        system_program: system_program::ID,
+        owner_program: protocol::ID,
+        delegation_program: ephemeral_rollups_sdk::id(),
+    };
+    build(accounts, ix::DelegateEr {}, &[])
+}
+
+pub fn resolve_token_draft_contest_er(
+    signer: &Pubkey,
+    contest: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    feed_accounts: &[Pubkey],
+) -> Instruction {
+    let accounts = ix_accounts::ResolveTokenDraftContestEr {
+        signer: *signer,
+        contest_metadata: pda::contest_metadata(),
+        contest: *contest,
+        contest_credits: pda::token_draft_contest_credits(contest),
+        mint: *mint,
+        program_token_account: pda::program_token_account(mint),
+        token_program: *token_program,
+        system_program: system_program::ID,
+        magic_program: MAGIC_PROGRAM_ID,
+        magic_context: MAGIC_CONTEXT_ID,
+    };
+    build(accounts, ix::ResolveTokenDraftContestEr {}, feed_accounts)
+}
@@ -0,0 +1,5 @@
+pub mod accounts;
+pub mod instructions;
+pub mod pda;
+
+pub use protocol::ID;
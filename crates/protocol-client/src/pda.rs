@@ -0,0 +1,118 @@
+use anchor_lang::prelude::Pubkey;
+use protocol::constants::seeds::{
+    SEED_CONFIG, SEED_CONTEST_METADATA, SEED_FEED_REGISTRY, SEED_HEAD_TO_HEAD_CONTEST,
+    SEED_OVER_UNDER_CONTEST, SEED_OVER_UNDER_ENTRY, SEED_PARIMUTUEL_CONTEST, SEED_PARIMUTUEL_ENTRY,
+    SEED_PROGRAM_TOKEN_ACCOUNT, SEED_RANGE_CONTEST, SEED_RANGE_CONTEST_ENTRY,
+    SEED_RANGE_CONTEST_PREDICTIONS, SEED_SEASON, SEED_SEASON_STANDING, SEED_SNAKE_DRAFT_CONTEST,
+    SEED_SURVIVOR_CONTEST, SEED_SURVIVOR_CONTEST_CREDITS, SEED_SURVIVOR_CONTEST_ENTRY, SEED_TEAM,
+    SEED_TOKEN_DRAFT_CONTEST, SEED_TOKEN_DRAFT_CONTEST_CREDITS, SEED_TOKEN_DRAFT_CONTEST_ENTRY,
+};
+
+fn find_pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &protocol::ID).0
+}
+
+pub fn config() -> Pubkey {
+    find_pda(&[SEED_CONFIG])
+}
+
+pub fn contest_metadata() -> Pubkey {
+    find_pda(&[SEED_CONTEST_METADATA])
+}
+
+pub fn feed_registry() -> Pubkey {
+    find_pda(&[SEED_FEED_REGISTRY])
+}
+
+pub fn program_token_account(mint: &Pubkey) -> Pubkey {
+    find_pda(&[SEED_PROGRAM_TOKEN_ACCOUNT, mint.as_ref()])
+}
+
+// Contests are keyed by the contest count in the metadata account at the time they were created
+pub fn token_draft_contest(id: u64, creator: &Pubkey) -> Pubkey {
+    find_pda(&[
+        SEED_TOKEN_DRAFT_CONTEST,
+        &id.to_le_bytes(),
+        creator.as_ref(),
+    ])
+}
+
+pub fn token_draft_contest_credits(contest: &Pubkey) -> Pubkey {
+    find_pda(&[SEED_TOKEN_DRAFT_CONTEST_CREDITS, contest.as_ref()])
+}
+
+pub fn token_draft_contest_entry(contest: &Pubkey, user: &Pubkey) -> Pubkey {
+    find_pda(&[
+        SEED_TOKEN_DRAFT_CONTEST_ENTRY,
+        contest.as_ref(),
+        user.as_ref(),
+    ])
+}
+
+pub fn head_to_head_contest(id: u64, challenger: &Pubkey) -> Pubkey {
+    find_pda(&[
+        SEED_HEAD_TO_HEAD_CONTEST,
+        &id.to_le_bytes(),
+        challenger.as_ref(),
+    ])
+}
+
+pub fn over_under_contest(id: u64, creator: &Pubkey) -> Pubkey {
+    find_pda(&[SEED_OVER_UNDER_CONTEST, &id.to_le_bytes(), creator.as_ref()])
+}
+
+pub fn over_under_entry(contest: &Pubkey, user: &Pubkey) -> Pubkey {
+    find_pda(&[SEED_OVER_UNDER_ENTRY, contest.as_ref(), user.as_ref()])
+}
+
+pub fn parimutuel_contest(id: u64, creator: &Pubkey) -> Pubkey {
+    find_pda(&[SEED_PARIMUTUEL_CONTEST, &id.to_le_bytes(), creator.as_ref()])
+}
+
+pub fn parimutuel_entry(contest: &Pubkey, user: &Pubkey) -> Pubkey {
+    find_pda(&[SEED_PARIMUTUEL_ENTRY, contest.as_ref(), user.as_ref()])
+}
+
+pub fn range_contest(id: u64, creator: &Pubkey) -> Pubkey {
+    find_pda(&[SEED_RANGE_CONTEST, &id.to_le_bytes(), creator.as_ref()])
+}
+
+pub fn range_contest_entry(contest: &Pubkey, user: &Pubkey) -> Pubkey {
+    find_pda(&[SEED_RANGE_CONTEST_ENTRY, contest.as_ref(), user.as_ref()])
+}
+
+pub fn range_contest_predictions(contest: &Pubkey) -> Pubkey {
+    find_pda(&[SEED_RANGE_CONTEST_PREDICTIONS, contest.as_ref()])
+}
+
+pub fn survivor_contest(id: u64, creator: &Pubkey) -> Pubkey {
+    find_pda(&[SEED_SURVIVOR_CONTEST, &id.to_le_bytes(), creator.as_ref()])
+}
+
+pub fn survivor_contest_entry(contest: &Pubkey, user: &Pubkey) -> Pubkey {
+    find_pda(&[SEED_SURVIVOR_CONTEST_ENTRY, contest.as_ref(), user.as_ref()])
+}
+
+pub fn survivor_contest_credits(contest: &Pubkey) -> Pubkey {
+    find_pda(&[SEED_SURVIVOR_CONTEST_CREDITS, contest.as_ref()])
+}
+
+pub fn season(id: u64) -> Pubkey {
+    find_pda(&[SEED_SEASON, &id.to_le_bytes()])
+}
+
+pub fn season_standing(season: &Pubkey, user: &Pubkey) -> Pubkey {
+    find_pda(&[SEED_SEASON_STANDING, season.as_ref(), user.as_ref()])
+}
+
+pub fn team(contest: &Pubkey, creator: &Pubkey) -> Pubkey {
+    find_pda(&[SEED_TEAM, contest.as_ref(), creator.as_ref()])
+}
+
+pub fn snake_draft_contest(id: u64, creator: &Pubkey) -> Pubkey {
+    find_pda(&[
+        SEED_SNAKE_DRAFT_CONTEST,
+        &id.to_le_bytes(),
+        creator.as_ref(),
+    ])
+}
@@ -0,0 +1,197 @@
+// Builders are checked against the account order, signer and writable flags of the program's
+// `#[derive(Accounts)]` structs, and against the discriminators the program dispatches on.
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use protocol::instruction as ix;
+use protocol_client::{instructions, pda};
+
+// (pubkey, is_signer, is_writable) for every account in order
+fn metas(instruction: &Instruction) -> Vec<(Pubkey, bool, bool)> {
+    instruction
+        .accounts
+        .iter()
+        .map(|meta| (meta.pubkey, meta.is_signer, meta.is_writable))
+        .collect()
+}
+
+fn discriminator(name: &str) -> [u8; 8] {
+    hash(format!("global:{name}").as_bytes()).to_bytes()[..8]
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn enter_token_draft_contest() {
+    let signer = Pubkey::new_unique();
+    let contest = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let signer_token_account = Pubkey::new_unique();
+    let token_program = anchor_spl::token_2022::ID;
+    let invite_signer = Pubkey::new_unique();
+
+    let instruction = instructions::enter_token_draft_contest(
+        &signer,
+        &contest,
+        &mint,
+        &token_program,
+        &signer_token_account,
+        None,
+        Some(invite_signer),
+        ix::EnterTokenDraftContest {
+            credit_allocation: vec![40, 60],
+            access_proof: vec![],
+            captain: None,
+        },
+    );
+
+    assert_eq!(instruction.program_id, protocol::ID);
+    assert_eq!(
+        instruction.data[..8],
+        discriminator("enter_token_draft_contest")
+    );
+
+    // Missing optional accounts are passed as the program id
+    assert_eq!(
+        metas(&instruction),
+        vec![
+            (signer, true, true),
+            (pda::config(), false, false),
+            (contest, false, true),
+            (
+                pda::token_draft_contest_entry(&contest, &signer),
+                false,
+                true
+            ),
+            (pda::token_draft_contest_credits(&contest), false, true),
+            (protocol::ID, false, false),
+            (invite_signer, true, false),
+            (mint, false, true),
+            (pda::program_token_account(&mint), false, true),
+            (signer_token_account, false, true),
+            (token_program, false, false),
+            (system_program::ID, false, false),
+        ]
+    );
+}
+
+#[test]
+fn claim_token_draft_contest() {
+    let signer = Pubkey::new_unique();
+    let contest = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let signer_token_account = Pubkey::new_unique();
+    let team = pda::team(&contest, &Pubkey::new_unique());
+    let token_program = anchor_spl::token::ID;
+
+    let instruction = instructions::claim_token_draft_contest(
+        &signer,
+        &contest,
+        &mint,
+        &token_program,
+        &signer_token_account,
+        Some(team),
+    );
+
+    assert_eq!(
+        instruction.data[..8],
+        discriminator("claim_token_draft_contest")
+    );
+    assert_eq!(
+        metas(&instruction),
+        vec![
+            (signer, true, true),
+            (pda::config(), false, false),
+            (contest, false, true),
+            (pda::contest_metadata(), false, true),
+            (
+                pda::token_draft_contest_entry(&contest, &signer),
+                false,
+                true
+            ),
+            (team, false, false),
+            (mint, false, true),
+            (pda::program_token_account(&mint), false, true),
+            (signer_token_account, false, true),
+            (token_program, false, false),
+            (system_program::ID, false, false),
+        ]
+    );
+}
+
+#[test]
+fn close_token_draft_contest_entry() {
+    let signer = Pubkey::new_unique();
+    let contest = Pubkey::new_unique();
+    let user = Pubkey::new_unique();
+
+    let instruction = instructions::close_token_draft_contest_entry(&signer, &contest, &user);
+
+    assert_eq!(
+        instruction.data[..8],
+        discriminator("close_token_draft_contest_entry")
+    );
+
+    // The entry is derived from the user it is closed for, not the signer
+    assert_eq!(
+        metas(&instruction),
+        vec![
+            (signer, true, false),
+            (user, false, true),
+            (contest, false, true),
+            (pda::token_draft_contest_credits(&contest), false, true),
+            (pda::token_draft_contest_entry(&contest, &user), false, true),
+            (system_program::ID, false, false),
+        ]
+    );
+}
+
+#[test]
+fn post_token_draft_contest_prices() {
+    let signer = Pubkey::new_unique();
+    let contest = Pubkey::new_unique();
+    let feed_accounts = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let instruction =
+        instructions::post_token_draft_contest_prices(&signer, &contest, &feed_accounts);
+
+    assert_eq!(
+        instruction.data[..8],
+        discriminator("post_token_draft_contest_prices")
+    );
+
+    // Price update accounts follow the struct accounts as read-only remaining accounts
+    assert_eq!(
+        metas(&instruction),
+        vec![
+            (signer, true, true),
+            (contest, false, true),
+            (system_program::ID, false, false),
+            (feed_accounts[0], false, false),
+            (feed_accounts[1], false, false),
+        ]
+    );
+}
+
+#[test]
+fn settle_season_contest() {
+    let signer = Pubkey::new_unique();
+    let season = pda::season(0);
+    let contest = Pubkey::new_unique();
+
+    let instruction = instructions::settle_season_contest(&signer, &season, &contest);
+
+    assert_eq!(
+        instruction.data[..8],
+        discriminator("settle_season_contest")
+    );
+    assert_eq!(
+        metas(&instruction),
+        vec![
+            (signer, true, false),
+            (season, false, true),
+            (contest, false, true),
+        ]
+    );
+}
@@ -0,0 +1,137 @@
+// Each helper is checked against the literal seeds, in the order the program's account constraints
+// list them, so a renamed seed or a swapped component breaks here instead of on chain.
+use anchor_lang::prelude::Pubkey;
+use protocol_client::pda;
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &protocol_client::ID).0
+}
+
+#[test]
+fn global_accounts() {
+    let mint = Pubkey::new_unique();
+
+    assert_eq!(pda::config(), find(&[b"config"]));
+    assert_eq!(pda::contest_metadata(), find(&[b"contest_metadata"]));
+    assert_eq!(pda::feed_registry(), find(&[b"feed_registry"]));
+    assert_eq!(
+        pda::program_token_account(&mint),
+        find(&[b"token_account", mint.as_ref()])
+    );
+}
+
+#[test]
+fn token_draft_accounts() {
+    let creator = Pubkey::new_unique();
+    let user = Pubkey::new_unique();
+    let contest = pda::token_draft_contest(7, &creator);
+
+    assert_eq!(
+        contest,
+        find(&[
+            b"token_draft_contest",
+            &7u64.to_le_bytes(),
+            creator.as_ref()
+        ])
+    );
+    assert_eq!(
+        pda::token_draft_contest_credits(&contest),
+        find(&[b"token_draft_contest_credits", contest.as_ref()])
+    );
+    assert_eq!(
+        pda::token_draft_contest_entry(&contest, &user),
+        find(&[
+            b"token_draft_contest_entry",
+            contest.as_ref(),
+            user.as_ref()
+        ])
+    );
+    assert_eq!(
+        pda::team(&contest, &creator),
+        find(&[b"team", contest.as_ref(), creator.as_ref()])
+    );
+}
+
+#[test]
+fn game_mode_accounts() {
+    let creator = Pubkey::new_unique();
+    let user = Pubkey::new_unique();
+
+    assert_eq!(
+        pda::head_to_head_contest(3, &creator),
+        find(&[
+            b"head_to_head_contest",
+            &3u64.to_le_bytes(),
+            creator.as_ref()
+        ])
+    );
+
+    let over_under = pda::over_under_contest(3, &creator);
+    assert_eq!(
+        over_under,
+        find(&[b"over_under_contest", &3u64.to_le_bytes(), creator.as_ref()])
+    );
+    assert_eq!(
+        pda::over_under_entry(&over_under, &user),
+        find(&[b"over_under_entry", over_under.as_ref(), user.as_ref()])
+    );
+
+    let parimutuel = pda::parimutuel_contest(3, &creator);
+    assert_eq!(
+        parimutuel,
+        find(&[b"parimutuel_contest", &3u64.to_le_bytes(), creator.as_ref()])
+    );
+    assert_eq!(
+        pda::parimutuel_entry(&parimutuel, &user),
+        find(&[b"parimutuel_entry", parimutuel.as_ref(), user.as_ref()])
+    );
+
+    let range = pda::range_contest(3, &creator);
+    assert_eq!(
+        range,
+        find(&[b"range_contest", &3u64.to_le_bytes(), creator.as_ref()])
+    );
+    assert_eq!(
+        pda::range_contest_entry(&range, &user),
+        find(&[b"range_contest_entry", range.as_ref(), user.as_ref()])
+    );
+    assert_eq!(
+        pda::range_contest_predictions(&range),
+        find(&[b"range_contest_predictions", range.as_ref()])
+    );
+
+    let survivor = pda::survivor_contest(3, &creator);
+    assert_eq!(
+        survivor,
+        find(&[b"survivor_contest", &3u64.to_le_bytes(), creator.as_ref()])
+    );
+    assert_eq!(
+        pda::survivor_contest_entry(&survivor, &user),
+        find(&[b"survivor_contest_entry", survivor.as_ref(), user.as_ref()])
+    );
+    assert_eq!(
+        pda::survivor_contest_credits(&survivor),
+        find(&[b"survivor_contest_credits", survivor.as_ref()])
+    );
+
+    assert_eq!(
+        pda::snake_draft_contest(3, &creator),
+        find(&[
+            b"snake_draft_contest",
+            &3u64.to_le_bytes(),
+            creator.as_ref()
+        ])
+    );
+}
+
+#[test]
+fn season_accounts() {
+    let user = Pubkey::new_unique();
+    let season = pda::season(2);
+
+    assert_eq!(season, find(&[b"season", &2u64.to_le_bytes()]));
+    assert_eq!(
+        pda::season_standing(&season, &user),
+        find(&[b"season_standing", season.as_ref(), user.as_ref()])
+    );
+}
@@ -0,0 +1,7 @@
+#![no_std]
+
+extern crate alloc;
+
+pub mod payout;
+pub mod rank;
+pub mod roi;
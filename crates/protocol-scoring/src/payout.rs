@@ -0,0 +1,38 @@
+// Amounts are never negative, so truncating the float product floors it without needing std
+pub fn calc_fee_amount(pool_amount: u64, fee_percent: u8) -> u64 {
+    let fee_frac = fee_percent as f64 / 100.0;
+    (fee_frac * pool_amount as f64) as u64
+}
+
+// Share of an amount given as a percentage, as used by reward allocations
+pub fn calc_reward_share(amount: u64, percent: u8) -> u64 {
+    ((percent as f64 / 100.0) * (amount as f64)) as u64
+}
+
+// Winners split the pool after fees in proportion to their stake on the winning outcome
+pub fn calc_parimutuel_payout(
+    stake: u64,
+    winning_amount: u64,
+    pool_amount: u64,
+    fee_amount: u64,
+) -> u64 {
+    if winning_amount == 0 {
+        return 0;
+    }
+    let reward_amount = (pool_amount - fee_amount) as u128;
+    (stake as u128 * reward_amount / winning_amount as u128) as u64
+}
+
+// Share of an amount given in basis points, integer math so small bounties do not round away
+pub fn calc_bps_share(amount: u64, bps: u16) -> u64 {
+    (amount as u128 * bps as u128 / 10_000) as u64
+}
+
+// Share of an amount for one weight out of a total, team prizes are split over the weights of the
+// members who entered
+pub fn calc_weighted_share(amount: u64, weight: u8, total_weight: u8) -> u64 {
+    if total_weight == 0 {
+        return 0;
+    }
+    (amount as u128 * weight as u128 / total_weight as u128) as u64
+}
@@ -0,0 +1,56 @@
+use alloc::vec::Vec;
+
+// Returns the n highest scores in descending order, ranking by a negated score picks the lowest
+pub fn find_top_n(scores: &[(usize, f64)], n: usize) -> Vec<(usize, f64)> {
+    if scores.len() <= n {
+        let mut x = scores.to_vec();
+        x.sort_by(|a, b| b.1.total_cmp(&a.1));
+        return x;
+    }
+
+    let mut min_heap = scores[..n].to_vec();
+
+    min_heapify(&mut min_heap);
+
+    for &score in &scores[n..] {
+        if score.1 > min_heap[0].1 {
+            min_heap[0] = score;
+            sift_down(&mut min_heap, 0);
+        }
+    }
+
+    min_heap.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    min_heap
+}
+
+fn min_heapify(arr: &mut [(usize, f64)]) {
+    let len = arr.len();
+    for i in (0..len / 2).rev() {
+        sift_down(arr, i);
+    }
+}
+
+fn sift_down(arr: &mut [(usize, f64)], mut root: usize) {
+    let len = arr.len();
+    loop {
+        let left = 2 * root + 1;
+        let right = 2 * root + 2;
+        let mut smallest = root;
+
+        if left < len && arr[left].1 < arr[smallest].1 {
+            smallest = left;
+        }
+
+        if right < len && arr[right].1 < arr[smallest].1 {
+            smallest = right;
+        }
+
+        if smallest == root {
+            break;
+        }
+
+        arr.swap(root, smallest);
+        root = smallest;
+    }
+}
@@ -0,0 +1,43 @@
+use alloc::vec::Vec;
+
+pub fn calc_token_rois(token_start_prices: &[f64], token_prices: &[f64]) -> Vec<f64> {
+    token_prices
+        .iter()
+        .zip(token_start_prices.iter())
+        .map(|(&price, &start_price)| {
+            let delta = price - start_price;
+            (delta / start_price) * 100.0
+        })
+        .collect()
+}
+
+// Compounds the returns of consecutive segments into a single ROI
+pub fn calc_chained_roi(segment_rois: &[f64]) -> f64 {
+    let Some((&first, rest)) = segment_rois.split_first() else {
+        return 0.0;
+    };
+    rest.iter().fold(first, |acc, &roi| {
+        ((1.0 + acc / 100.0) * (1.0 + roi / 100.0) - 1.0) * 100.0
+    })
+}
+
+pub fn calc_avg_roi(allocation: &[i8], token_rois: &[f64], captain: Option<(usize, f64)>) -> f64 {
+    let mut avg_roi = 0.0;
+
+    // Normalize by the credits spent since equally weighted picks do not add up to 100, shorted
+    // tokens keep their negative sign so that a falling price scores positively
+    let total_credits: u32 = allocation
+        .iter()
+        .map(|&alloc| alloc.unsigned_abs() as u32)
+        .sum();
+    for (i, &alloc) in allocation.iter().enumerate() {
+        // The captain's ROI counts at the contest's multiplier
+        let roi = match captain {
+            Some((captain, multiplier)) if captain == i => token_rois[i] * multiplier,
+            _ => token_rois[i],
+        };
+        avg_roi += ((alloc as f64) / (total_credits as f64)) * roi;
+    }
+
+    avg_roi
+}
@@ -0,0 +1,111 @@
+// Golden vectors for the scoring math shared with the on-chain program. Any change to these
+// results changes how existing contests settle, so expected values are compared exactly.
+use protocol_scoring::payout::{
+    calc_bps_share, calc_fee_amount, calc_parimutuel_payout, calc_reward_share, calc_weighted_share,
+};
+use protocol_scoring::rank::find_top_n;
+use protocol_scoring::roi::{calc_avg_roi, calc_chained_roi, calc_token_rois};
+
+const START_PRICES: [f64; 3] = [1.0, 2.5, 0.00002134];
+const END_PRICES: [f64; 3] = [1.1, 2.0, 0.00002567];
+const TOKEN_ROIS: [f64; 3] = [10.000000000000009, -20.0, 20.290534208059988];
+
+#[test]
+fn token_rois() {
+    assert_eq!(calc_token_rois(&START_PRICES, &END_PRICES), TOKEN_ROIS);
+    assert_eq!(calc_token_rois(&[], &[]), Vec::<f64>::new());
+}
+
+#[test]
+fn avg_roi() {
+    assert_eq!(
+        calc_avg_roi(&[35, 65, 0], &TOKEN_ROIS, None),
+        -9.499999999999996
+    );
+    assert_eq!(
+        calc_avg_roi(&[1, 1, 0], &TOKEN_ROIS, None),
+        -4.999999999999996
+    );
+
+    // Shorted tokens score the inverse of their ROI
+    assert_eq!(
+        calc_avg_roi(&[-50, 0, 50], &TOKEN_ROIS, None),
+        5.1452671040299895
+    );
+
+    // The captain's ROI is scaled before weighting
+    assert_eq!(
+        calc_avg_roi(&[35, 65, 0], &TOKEN_ROIS, Some((1, 1.5))),
+        -15.999999999999996
+    );
+}
+
+#[test]
+fn chained_roi() {
+    assert_eq!(calc_chained_roi(&[10.0, -5.0, 2.5]), 7.112499999999988);
+    assert_eq!(calc_chained_roi(&[7.25]), 7.25);
+    assert_eq!(calc_chained_roi(&[]), 0.0);
+}
+
+#[test]
+fn top_n() {
+    let scores = [(0, 1.5), (1, -2.0), (2, 7.0), (3, 1.5), (4, 3.25), (5, 0.0)];
+
+    // Ties keep the earlier entry
+    assert_eq!(find_top_n(&scores, 3), vec![(2, 7.0), (4, 3.25), (0, 1.5)]);
+    assert_eq!(find_top_n(&scores, 1), vec![(2, 7.0)]);
+
+    // Asking for more winners than entries ranks everyone
+    assert_eq!(
+        find_top_n(&scores[..4], 6),
+        vec![(2, 7.0), (0, 1.5), (3, 1.5), (1, -2.0)]
+    );
+
+    // Negated scores pick the lowest
+    let negated: Vec<(usize, f64)> = scores.iter().map(|&(i, s)| (i, -s)).collect();
+    assert_eq!(find_top_n(&negated, 2), vec![(1, 2.0), (5, -0.0)]);
+}
+
+#[test]
+fn fee_amount() {
+    assert_eq!(calc_fee_amount(30_000_000, 10), 3_000_000);
+    assert_eq!(calc_fee_amount(1_000_001, 7), 70_000);
+    assert_eq!(calc_fee_amount(1_000_000, 0), 0);
+    assert_eq!(calc_fee_amount(0, 10), 0);
+}
+
+#[test]
+fn reward_share() {
+    assert_eq!(calc_reward_share(27_000_000, 50), 13_500_000);
+    assert_eq!(calc_reward_share(1_000_000, 33), 330_000);
+    assert_eq!(calc_reward_share(27_000_000, 100), 27_000_000);
+
+    // 0.29 * 100 lands just under 29 in floating point and truncates down
+    assert_eq!(calc_reward_share(100, 29), 28);
+}
+
+#[test]
+fn parimutuel_payout() {
+    assert_eq!(calc_parimutuel_payout(10, 30, 100, 10), 30);
+    assert_eq!(calc_parimutuel_payout(7, 9, 25, 2), 17);
+    assert_eq!(calc_parimutuel_payout(5, 0, 100, 10), 0);
+    assert_eq!(
+        calc_parimutuel_payout(u64::MAX / 2, u64::MAX / 2, u64::MAX, 1),
+        u64::MAX - 1
+    );
+}
+
+#[test]
+fn bps_share() {
+    assert_eq!(calc_bps_share(13_500_000, 50), 67_500);
+    assert_eq!(calc_bps_share(199, 50), 0);
+    assert_eq!(calc_bps_share(u64::MAX, 10_000), u64::MAX);
+}
+
+#[test]
+fn weighted_share() {
+    assert_eq!(calc_weighted_share(13_500_000, 60, 100), 8_100_000);
+    assert_eq!(calc_weighted_share(13_500_000, 60, 60), 13_500_000);
+    assert_eq!(calc_weighted_share(100, 30, 90), 33);
+    assert_eq!(calc_weighted_share(100, 0, 0), 0);
+}
@@ -4,3 +4,19 @@ pub const SEED_PROGRAM_TOKEN_ACCOUNT: &[u8] = b"token_account";
 pub const SEED_TOKEN_DRAFT_CONTEST: &[u8] = b"token_draft_contest";
 pub const SEED_TOKEN_DRAFT_CONTEST_CREDITS: &[u8] = b"token_draft_contest_credits";
 pub const SEED_TOKEN_DRAFT_CONTEST_ENTRY: &[u8] = b"token_draft_contest_entry";
+pub const SEED_FEED_REGISTRY: &[u8] = b"feed_registry";
+pub const SEED_HEAD_TO_HEAD_CONTEST: &[u8] = b"head_to_head_contest";
+pub const SEED_OVER_UNDER_CONTEST: &[u8] = b"over_under_contest";
+pub const SEED_OVER_UNDER_ENTRY: &[u8] = b"over_under_entry";
+pub const SEED_PARIMUTUEL_CONTEST: &[u8] = b"parimutuel_contest";
+pub const SEED_PARIMUTUEL_ENTRY: &[u8] = b"parimutuel_entry";
+pub const SEED_RANGE_CONTEST: &[u8] = b"range_contest";
+pub const SEED_RANGE_CONTEST_ENTRY: &[u8] = b"range_contest_entry";
+pub const SEED_RANGE_CONTEST_PREDICTIONS: &[u8] = b"range_contest_predictions";
+pub const SEED_SURVIVOR_CONTEST: &[u8] = b"survivor_contest";
+pub const SEED_SURVIVOR_CONTEST_ENTRY: &[u8] = b"survivor_contest_entry";
+pub const SEED_SURVIVOR_CONTEST_CREDITS: &[u8] = b"survivor_contest_credits";
+pub const SEED_SEASON: &[u8] = b"season";
+pub const SEED_SEASON_STANDING: &[u8] = b"season_standing";
+pub const SEED_TEAM: &[u8] = b"team";
+pub const SEED_SNAKE_DRAFT_CONTEST: &[u8] = b"snake_draft_contest";
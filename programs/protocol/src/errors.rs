@@ -5,6 +5,9 @@ pub enum ConfigError {
     Unauthorized,
     AlreadyInitialized,
     InvalidFeePercent,
+    InvalidFeedSymbol,
+    FeedAlreadyRegistered,
+    FeedNotRegistered,
 }
 
 #[error_code]
@@ -26,4 +29,59 @@ pub enum ContestError {
     InvalidRewardAllocation,
     ContestNotStarted,
     ContestPriceNotSet,
+    AccessDenied,
+    FeedNotAllowed,
+    PriceConfidenceTooLow,
+    InvalidSalaryCap,
+    SalaryCapExceeded,
+    InvalidCaptainMultiplier,
+    InvalidCaptain,
+    SelfChallenge,
+    ChallengeAlreadyAccepted,
+    ChallengeNotAccepted,
+    PricesAlreadyPosted,
+    NotParticipant,
+    InvalidStrikePrice,
+    InvalidTokenIndex,
+    InvalidPrediction,
+    InvalidRoundConfig,
+    Eliminated,
+    InvalidSeasonConfig,
+    SeasonMismatch,
+    AlreadyInSeason,
+    AlreadySettled,
+    SeasonFinalized,
+    SeasonNotFinalized,
+    InvalidTeam,
+    NotTeamMember,
+    InvalidRebalanceCheckpoints,
+    CheckpointNotReached,
+    RebalanceClosed,
+    InvalidSnakeDraftConfig,
+    AlreadyJoined,
+    DraftNotActive,
+    DraftNotComplete,
+    NotYourTurn,
+    TokenAlreadyOwned,
+    PickDeadlinePassed,
+    PickDeadlineNotReached,
+    InvalidPayoutAccounts,
+    NotCreator,
+    RewardNotClaimed,
+    ClaimsPending,
+    SeasonNotSettled,
+    InvalidClaimWindow,
+    ClaimExpired,
+    ClaimWindowOpen,
+    NothingToSweep,
+    KeeperBountiesUnpaid,
+    PricePostingClosed,
+    PricePostingOpen,
+    InvalidPublishTime,
+    ContestCancelled,
+    ContestNotCancelled,
+    NotRefundable,
+    SeasonPointsPending,
+    EntriesOpen,
+    ContestNotClosed,
 }
@@ -0,0 +1,452 @@
+use crate::state::contest::{ContestAccess, SalaryCap, TeamConfig};
+use crate::state::over_under::OverUnderSide;
+use crate::state::range::RangeTarget;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ContestCreated {
+    pub contest: Pubkey,
+    pub id: u64,
+    pub creator: Pubkey,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub entry_fee: u64,
+    pub max_entries: u32,
+    pub token_feed_ids: Vec<Pubkey>,
+    pub token_min_confidence_ratios: Vec<u32>,
+    pub reward_allocation: Vec<u8>,
+    pub access: ContestAccess,
+    pub salary_cap: Option<SalaryCap>,
+    pub allow_short: bool,
+    pub captain_multiplier_bps: Option<u16>,
+    pub team_config: Option<TeamConfig>,
+    pub rebalance_times: Vec<u64>,
+    pub claim_window: Option<u64>,
+}
+
+#[event]
+pub struct EntrySubmitted {
+    pub contest: Pubkey,
+    pub entry: Pubkey,
+    pub user: Pubkey,
+    pub entry_id: u32,
+    pub credit_allocation: Vec<i8>,
+    pub captain: Option<u8>,
+    pub team_id: Option<u32>,
+}
+
+#[event]
+pub struct EntryRebalanced {
+    pub contest: Pubkey,
+    pub entry: Pubkey,
+    pub user: Pubkey,
+    pub entry_id: u32,
+    pub segment: u8,
+    pub credit_allocation: Vec<i8>,
+}
+
+#[event]
+pub struct PricesPosted {
+    pub contest: Pubkey,
+    pub snapshot: PriceSnapshot,
+    pub token_prices: Vec<f64>,
+    pub publish_times: Vec<i64>,
+}
+
+// Which point of the contest a set of posted prices belongs to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PriceSnapshot {
+    Start,
+    Checkpoint { index: u8 },
+    End,
+    // Start of a survivor round
+    Round { index: u8 },
+}
+
+#[event]
+pub struct ContestCancelled {
+    pub contest: Pubkey,
+    pub num_entries: u32,
+}
+
+#[event]
+pub struct EntryRefunded {
+    pub contest: Pubkey,
+    pub entry: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ContestResolved {
+    pub contest: Pubkey,
+    pub token_rois: Vec<f64>,
+    pub winner_ids: Vec<u32>,
+    pub winner_rois: Vec<f64>,
+    pub fee_amount: u64,
+}
+
+#[event]
+pub struct RewardClaimed {
+    pub contest: Pubkey,
+    pub entry: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RewardsDistributed {
+    pub contest: Pubkey,
+    pub keeper: Pubkey,
+    pub num_paid: u32,
+    pub bounty_amount: u64,
+}
+
+#[event]
+pub struct KeeperBountiesPaid {
+    pub contest: Pubkey,
+    pub price_poster: Pubkey,
+    pub resolver: Pubkey,
+    pub bounty_amount: u64,
+}
+
+#[event]
+pub struct PrizesSwept {
+    pub contest: Pubkey,
+    pub season: Option<Pubkey>,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FeeWithdrawn {
+    pub admin: Pubkey,
+    pub withdrawal_token_account: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TeamCreated {
+    pub contest: Pubkey,
+    pub team: Pubkey,
+    pub team_id: u32,
+    pub creator: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub member_weights: Vec<u8>,
+}
+
+#[event]
+pub struct SeasonCreated {
+    pub season: Pubkey,
+    pub id: u64,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub points_table: Vec<u32>,
+    pub fee_share_percent: u8,
+    pub prize_allocation: Vec<u8>,
+}
+
+#[event]
+pub struct SeasonContestAdded {
+    pub season: Pubkey,
+    pub contest: Pubkey,
+}
+
+#[event]
+pub struct SeasonContestSettled {
+    pub season: Pubkey,
+    pub contest: Pubkey,
+    pub prize_amount: u64,
+}
+
+#[event]
+pub struct SeasonPointsAwarded {
+    pub season: Pubkey,
+    pub contest: Pubkey,
+    pub entry: Pubkey,
+    pub user: Pubkey,
+    pub points: u32,
+    pub total_points: u64,
+}
+
+#[event]
+pub struct SeasonFinalized {
+    pub season: Pubkey,
+    pub prize_pool_amount: u64,
+    pub leaders: Vec<Pubkey>,
+}
+
+#[event]
+pub struct SeasonPrizeClaimed {
+    pub season: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EntryClosed {
+    pub contest: Pubkey,
+    pub entry: Pubkey,
+    pub user: Pubkey,
+}
+
+#[event]
+pub struct ContestClosed {
+    pub contest: Pubkey,
+    pub creator: Pubkey,
+}
+
+#[event]
+pub struct TeamClosed {
+    pub contest: Pubkey,
+    pub team: Pubkey,
+    pub creator: Pubkey,
+}
+
+// Head-to-head duels and snake drafts keep their players on the contest account, so their
+// refunds and claims are keyed by user instead of by entry
+#[event]
+pub struct PlayerRefunded {
+    pub contest: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PlayerRewardClaimed {
+    pub contest: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct HeadToHeadContestCreated {
+    pub contest: Pubkey,
+    pub id: u64,
+    pub challenger: Pubkey,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub stake: u64,
+    pub token_feed_ids: Vec<Pubkey>,
+    pub credit_allocation: Vec<i8>,
+}
+
+#[event]
+pub struct HeadToHeadContestAccepted {
+    pub contest: Pubkey,
+    pub opponent: Pubkey,
+    pub credit_allocation: Vec<i8>,
+}
+
+#[event]
+pub struct HeadToHeadContestCancelled {
+    pub contest: Pubkey,
+    pub challenger: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct HeadToHeadContestResolved {
+    pub contest: Pubkey,
+    pub token_rois: Vec<f64>,
+    pub challenger_roi: f64,
+    pub opponent_roi: f64,
+    pub winner: Option<Pubkey>,
+    pub fee_amount: u64,
+}
+
+#[event]
+pub struct OverUnderContestCreated {
+    pub contest: Pubkey,
+    pub id: u64,
+    pub creator: Pubkey,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub feed_id: Pubkey,
+    pub strike_price: f64,
+}
+
+#[event]
+pub struct OverUnderEntrySubmitted {
+    pub contest: Pubkey,
+    pub entry: Pubkey,
+    pub user: Pubkey,
+    pub side: OverUnderSide,
+    pub amount: u64,
+}
+
+#[event]
+pub struct OverUnderContestResolved {
+    pub contest: Pubkey,
+    pub end_price: f64,
+    pub publish_time: i64,
+    pub outcome: Option<OverUnderSide>,
+    pub fee_amount: u64,
+}
+
+#[event]
+pub struct ParimutuelContestCreated {
+    pub contest: Pubkey,
+    pub id: u64,
+    pub creator: Pubkey,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub token_feed_ids: Vec<Pubkey>,
+}
+
+#[event]
+pub struct ParimutuelEntrySubmitted {
+    pub contest: Pubkey,
+    pub entry: Pubkey,
+    pub user: Pubkey,
+    pub token_index: u8,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ParimutuelContestCancelled {
+    pub contest: Pubkey,
+    pub pool_amount: u64,
+}
+
+#[event]
+pub struct ParimutuelContestResolved {
+    pub contest: Pubkey,
+    pub token_rois: Vec<f64>,
+    pub winning_token: Option<u8>,
+    pub fee_amount: u64,
+}
+
+#[event]
+pub struct RangeContestCreated {
+    pub contest: Pubkey,
+    pub id: u64,
+    pub creator: Pubkey,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub entry_fee: u64,
+    pub max_entries: u32,
+    pub feed_id: Pubkey,
+    pub target: RangeTarget,
+    pub reward_allocation: Vec<u8>,
+}
+
+#[event]
+pub struct RangeEntrySubmitted {
+    pub contest: Pubkey,
+    pub entry: Pubkey,
+    pub user: Pubkey,
+    pub entry_id: u32,
+    pub prediction: f64,
+}
+
+#[event]
+pub struct RangeContestResolved {
+    pub contest: Pubkey,
+    pub end_price: f64,
+    pub publish_time: i64,
+    pub settled_value: f64,
+    pub winner_ids: Vec<u32>,
+    pub fee_amount: u64,
+}
+
+#[event]
+pub struct SurvivorContestCreated {
+    pub contest: Pubkey,
+    pub id: u64,
+    pub creator: Pubkey,
+    pub start_time: u64,
+    pub draft_duration: u64,
+    pub round_duration: u64,
+    pub num_rounds: u8,
+    pub elimination_percent: u8,
+    pub entry_fee: u64,
+    pub max_entries: u32,
+    pub token_feed_ids: Vec<Pubkey>,
+}
+
+#[event]
+pub struct SurvivorEntrySubmitted {
+    pub contest: Pubkey,
+    pub entry: Pubkey,
+    pub user: Pubkey,
+    pub entry_id: u32,
+    pub credit_allocation: Vec<i8>,
+}
+
+#[event]
+pub struct SurvivorEntryRedrafted {
+    pub contest: Pubkey,
+    pub entry: Pubkey,
+    pub user: Pubkey,
+    pub entry_id: u32,
+    pub round: u8,
+    pub credit_allocation: Vec<i8>,
+}
+
+// The fee is only taken when the final round resolves, earlier rounds report zero
+#[event]
+pub struct SurvivorRoundResolved {
+    pub contest: Pubkey,
+    pub round: u8,
+    pub token_rois: Vec<f64>,
+    pub eliminated_ids: Vec<u32>,
+    pub num_survivors: u32,
+    pub fee_amount: u64,
+}
+
+#[event]
+pub struct SnakeDraftContestCreated {
+    pub contest: Pubkey,
+    pub id: u64,
+    pub creator: Pubkey,
+    pub entry_fee: u64,
+    pub max_players: u8,
+    pub picks_per_player: u8,
+    pub pick_duration: u64,
+    pub contest_duration: u64,
+    pub fill_deadline: u64,
+    pub token_feed_ids: Vec<Pubkey>,
+    pub reward_allocation: Vec<u8>,
+}
+
+#[event]
+pub struct SnakeDraftPlayerJoined {
+    pub contest: Pubkey,
+    pub player: Pubkey,
+    pub player_id: u8,
+}
+
+#[event]
+pub struct SnakeDraftTokenPicked {
+    pub contest: Pubkey,
+    pub player: Pubkey,
+    pub token_index: u8,
+    pub is_auto_pick: bool,
+}
+
+#[event]
+pub struct SnakeDraftContestResolved {
+    pub contest: Pubkey,
+    pub token_rois: Vec<f64>,
+    pub winner_ids: Vec<u32>,
+    pub fee_amount: u64,
+}
+
+#[event]
+pub struct FeedRegistryCuratorSet {
+    pub curator: Pubkey,
+}
+
+#[event]
+pub struct RegistryFeedAdded {
+    pub feed_id: Pubkey,
+    pub symbol: String,
+    pub min_confidence_ratio: u32,
+}
+
+#[event]
+pub struct RegistryFeedUpdated {
+    pub feed_id: Pubkey,
+    pub min_confidence_ratio: u32,
+    pub is_enabled: bool,
+}
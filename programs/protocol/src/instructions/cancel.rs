@@ -0,0 +1,125 @@
+use crate::constants::seeds::{
+    SEED_CONFIG, SEED_PROGRAM_TOKEN_ACCOUNT, SEED_TOKEN_DRAFT_CONTEST_ENTRY,
+};
+use crate::errors::ContestError;
+use crate::events::{ContestCancelled, EntryRefunded};
+use crate::state::config::Config;
+use crate::state::contest::TokenDraftContest;
+use crate::state::entry::TokenDraftContestEntry;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct CancelTokenDraftContest<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, TokenDraftContest>>,
+}
+
+#[derive(Accounts)]
+pub struct RefundTokenDraftContestEntry<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, TokenDraftContest>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TOKEN_DRAFT_CONTEST_ENTRY, contest.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub contest_entry: Box<Account<'info, TokenDraftContestEntry>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// Anyone can cancel a contest whose start or checkpoint prices were not posted within the posting
+// window
+pub fn cancel_token_draft_contest(ctx: Context<CancelTokenDraftContest>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+
+    require!(!contest.is_cancelled, ContestError::ContestCancelled);
+    require!(!contest.is_resolved, ContestError::AlreadyResolved);
+    require!(
+        contest.has_missed_price_posting(),
+        ContestError::PricePostingOpen
+    );
+
+    contest.is_cancelled = true;
+
+    // Every entry is owed its entry fee back before the contest can be closed
+    contest.num_pending_claims = contest.num_entries;
+
+    emit!(ContestCancelled {
+        contest: contest.key(),
+        num_entries: contest.num_entries,
+    });
+
+    Ok(())
+}
+
+pub fn refund_token_draft_contest_entry(ctx: Context<RefundTokenDraftContestEntry>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let contest_entry = &mut ctx.accounts.contest_entry;
+
+    require!(contest.is_cancelled, ContestError::ContestNotCancelled);
+    require!(!contest_entry.has_claimed, ContestError::AlreadyClaimed);
+
+    let refund_amount = contest.entry_fee;
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.program_token_account.to_account_info(),
+        to: ctx.accounts.signer_token_account.to_account_info(),
+        authority: ctx.accounts.program_token_account.to_account_info(),
+    };
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_PROGRAM_TOKEN_ACCOUNT,
+        mint_key.as_ref(),
+        &[ctx.bumps.program_token_account],
+    ]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_context, refund_amount, ctx.accounts.mint.decimals)?;
+
+    // Mark the entry as refunded
+    contest_entry.has_claimed = true;
+    contest.num_pending_claims -= 1;
+
+    emit!(EntryRefunded {
+        contest: contest.key(),
+        entry: contest_entry.key(),
+        user: contest_entry.user,
+        amount: refund_amount,
+    });
+
+    Ok(())
+}
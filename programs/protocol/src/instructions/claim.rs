@@ -1,10 +1,12 @@
 use crate::constants::seeds::{
     SEED_CONTEST_METADATA, SEED_PROGRAM_TOKEN_ACCOUNT, SEED_TOKEN_DRAFT_CONTEST_ENTRY,
 };
+use crate::events::RewardClaimed;
 use crate::state::config::Config;
 use crate::state::contest::TokenDraftContest;
 use crate::state::entry::TokenDraftContestEntry;
 use crate::state::metadata::ContestMetadata;
+use crate::state::team::Team;
 use crate::{constants::seeds::SEED_CONFIG, errors::ContestError};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{
@@ -39,6 +41,8 @@ pub struct ClaimTokenDraftContest<'info> {
     )]
     pub contest_entry: Box<Account<'info, TokenDraftContestEntry>>,
 
+    pub team: Option<Box<Account<'info, Team>>>,
+
     #[account(mut)]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
@@ -72,25 +76,16 @@ pub fn claim_token_draft_contest(ctx: Context<ClaimTokenDraftContest>) -> Result
     // Check if the user has already claimed their rewards
     require!(!contest_entry.has_claimed, ContestError::AlreadyClaimed);
 
-    let pos_opt = contest
-        .winner_ids
-        .iter()
-        .position(|&id| id == contest_entry.id);
-    require!(pos_opt.is_some(), ContestError::NotWinner);
-
-    let pos = pos_opt.unwrap();
-    let alloc = contest.winner_reward_allocation[pos];
+    require!(!contest.is_claim_expired(), ContestError::ClaimExpired);
 
-    // Calculate the user reward amount based on the credit allocation
-    let fee_frac = ctx
-        .accounts
-        .contest_metadata
-        .token_draft_contest_fee_percent as f64
-        / 100.0;
-    let total_pool_amount = contest.pool_amount();
-    let fee_amount = (fee_frac * total_pool_amount as f64).floor() as u64;
-    let total_reward_amount = total_pool_amount - fee_amount;
-    let user_reward_amount = ((alloc as f64 / 100.0) * (total_reward_amount as f64)).floor() as u64;
+    let user_reward_amount = contest.entry_reward_amount(
+        contest.key(),
+        contest_entry,
+        ctx.accounts
+            .contest_metadata
+            .token_draft_contest_fee_percent,
+        ctx.accounts.team.as_deref().map(|team| &**team),
+    )?;
 
     // Transfer the reward to the user's token account
     let cpi_accounts = TransferChecked {
@@ -111,6 +106,139 @@ pub fn claim_token_draft_contest(ctx: Context<ClaimTokenDraftContest>) -> Result
 
     // Mark the entry as claimed
     contest_entry.has_claimed = true;
+    contest.num_pending_claims -= 1;
+    contest.paid_amount += user_reward_amount;
+
+    emit!(RewardClaimed {
+        contest: contest.key(),
+        entry: contest_entry.key(),
+        user: contest_entry.user,
+        amount: user_reward_amount,
+    });
 
     Ok(())
 }
+
+#[derive(Accounts)]
+pub struct ClaimManyTokenDraftContests<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// Remaining accounts hold a contest and the signer's entry for each claim, followed by the team
+// for team contests. Every reward is paid in a single transfer since all contests share the mint.
+pub fn claim_many_token_draft_contests<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimManyTokenDraftContests<'info>>,
+) -> Result<()> {
+    let fee_percent = ctx
+        .accounts
+        .contest_metadata
+        .token_draft_contest_fee_percent;
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+    let mut total_reward_amount = 0;
+    while let Some(contest_info) = remaining_accounts.next() {
+        let mut contest = Account::<TokenDraftContest>::try_from(contest_info)?;
+        let entry_info = remaining_accounts
+            .next()
+            .ok_or(ContestError::InvalidPayoutAccounts)?;
+        let mut contest_entry = Account::<TokenDraftContestEntry>::try_from(entry_info)?;
+        let team = match contest.team_config {
+            Some(_) => {
+                let team_info = remaining_accounts
+                    .next()
+                    .ok_or(ContestError::InvalidPayoutAccounts)?;
+                Some(Account::<Team>::try_from(team_info)?)
+            }
+            None => None,
+        };
+        require!(
+            contest_entry.contest_key == contest.key()
+                && contest_entry.user == ctx.accounts.signer.key(),
+            ContestError::InvalidPayoutAccounts
+        );
+
+        // Unresolved contests, expired claims, claimed entries and losing entries are skipped
+        // instead of failing the batch
+        if !contest.is_resolved || contest.is_claim_expired() || contest_entry.has_claimed {
+            continue;
+        }
+        if contest.winner_position(&contest_entry).is_none() {
+            continue;
+        }
+
+        let user_reward_amount = contest.entry_reward_amount(
+            contest.key(),
+            &contest_entry,
+            fee_percent,
+            team.as_deref(),
+        )?;
+
+        // Mark the entry as claimed
+        contest_entry.has_claimed = true;
+        contest_entry.exit(&crate::ID)?;
+        contest.num_pending_claims -= 1;
+        contest.paid_amount += user_reward_amount;
+        contest.exit(&crate::ID)?;
+
+        emit!(RewardClaimed {
+            contest: contest.key(),
+            entry: contest_entry.key(),
+            user: contest_entry.user,
+            amount: user_reward_amount,
+        });
+
+        total_reward_amount += user_reward_amount;
+    }
+
+    if total_reward_amount == 0 {
+        return Ok(());
+    }
+
+    // Transfer the rewards to the user's token account
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.program_token_account.to_account_info(),
+        to: ctx.accounts.signer_token_account.to_account_info(),
+        authority: ctx.accounts.program_token_account.to_account_info(),
+    };
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_PROGRAM_TOKEN_ACCOUNT,
+        mint_key.as_ref(),
+        &[ctx.bumps.program_token_account],
+    ]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_context, total_reward_amount, ctx.accounts.mint.decimals)
+}
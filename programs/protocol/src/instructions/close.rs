@@ -0,0 +1,177 @@
+use crate::constants::seeds::{
+    SEED_TEAM, SEED_TOKEN_DRAFT_CONTEST_CREDITS, SEED_TOKEN_DRAFT_CONTEST_ENTRY,
+};
+use crate::errors::ContestError;
+use crate::events::{ContestClosed, EntryClosed, TeamClosed};
+use crate::state::contest::TokenDraftContest;
+use crate::state::credit::TokenDraftContestCredits;
+use crate::state::entry::TokenDraftContestEntry;
+use crate::state::team::Team;
+use anchor_lang::prelude::*;
+
+// Anyone can close an entry on behalf of its user, the rent always returns to the user
+#[derive(Accounts)]
+pub struct CloseTokenDraftContestEntry<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub user: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, TokenDraftContest>>,
+
+    // Credit rows are only read up to resolve, one is dropped per closed entry and its rent
+    // returned to the user who paid for it while entering
+    #[account(
+        mut,
+        realloc = contest_credits.to_account_info().data_len() - contest.credits_entry_space(),
+        realloc::payer = user,
+        realloc::zero = false,
+        seeds = [SEED_TOKEN_DRAFT_CONTEST_CREDITS, contest.key().as_ref()],
+        bump
+    )]
+    pub contest_credits: Box<Account<'info, TokenDraftContestCredits>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [SEED_TOKEN_DRAFT_CONTEST_ENTRY, contest.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub contest_entry: Box<Account<'info, TokenDraftContestEntry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseTokenDraftContest<'info> {
+    #[account(
+        mut,
+        address = contest.creator @ ContestError::NotCreator
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = signer
+    )]
+    pub contest: Box<Account<'info, TokenDraftContest>>,
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [SEED_TOKEN_DRAFT_CONTEST_CREDITS, contest.key().as_ref()],
+        bump
+    )]
+    pub contest_credits: Box<Account<'info, TokenDraftContestCredits>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseTeam<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: the contest must already be closed, only its address is used
+    pub contest: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [SEED_TEAM, contest.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub team: Box<Account<'info, Team>>,
+}
+
+pub fn close_token_draft_contest_entry(ctx: Context<CloseTokenDraftContestEntry>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let contest_entry = &ctx.accounts.contest_entry;
+
+    if contest.is_cancelled {
+        // Entries of a cancelled contest are closed once their entry fee is refunded
+        require!(contest_entry.has_claimed, ContestError::RewardNotClaimed);
+    } else {
+        require!(contest.is_resolved, ContestError::ContestNotResolved);
+
+        // Winners keep their entry until the reward is paid or expires. Entries are closed at the
+        // start time, so a closed entry can never be recreated and claimed again.
+        if contest.winner_position(contest_entry).is_some() {
+            require!(
+                contest_entry.has_claimed || contest.is_claim_expired(),
+                ContestError::RewardNotClaimed
+            );
+            require!(
+                contest.season.is_none() || contest_entry.has_season_points,
+                ContestError::SeasonNotSettled
+            );
+        }
+    }
+
+    // Drop a row to match the shrunk credits account, the rows are no longer read
+    let contest_credits = &mut ctx.accounts.contest_credits;
+    let num_credits = contest_credits.credit_allocations.len();
+    contest_credits
+        .credit_allocations
+        .truncate(num_credits - contest.credit_row_len());
+    if contest.team_config.is_some() {
+        contest_credits.entry_teams.pop();
+    }
+
+    contest.num_closed_entries += 1;
+
+    emit!(EntryClosed {
+        contest: contest.key(),
+        entry: contest_entry.key(),
+        user: contest_entry.user,
+    });
+
+    Ok(())
+}
+
+// Rent for the contest and credits accounts returns to the creator who opened them, every entry
+// must be closed first so the credit rows entrants paid for are refunded to them
+pub fn close_token_draft_contest(ctx: Context<CloseTokenDraftContest>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    require!(
+        contest.num_closed_entries == contest.num_entries,
+        ContestError::EntriesOpen
+    );
+
+    // A cancelled contest only owes refunds, nothing is left to settle with keepers or the season
+    require!(contest.num_pending_claims == 0, ContestError::ClaimsPending);
+    if !contest.is_cancelled {
+        require!(contest.is_resolved, ContestError::ContestNotResolved);
+        require!(
+            contest.has_paid_keeper_bounties,
+            ContestError::KeeperBountiesUnpaid
+        );
+        require!(
+            contest.season.is_none() || contest.is_season_settled,
+            ContestError::SeasonNotSettled
+        );
+    }
+
+    emit!(ContestClosed {
+        contest: contest.key(),
+        creator: contest.creator,
+    });
+
+    Ok(())
+}
+
+// Members' entries need the team until every prize is paid, so teams close after their contest
+pub fn close_team(ctx: Context<CloseTeam>) -> Result<()> {
+    require!(
+        ctx.accounts.contest.data_is_empty(),
+        ContestError::ContestNotClosed
+    );
+
+    emit!(TeamClosed {
+        contest: ctx.accounts.contest.key(),
+        team: ctx.accounts.team.key(),
+        creator: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
@@ -1,14 +1,35 @@
 use crate::constants::seeds::{
-    SEED_CONTEST_METADATA, SEED_TOKEN_DRAFT_CONTEST, SEED_TOKEN_DRAFT_CONTEST_CREDITS,
+    SEED_CONTEST_METADATA, SEED_FEED_REGISTRY, SEED_TOKEN_DRAFT_CONTEST,
+    SEED_TOKEN_DRAFT_CONTEST_CREDITS,
 };
 use crate::errors::ContestError;
-use crate::state::contest::{TokenDraftContest, MAX_TOKEN_PER_DRAFT};
+use crate::events::ContestCreated;
+use crate::state::contest::{
+    ContestAccess, SalaryCap, TeamConfig, TokenDraftContest, CAPTAIN_MULTIPLIER_BPS_BASE,
+    MAX_REBALANCE_CHECKPOINTS, MAX_TOKEN_PER_DRAFT,
+};
 use crate::state::credit::TokenDraftContestCredits;
+use crate::state::feed_registry::FeedRegistry;
 use crate::state::metadata::ContestMetadata;
+use crate::utils::price::load_price_feeds;
 use anchor_lang::prelude::*;
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 #[derive(Accounts)]
+#[instruction(
+    start_time: u64,
+    end_time: u64,
+    entry_fee: u64,
+    max_entries: u32,
+    token_feed_ids: Vec<Pubkey>,
+    reward_allocation: Vec<u8>,
+    access: ContestAccess,
+    salary_cap: Option<SalaryCap>,
+    allow_short: bool,
+    captain_multiplier_bps: Option<u16>,
+    team_config: Option<TeamConfig>,
+    rebalance_times: Vec<u64>,
+    claim_window: Option<u64>,
+)]
 pub struct CreateTokenDraftContest<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -23,7 +44,7 @@ pub struct CreateTokenDraftContest<'info> {
     #[account(
         init,
         payer = signer,
-        space = 8 + TokenDraftContest::INIT_SPACE,
+        space = 8 + TokenDraftContest::space(token_feed_ids.len(), reward_allocation.len(), rebalance_times.len()),
         seeds = [SEED_TOKEN_DRAFT_CONTEST, contest_metadata.token_draft_contest_count.to_le_bytes().as_ref(), signer.key().as_ref()],
         bump
     )]
@@ -32,21 +53,22 @@ pub struct CreateTokenDraftContest<'info> {
     #[account(
         init,
         payer = signer,
-        space = 8 + TokenDraftContest::INIT_SPACE,
+        space = 8 + TokenDraftContestCredits::INIT_SPACE,
         seeds = [SEED_TOKEN_DRAFT_CONTEST_CREDITS, contest.key().as_ref()],
         bump
     )]
     pub contest_credits: Box<Account<'info, TokenDraftContestCredits>>,
 
-    pub feed0: Option<Box<Account<'info, PriceUpdateV2>>>,
-    pub feed1: Option<Box<Account<'info, PriceUpdateV2>>>,
-    pub feed2: Option<Box<Account<'info, PriceUpdateV2>>>,
-    pub feed3: Option<Box<Account<'info, PriceUpdateV2>>>,
-    pub feed4: Option<Box<Account<'info, PriceUpdateV2>>>,
+    #[account(
+        seeds = [SEED_FEED_REGISTRY],
+        bump
+    )]
+    pub feed_registry: Box<Account<'info, FeedRegistry>>,
 
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_token_draft_contest(
     ctx: Context<CreateTokenDraftContest>,
     start_time: u64,
@@ -55,6 +77,13 @@ pub fn create_token_draft_contest(
     max_entries: u32,
     token_feed_ids: Vec<Pubkey>,
     reward_allocation: Vec<u8>,
+    access: ContestAccess,
+    salary_cap: Option<SalaryCap>,
+    allow_short: bool,
+    captain_multiplier_bps: Option<u16>,
+    team_config: Option<TeamConfig>,
+    rebalance_times: Vec<u64>,
+    claim_window: Option<u64>,
 ) -> Result<()> {
     let contest = &mut ctx.accounts.contest;
     let current_time = Clock::get()?.unix_timestamp as u64;
@@ -76,18 +105,72 @@ pub fn create_token_draft_contest(
         ContestError::InvalidDraftTokenCount
     );
 
-    // Check that valid feeds are provided
-    let feed_accounts: Vec<&Option<Box<Account<'_, PriceUpdateV2>>>> = vec![
-        &ctx.accounts.feed0,
-        &ctx.accounts.feed1,
-        &ctx.accounts.feed2,
-        &ctx.accounts.feed3,
-        &ctx.accounts.feed4,
-    ];
-    for (i, _feed_id) in token_feed_ids.iter().enumerate() {
-        require!(feed_accounts[i].is_some(), ContestError::InvalidFeeds);
+    // Check that every token has a salary and at least one token fits under the cap
+    if let Some(salary_cap) = &salary_cap {
+        let is_salary_cap_good = salary_cap.token_salaries.len() == token_feed_ids.len()
+            && salary_cap
+                .token_salaries
+                .iter()
+                .any(|&salary| salary <= salary_cap.cap);
+        require!(is_salary_cap_good, ContestError::InvalidSalaryCap);
     }
 
+    // Check that the captain's ROI is not discounted
+    if let Some(captain_multiplier_bps) = captain_multiplier_bps {
+        require!(
+            captain_multiplier_bps >= CAPTAIN_MULTIPLIER_BPS_BASE,
+            ContestError::InvalidCaptainMultiplier
+        );
+    }
+
+    // Check that teams can hold at least one member
+    if let Some(team_config) = &team_config {
+        require!(team_config.max_team_size > 0, ContestError::InvalidTeam);
+    }
+
+    // Rebalance checkpoints must fall strictly inside the contest in increasing order
+    let is_rebalance_times_good = rebalance_times.len() <= MAX_REBALANCE_CHECKPOINTS
+        && rebalance_times.windows(2).all(|v| v[0] < v[1])
+        && rebalance_times
+            .iter()
+            .all(|&time| time > start_time && time < end_time);
+    require!(
+        is_rebalance_times_good,
+        ContestError::InvalidRebalanceCheckpoints
+    );
+
+    // Winners must get some time to claim before their prizes can be swept
+    require!(claim_window != Some(0), ContestError::InvalidClaimWindow);
+
+    // Check that valid feeds are provided
+    load_price_feeds(ctx.remaining_accounts, token_feed_ids.len())?;
+
+    // Only enabled feeds from the registry can be drafted
+    let token_min_confidence_ratios = ctx
+        .accounts
+        .feed_registry
+        .get_min_confidence_ratios(&token_feed_ids)?;
+
+    emit!(ContestCreated {
+        contest: contest.key(),
+        id: ctx.accounts.contest_metadata.token_draft_contest_count,
+        creator: ctx.accounts.signer.key(),
+        start_time,
+        end_time,
+        entry_fee,
+        max_entries,
+        token_feed_ids: token_feed_ids.clone(),
+        token_min_confidence_ratios: token_min_confidence_ratios.clone(),
+        reward_allocation: reward_allocation.clone(),
+        access,
+        salary_cap: salary_cap.clone(),
+        allow_short,
+        captain_multiplier_bps,
+        team_config,
+        rebalance_times: rebalance_times.clone(),
+        claim_window,
+    });
+
     // Set contest parameters
     contest.id = ctx.accounts.contest_metadata.token_draft_contest_count;
     contest.creator = ctx.accounts.signer.key();
@@ -96,7 +179,15 @@ pub fn create_token_draft_contest(
     contest.entry_fee = entry_fee;
     contest.max_entries = max_entries;
     contest.token_feed_ids = token_feed_ids;
+    contest.token_min_confidence_ratios = token_min_confidence_ratios;
     contest.is_resolved = false;
+    contest.access = access;
+    contest.salary_cap = salary_cap;
+    contest.allow_short = allow_short;
+    contest.captain_multiplier_bps = captain_multiplier_bps;
+    contest.team_config = team_config;
+    contest.rebalance_times = rebalance_times;
+    contest.claim_window = claim_window;
 
     // Initialize credit data
     ctx.accounts.contest_credits.contest_key = contest.key();
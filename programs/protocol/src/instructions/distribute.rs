@@ -0,0 +1,163 @@
+use crate::constants::seeds::{SEED_CONTEST_METADATA, SEED_PROGRAM_TOKEN_ACCOUNT};
+use crate::errors::ContestError;
+use crate::events::{RewardClaimed, RewardsDistributed};
+use crate::state::contest::{TokenDraftContest, DISTRIBUTE_REWARDS_BOUNTY_BPS};
+use crate::state::entry::TokenDraftContestEntry;
+use crate::state::metadata::ContestMetadata;
+use crate::state::team::Team;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use protocol_scoring::payout::calc_bps_share;
+
+#[derive(Accounts)]
+pub struct DistributeTokenDraftContestRewards<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, TokenDraftContest>>,
+
+    #[account(
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// Pays a batch of winners on their behalf, remaining accounts hold each winner's entry, team
+// (team contests only) and token account. The keeper keeps a small bounty out of every payout.
+pub fn distribute_token_draft_contest_rewards<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeTokenDraftContestRewards<'info>>,
+) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    require!(contest.is_resolved, ContestError::ContestNotResolved);
+    require!(!contest.is_claim_expired(), ContestError::ClaimExpired);
+
+    let accounts_per_winner = if contest.team_config.is_some() { 3 } else { 2 };
+    let num_winners = ctx.remaining_accounts.len() / accounts_per_winner;
+    require!(
+        num_winners > 0 && num_winners * accounts_per_winner == ctx.remaining_accounts.len(),
+        ContestError::InvalidPayoutAccounts
+    );
+
+    let fee_percent = ctx
+        .accounts
+        .contest_metadata
+        .token_draft_contest_fee_percent;
+    let mut num_paid = 0;
+    let mut paid_amount = 0;
+    let mut bounty_amount = 0;
+    for winner_accounts in ctx.remaining_accounts.chunks(accounts_per_winner) {
+        let mut contest_entry = Account::<TokenDraftContestEntry>::try_from(&winner_accounts[0])?;
+        require!(
+            contest_entry.contest_key == contest.key(),
+            ContestError::InvalidPayoutAccounts
+        );
+
+        // Entries that already claimed are skipped so a racing claim does not fail the batch
+        if contest_entry.has_claimed {
+            continue;
+        }
+
+        let team = match contest.team_config {
+            Some(_) => Some(Account::<Team>::try_from(&winner_accounts[1])?),
+            None => None,
+        };
+        let reward_amount = contest.entry_reward_amount(
+            contest.key(),
+            &contest_entry,
+            fee_percent,
+            team.as_deref(),
+        )?;
+
+        let user_token_account =
+            InterfaceAccount::<TokenAccount>::try_from(&winner_accounts[accounts_per_winner - 1])?;
+        require!(
+            user_token_account.mint == ctx.accounts.mint.key()
+                && user_token_account.owner == contest_entry.user,
+            ContestError::InvalidPayoutAccounts
+        );
+
+        let bounty = calc_bps_share(reward_amount, DISTRIBUTE_REWARDS_BOUNTY_BPS);
+        let user_amount = reward_amount - bounty;
+        transfer_reward(&ctx, user_token_account.to_account_info(), user_amount)?;
+
+        // Mark the entry as claimed so neither the owner nor another crank can pay it again
+        contest_entry.has_claimed = true;
+        contest_entry.exit(&crate::ID)?;
+
+        emit!(RewardClaimed {
+            contest: contest.key(),
+            entry: contest_entry.key(),
+            user: contest_entry.user,
+            amount: user_amount,
+        });
+
+        num_paid += 1;
+        paid_amount += reward_amount;
+        bounty_amount += bounty;
+    }
+
+    if bounty_amount > 0 {
+        transfer_reward(
+            &ctx,
+            ctx.accounts.signer_token_account.to_account_info(),
+            bounty_amount,
+        )?;
+    }
+
+    emit!(RewardsDistributed {
+        contest: contest.key(),
+        keeper: ctx.accounts.signer.key(),
+        num_paid,
+        bounty_amount,
+    });
+
+    ctx.accounts.contest.num_pending_claims -= num_paid;
+    ctx.accounts.contest.paid_amount += paid_amount;
+
+    Ok(())
+}
+
+fn transfer_reward<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, DistributeTokenDraftContestRewards<'info>>,
+    to: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.program_token_account.to_account_info(),
+        to,
+        authority: ctx.accounts.program_token_account.to_account_info(),
+    };
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_PROGRAM_TOKEN_ACCOUNT,
+        mint_key.as_ref(),
+        &[ctx.bumps.program_token_account],
+    ]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_context, amount, ctx.accounts.mint.decimals)
+}
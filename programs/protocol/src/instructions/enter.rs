@@ -8,10 +8,12 @@ use crate::constants::seeds::{
     SEED_TOKEN_DRAFT_CONTEST_ENTRY,
 };
 use crate::errors::ContestError;
+use crate::events::EntrySubmitted;
 use crate::state::config::Config;
 use crate::state::contest::TokenDraftContest;
 use crate::state::credit::TokenDraftContestCredits;
-use crate::state::entry::{TokenDraftContestEntry, TOTAL_CREDIT_PER_CONTEST};
+use crate::state::entry::TokenDraftContestEntry;
+use crate::state::team::Team;
 
 #[derive(Accounts)]
 pub struct EnterTokenDraftContest<'info> {
@@ -38,7 +40,7 @@ pub struct EnterTokenDraftContest<'info> {
 
     #[account(
         mut,
-        realloc = contest_credits.to_account_info().data_len() + 32,
+        realloc = contest_credits.to_account_info().data_len() + contest.credits_entry_space(),
         realloc::payer = signer,
         realloc::zero = false,
         seeds = [SEED_TOKEN_DRAFT_CONTEST_CREDITS, contest.key().as_ref()],
@@ -46,6 +48,11 @@ pub struct EnterTokenDraftContest<'info> {
     )]
     pub contest_credits: Box<Account<'info, TokenDraftContestCredits>>,
 
+    #[account(mut)]
+    pub team: Option<Box<Account<'info, Team>>>,
+
+    pub invite_signer: Option<Signer<'info>>,
+
     #[account(mut)]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
@@ -71,13 +78,25 @@ pub struct EnterTokenDraftContest<'info> {
 
 pub fn enter_token_draft_contest(
     ctx: Context<EnterTokenDraftContest>,
-    credit_allocation: Vec<u8>,
+    credit_allocation: Vec<i8>,
+    access_proof: Vec<[u8; 32]>,
+    captain: Option<u8>,
 ) -> Result<()> {
     let contest = &mut ctx.accounts.contest;
 
     // Check if the contest entry is closed
     require!(contest.is_entry_active(), ContestError::EntryClosed);
 
+    // Check if the user is allowed to enter a private contest
+    require!(
+        contest.can_enter(
+            &ctx.accounts.signer.key(),
+            &access_proof,
+            ctx.accounts.invite_signer.as_ref().map(|signer| signer.key()),
+        ),
+        ContestError::AccessDenied
+    );
+
     // Check if the contest is already full
     require!(
         contest.num_entries <= contest.max_entries,
@@ -85,15 +104,22 @@ pub fn enter_token_draft_contest(
     );
 
     // Check if allocation is valid
-    let sum_credits: u8 = credit_allocation.iter().sum();
-    require!(
-        sum_credits == TOTAL_CREDIT_PER_CONTEST,
-        ContestError::InvalidDraftTokenDistribution
-    );
-    require!(
-        contest.token_feed_ids.len() == credit_allocation.len(),
-        ContestError::InvalidDraftTokenDistribution
-    );
+    contest.validate_credit_allocation(&credit_allocation)?;
+    contest.validate_captain(&credit_allocation, captain)?;
+
+    // Team contests require every entry to join through a team the user is a member of
+    let team_id = match (&contest.team_config, &mut ctx.accounts.team) {
+        (None, None) => None,
+        (Some(_), Some(team)) => {
+            let weight = team
+                .member_weight(&ctx.accounts.signer.key())
+                .filter(|_| team.contest_key == contest.key())
+                .ok_or(ContestError::NotTeamMember)?;
+            team.entered_weight += weight;
+            Some(team.id)
+        }
+        _ => return err!(ContestError::NotTeamMember),
+    };
 
     // Transfer entry fee from the user's token account to the program's token account
     let cpi_accounts = TransferChecked {
@@ -108,6 +134,7 @@ pub fn enter_token_draft_contest(
 
     // Update number of entries
     contest.num_entries += 1;
+    let num_segments = contest.num_segments();
 
     // Create a new participation record
     let contest_entry = &mut ctx.accounts.contest_entry;
@@ -115,12 +142,31 @@ pub fn enter_token_draft_contest(
     contest_entry.id = contest.num_entries - 1;
     contest_entry.contest_key = ctx.accounts.contest.key();
     contest_entry.credit_allocation = credit_allocation.clone();
+    contest_entry.captain = captain;
+    contest_entry.team_id = team_id;
 
     // Append to credit allocation account
-    ctx.accounts
-        .contest_credits
-        .credit_allocations
-        .append(&mut credit_allocation.clone());
+    // The allocation holds for every segment until the entry rebalances
+    let credit_allocations = &mut ctx.accounts.contest_credits.credit_allocations;
+    for _ in 0..num_segments {
+        credit_allocations.extend_from_slice(&credit_allocation);
+    }
+    if let Some(captain) = captain {
+        credit_allocations.push(captain as i8);
+    }
+    if let Some(team_id) = team_id {
+        ctx.accounts.contest_credits.entry_teams.push(team_id);
+    }
+
+    emit!(EntrySubmitted {
+        contest: ctx.accounts.contest.key(),
+        entry: ctx.accounts.contest_entry.key(),
+        user: ctx.accounts.contest_entry.user,
+        entry_id: ctx.accounts.contest_entry.id,
+        credit_allocation,
+        captain,
+        team_id,
+    });
 
     Ok(())
 }
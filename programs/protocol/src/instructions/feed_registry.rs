@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::seeds::{SEED_CONFIG, SEED_FEED_REGISTRY};
+use crate::errors::ConfigError;
+use crate::events::{FeedRegistryCuratorSet, RegistryFeedAdded, RegistryFeedUpdated};
+use crate::state::config::Config;
+use crate::state::feed_registry::{FeedRegistry, RegisteredFeed, MAX_FEED_SYMBOL_LEN};
+
+#[derive(Accounts)]
+pub struct InitFeedRegistry<'info> {
+    #[account(
+        mut,
+        address = config.admin @ ConfigError::Unauthorized
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + FeedRegistry::INIT_SPACE,
+        seeds = [SEED_FEED_REGISTRY],
+        bump
+    )]
+    pub feed_registry: Box<Account<'info, FeedRegistry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetFeedRegistryCurator<'info> {
+    #[account(address = config.admin @ ConfigError::Unauthorized)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_FEED_REGISTRY],
+        bump
+    )]
+    pub feed_registry: Box<Account<'info, FeedRegistry>>,
+}
+
+#[derive(Accounts)]
+pub struct AddRegistryFeed<'info> {
+    #[account(
+        mut,
+        address = feed_registry.curator @ ConfigError::Unauthorized
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        realloc = feed_registry.to_account_info().data_len() + RegisteredFeed::INIT_SPACE,
+        realloc::payer = signer,
+        realloc::zero = false,
+        seeds = [SEED_FEED_REGISTRY],
+        bump
+    )]
+    pub feed_registry: Box<Account<'info, FeedRegistry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRegistryFeed<'info> {
+    #[account(address = feed_registry.curator @ ConfigError::Unauthorized)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_FEED_REGISTRY],
+        bump
+    )]
+    pub feed_registry: Box<Account<'info, FeedRegistry>>,
+}
+
+pub fn init_feed_registry(ctx: Context<InitFeedRegistry>, curator: Pubkey) -> Result<()> {
+    ctx.accounts.feed_registry.curator = curator;
+
+    emit!(FeedRegistryCuratorSet { curator });
+
+    Ok(())
+}
+
+pub fn set_feed_registry_curator(
+    ctx: Context<SetFeedRegistryCurator>,
+    curator: Pubkey,
+) -> Result<()> {
+    ctx.accounts.feed_registry.curator = curator;
+
+    emit!(FeedRegistryCuratorSet { curator });
+
+    Ok(())
+}
+
+pub fn add_registry_feed(
+    ctx: Context<AddRegistryFeed>,
+    feed_id: Pubkey,
+    symbol: String,
+    min_confidence_ratio: u32,
+) -> Result<()> {
+    let feed_registry = &mut ctx.accounts.feed_registry;
+
+    require!(
+        !symbol.is_empty() && symbol.len() <= MAX_FEED_SYMBOL_LEN,
+        ConfigError::InvalidFeedSymbol
+    );
+    require!(
+        feed_registry.get_feed(&feed_id).is_none(),
+        ConfigError::FeedAlreadyRegistered
+    );
+
+    feed_registry.feeds.push(RegisteredFeed {
+        feed_id,
+        symbol: symbol.clone(),
+        min_confidence_ratio,
+        is_enabled: true,
+    });
+
+    emit!(RegistryFeedAdded {
+        feed_id,
+        symbol,
+        min_confidence_ratio,
+    });
+
+    Ok(())
+}
+
+pub fn update_registry_feed(
+    ctx: Context<UpdateRegistryFeed>,
+    feed_id: Pubkey,
+    min_confidence_ratio: u32,
+    is_enabled: bool,
+) -> Result<()> {
+    let feed = ctx
+        .accounts
+        .feed_registry
+        .feeds
+        .iter_mut()
+        .find(|feed| feed.feed_id == feed_id)
+        .ok_or(ConfigError::FeedNotRegistered)?;
+
+    feed.min_confidence_ratio = min_confidence_ratio;
+    feed.is_enabled = is_enabled;
+
+    emit!(RegistryFeedUpdated {
+        feed_id,
+        min_confidence_ratio,
+        is_enabled,
+    });
+
+    Ok(())
+}
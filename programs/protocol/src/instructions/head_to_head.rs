@@ -0,0 +1,640 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::seeds::{
+    SEED_CONFIG, SEED_CONTEST_METADATA, SEED_FEED_REGISTRY, SEED_HEAD_TO_HEAD_CONTEST,
+    SEED_PROGRAM_TOKEN_ACCOUNT,
+};
+use crate::errors::ContestError;
+use crate::events::{
+    HeadToHeadContestAccepted, HeadToHeadContestCancelled, HeadToHeadContestCreated,
+    HeadToHeadContestResolved, PlayerRefunded, PlayerRewardClaimed, PriceSnapshot, PricesPosted,
+};
+use crate::state::config::Config;
+use crate::state::contest::MAX_TOKEN_PER_DRAFT;
+use crate::state::feed_registry::FeedRegistry;
+use crate::state::head_to_head::HeadToHeadContest;
+use crate::state::metadata::ContestMetadata;
+use crate::utils::price::{calc_publish_delay, get_token_price_updates, load_price_feeds};
+use crate::utils::roi::get_token_rois;
+use protocol_scoring::payout::calc_fee_amount;
+use protocol_scoring::roi::calc_avg_roi;
+
+#[derive(Accounts)]
+#[instruction(start_time: u64, end_time: u64, stake: u64, token_feed_ids: Vec<Pubkey>)]
+pub struct CreateHeadToHeadContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + HeadToHeadContest::space(token_feed_ids.len()),
+        seeds = [SEED_HEAD_TO_HEAD_CONTEST, contest_metadata.head_to_head_contest_count.to_le_bytes().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub contest: Box<Account<'info, HeadToHeadContest>>,
+
+    #[account(
+        seeds = [SEED_FEED_REGISTRY],
+        bump
+    )]
+    pub feed_registry: Box<Account<'info, FeedRegistry>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptHeadToHeadContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, HeadToHeadContest>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelHeadToHeadContest<'info> {
+    #[account(
+        mut,
+        address = contest.challenger @ ContestError::NotParticipant
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        close = signer
+    )]
+    pub contest: Box<Account<'info, HeadToHeadContest>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RefundHeadToHeadContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, HeadToHeadContest>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct PostHeadToHeadContestPrices<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, HeadToHeadContest>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveHeadToHeadContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, HeadToHeadContest>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimHeadToHeadContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, HeadToHeadContest>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_head_to_head_contest(
+    ctx: Context<CreateHeadToHeadContest>,
+    start_time: u64,
+    end_time: u64,
+    stake: u64,
+    token_feed_ids: Vec<Pubkey>,
+    credit_allocation: Vec<i8>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    // Duel must start in some future time
+    require!(start_time > current_time, ContestError::InvalidDuration);
+
+    // Duel must end later than it starts
+    require!(end_time > start_time, ContestError::InvalidDuration);
+
+    // At least one token must be selected for the draft and no more than MAX_TOKEN_PER_DRAFT
+    require!(
+        !token_feed_ids.is_empty() && token_feed_ids.len() <= MAX_TOKEN_PER_DRAFT,
+        ContestError::InvalidDraftTokenCount
+    );
+
+    // Check that valid feeds are provided
+    load_price_feeds(ctx.remaining_accounts, token_feed_ids.len())?;
+
+    // Only enabled feeds from the registry can be drafted
+    let token_min_confidence_ratios = ctx
+        .accounts
+        .feed_registry
+        .get_min_confidence_ratios(&token_feed_ids)?;
+
+    // Set duel parameters
+    let contest = &mut ctx.accounts.contest;
+    contest.id = ctx.accounts.contest_metadata.head_to_head_contest_count;
+    contest.challenger = ctx.accounts.signer.key();
+    contest.start_time = start_time;
+    contest.end_time = end_time;
+    contest.stake = stake;
+    contest.token_feed_ids = token_feed_ids;
+    contest.token_min_confidence_ratios = token_min_confidence_ratios;
+
+    // Check if the challenger's allocation is valid
+    contest.validate_credit_allocation(&credit_allocation)?;
+    contest.challenger_allocation = credit_allocation.clone();
+
+    // Transfer the stake from the challenger's token account to the program's token account
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.signer_token_account.to_account_info(),
+        to: ctx.accounts.program_token_account.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+    transfer_checked(cpi_context, stake, ctx.accounts.mint.decimals)?;
+
+    // Update contest metadata
+    ctx.accounts.contest_metadata.head_to_head_contest_count += 1;
+
+    let contest = &ctx.accounts.contest;
+    emit!(HeadToHeadContestCreated {
+        contest: contest.key(),
+        id: contest.id,
+        challenger: contest.challenger,
+        start_time,
+        end_time,
+        stake,
+        token_feed_ids: contest.token_feed_ids.clone(),
+        credit_allocation,
+    });
+
+    Ok(())
+}
+
+pub fn accept_head_to_head_contest(
+    ctx: Context<AcceptHeadToHeadContest>,
+    credit_allocation: Vec<i8>,
+) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+
+    // Check if the duel can still be accepted
+    require!(contest.is_entry_active(), ContestError::EntryClosed);
+    require!(
+        !contest.is_accepted(),
+        ContestError::ChallengeAlreadyAccepted
+    );
+    require_keys_neq!(
+        ctx.accounts.signer.key(),
+        contest.challenger,
+        ContestError::SelfChallenge
+    );
+
+    // Check if the opponent's allocation is valid
+    contest.validate_credit_allocation(&credit_allocation)?;
+
+    // Transfer the matching stake from the opponent's token account to the program's token account
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.signer_token_account.to_account_info(),
+        to: ctx.accounts.program_token_account.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+    transfer_checked(cpi_context, contest.stake, ctx.accounts.mint.decimals)?;
+
+    contest.opponent = Some(ctx.accounts.signer.key());
+    contest.opponent_allocation = credit_allocation.clone();
+
+    emit!(HeadToHeadContestAccepted {
+        contest: contest.key(),
+        opponent: ctx.accounts.signer.key(),
+        credit_allocation,
+    });
+
+    Ok(())
+}
+
+pub fn cancel_head_to_head_contest(ctx: Context<CancelHeadToHeadContest>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    // Only a duel nobody accepted can be called off
+    require!(
+        !contest.is_accepted(),
+        ContestError::ChallengeAlreadyAccepted
+    );
+
+    // Refund the stake to the challenger
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.program_token_account.to_account_info(),
+        to: ctx.accounts.signer_token_account.to_account_info(),
+        authority: ctx.accounts.program_token_account.to_account_info(),
+    };
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_PROGRAM_TOKEN_ACCOUNT,
+        mint_key.as_ref(),
+        &[ctx.bumps.program_token_account],
+    ]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_context, contest.stake, ctx.accounts.mint.decimals)?;
+
+    emit!(HeadToHeadContestCancelled {
+        contest: contest.key(),
+        challenger: contest.challenger,
+        amount: contest.stake,
+    });
+
+    Ok(())
+}
+
+pub fn post_head_to_head_contest_prices(ctx: Context<PostHeadToHeadContestPrices>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    require!(!contest.is_entry_active(), ContestError::ContestNotStarted);
+    require!(contest.is_accepted(), ContestError::ChallengeNotAccepted);
+    require!(
+        contest.token_start_prices.is_empty(),
+        ContestError::PricesAlreadyPosted
+    );
+
+    // Once the posting window is missed both stakes are refunded instead
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    require!(
+        current_time <= contest.start_price_deadline(),
+        ContestError::PricePostingClosed
+    );
+
+    // Set start prices for each token, published right at the start
+    let (token_start_prices, publish_times) = get_token_price_updates(
+        ctx.remaining_accounts,
+        &contest.token_feed_ids,
+        &contest.token_min_confidence_ratios,
+    )?;
+    calc_publish_delay(&publish_times, contest.start_time)?;
+    ctx.accounts.contest.token_start_prices = token_start_prices.clone();
+
+    emit!(PricesPosted {
+        contest: ctx.accounts.contest.key(),
+        snapshot: PriceSnapshot::Start,
+        token_prices: token_start_prices,
+        publish_times,
+    });
+
+    Ok(())
+}
+
+// Either player takes their stake back from an accepted duel that can never be resolved
+pub fn refund_head_to_head_contest(ctx: Context<RefundHeadToHeadContest>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let signer_key = ctx.accounts.signer.key();
+
+    // A duel nobody accepted is cancelled by the challenger instead
+    require!(contest.is_accepted(), ContestError::ChallengeNotAccepted);
+    require!(
+        contest.has_missed_price_posting(),
+        ContestError::PricePostingOpen
+    );
+
+    let is_challenger = signer_key == contest.challenger;
+    let is_opponent = contest.opponent == Some(signer_key);
+    require!(is_challenger || is_opponent, ContestError::NotParticipant);
+
+    let has_claimed = if is_challenger {
+        contest.has_challenger_claimed
+    } else {
+        contest.has_opponent_claimed
+    };
+    require!(!has_claimed, ContestError::AlreadyClaimed);
+
+    // Refund the stake to the player
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.program_token_account.to_account_info(),
+        to: ctx.accounts.signer_token_account.to_account_info(),
+        authority: ctx.accounts.program_token_account.to_account_info(),
+    };
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_PROGRAM_TOKEN_ACCOUNT,
+        mint_key.as_ref(),
+        &[ctx.bumps.program_token_account],
+    ]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_context, contest.stake, ctx.accounts.mint.decimals)?;
+
+    // Mark the player as refunded
+    if is_challenger {
+        contest.has_challenger_claimed = true;
+    } else {
+        contest.has_opponent_claimed = true;
+    }
+
+    emit!(PlayerRefunded {
+        contest: contest.key(),
+        user: signer_key,
+        amount: contest.stake,
+    });
+
+    Ok(())
+}
+
+pub fn resolve_head_to_head_contest(ctx: Context<ResolveHeadToHeadContest>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    require!(
+        !contest.token_start_prices.is_empty(),
+        ContestError::ContestPriceNotSet
+    );
+
+    // Check that end time has passed
+    require!(contest.has_ended(), ContestError::ContestNotEnded);
+
+    require!(!contest.is_resolved, ContestError::AlreadyResolved);
+
+    let token_rois = get_token_rois(
+        ctx.remaining_accounts,
+        &contest.token_feed_ids,
+        &contest.token_start_prices,
+        &contest.token_min_confidence_ratios,
+        contest.end_time,
+    )?;
+
+    // The higher weighted ROI wins the duel
+    let challenger_roi = calc_avg_roi(&contest.challenger_allocation, &token_rois, None);
+    let opponent_roi = calc_avg_roi(&contest.opponent_allocation, &token_rois, None);
+    let winner = if challenger_roi > opponent_roi {
+        Some(contest.challenger)
+    } else if opponent_roi > challenger_roi {
+        contest.opponent
+    } else {
+        None
+    };
+
+    let contest = &mut ctx.accounts.contest;
+    contest.token_rois = token_rois.clone();
+    contest.winner = winner;
+    contest.is_resolved = true;
+
+    // Accumulate the fee amount from this duel
+    let total_pool_amount = contest.pool_amount();
+    let fee_amount = calc_fee_amount(
+        total_pool_amount,
+        ctx.accounts
+            .contest_metadata
+            .token_draft_contest_fee_percent,
+    );
+    ctx.accounts.contest_metadata.token_draft_contest_fee_amount += fee_amount;
+
+    emit!(HeadToHeadContestResolved {
+        contest: ctx.accounts.contest.key(),
+        token_rois,
+        challenger_roi,
+        opponent_roi,
+        winner,
+        fee_amount,
+    });
+
+    Ok(())
+}
+
+pub fn claim_head_to_head_contest(ctx: Context<ClaimHeadToHeadContest>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let signer_key = ctx.accounts.signer.key();
+
+    // Check if the duel has already been resolved
+    require!(contest.is_resolved, ContestError::ContestNotResolved);
+
+    let is_challenger = signer_key == contest.challenger;
+    let is_opponent = contest.opponent == Some(signer_key);
+    require!(is_challenger || is_opponent, ContestError::NotParticipant);
+
+    // Check if the player has already claimed their rewards
+    let has_claimed = if is_challenger {
+        contest.has_challenger_claimed
+    } else {
+        contest.has_opponent_claimed
+    };
+    require!(!has_claimed, ContestError::AlreadyClaimed);
+
+    // Calculate the player reward amount, a tie splits the pot evenly
+    let total_pool_amount = contest.pool_amount();
+    let fee_amount = calc_fee_amount(
+        total_pool_amount,
+        ctx.accounts
+            .contest_metadata
+            .token_draft_contest_fee_percent,
+    );
+    let total_reward_amount = total_pool_amount - fee_amount;
+    let user_reward_amount = match contest.winner {
+        Some(winner) => {
+            require_keys_eq!(winner, signer_key, ContestError::NotWinner);
+            total_reward_amount
+        }
+        None => total_reward_amount / 2,
+    };
+
+    // Transfer the reward to the player's token account
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.program_token_account.to_account_info(),
+        to: ctx.accounts.signer_token_account.to_account_info(),
+        authority: ctx.accounts.program_token_account.to_account_info(),
+    };
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_PROGRAM_TOKEN_ACCOUNT,
+        mint_key.as_ref(),
+        &[ctx.bumps.program_token_account],
+    ]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_context, user_reward_amount, ctx.accounts.mint.decimals)?;
+
+    // Mark the player as claimed
+    if is_challenger {
+        contest.has_challenger_claimed = true;
+    } else {
+        contest.has_opponent_claimed = true;
+    }
+
+    emit!(PlayerRewardClaimed {
+        contest: contest.key(),
+        user: signer_key,
+        amount: user_reward_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use crate::constants::seeds::SEED_PROGRAM_TOKEN_ACCOUNT;
+use crate::errors::ContestError;
+use crate::events::KeeperBountiesPaid;
+use crate::state::contest::TokenDraftContest;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct PayTokenDraftContestKeeperBounties<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, TokenDraftContest>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = Some(price_poster_token_account.owner) == contest.price_poster @ ContestError::InvalidPayoutAccounts
+    )]
+    pub price_poster_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = Some(resolver_token_account.owner) == contest.resolver @ ContestError::InvalidPayoutAccounts
+    )]
+    pub resolver_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// Pays the keepers that posted the start prices and resolved the contest, anyone can push the
+// payment so the creator is never stuck waiting on a keeper before closing the contest
+pub fn pay_token_draft_contest_keeper_bounties(
+    ctx: Context<PayTokenDraftContestKeeperBounties>,
+) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    require!(contest.is_resolved, ContestError::ContestNotResolved);
+    require!(
+        !contest.has_paid_keeper_bounties,
+        ContestError::AlreadyClaimed
+    );
+
+    let bounty_amount = contest.keeper_bounty_amount;
+    if bounty_amount > 0 {
+        transfer_bounty(
+            ctx.accounts,
+            ctx.bumps.program_token_account,
+            ctx.accounts.price_poster_token_account.to_account_info(),
+            bounty_amount,
+        )?;
+        transfer_bounty(
+            ctx.accounts,
+            ctx.bumps.program_token_account,
+            ctx.accounts.resolver_token_account.to_account_info(),
+            bounty_amount,
+        )?;
+    }
+
+    emit!(KeeperBountiesPaid {
+        contest: contest.key(),
+        price_poster: ctx.accounts.price_poster_token_account.owner,
+        resolver: ctx.accounts.resolver_token_account.owner,
+        bounty_amount,
+    });
+
+    ctx.accounts.contest.has_paid_keeper_bounties = true;
+
+    Ok(())
+}
+
+fn transfer_bounty<'info>(
+    accounts: &PayTokenDraftContestKeeperBounties<'info>,
+    bump: u8,
+    to: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let cpi_accounts = TransferChecked {
+        mint: accounts.mint.to_account_info(),
+        from: accounts.program_token_account.to_account_info(),
+        to,
+        authority: accounts.program_token_account.to_account_info(),
+    };
+    let mint_key = accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[SEED_PROGRAM_TOKEN_ACCOUNT, mint_key.as_ref(), &[bump]]];
+    let cpi_program = accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_context, amount, accounts.mint.decimals)
+}
@@ -1,19 +1,49 @@
+pub mod cancel;
 pub mod claim;
+pub mod close;
 pub mod create;
 pub mod delegate_er;
+pub mod distribute;
 pub mod enter;
+pub mod feed_registry;
+pub mod head_to_head;
 pub mod initialize;
+pub mod keeper;
+pub mod over_under;
+pub mod parimutuel;
 pub mod post_prices;
+pub mod range;
+pub mod rebalance;
 pub mod resolve;
 pub mod resolve_er;
+pub mod season;
+pub mod snake_draft;
+pub mod survivor;
+pub mod sweep;
+pub mod team;
 pub mod withdraw_fee;
 
+pub use cancel::*;
 pub use claim::*;
+pub use close::*;
 pub use create::*;
 pub use delegate_er::*;
+pub use distribute::*;
 pub use enter::*;
+pub use feed_registry::*;
+pub use head_to_head::*;
 pub use initialize::*;
+pub use keeper::*;
+pub use over_under::*;
+pub use parimutuel::*;
 pub use post_prices::*;
+pub use range::*;
+pub use rebalance::*;
 pub use resolve::*;
 pub use resolve_er::*;
+pub use season::*;
+pub use snake_draft::*;
+pub use survivor::*;
+pub use sweep::*;
+pub use team::*;
 pub use withdraw_fee::*;
@@ -0,0 +1,381 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::seeds::{
+    SEED_CONFIG, SEED_CONTEST_METADATA, SEED_FEED_REGISTRY, SEED_OVER_UNDER_CONTEST,
+    SEED_OVER_UNDER_ENTRY, SEED_PROGRAM_TOKEN_ACCOUNT,
+};
+use crate::errors::ContestError;
+use crate::events::{
+    OverUnderContestCreated, OverUnderContestResolved, OverUnderEntrySubmitted, RewardClaimed,
+};
+use crate::state::config::Config;
+use crate::state::feed_registry::FeedRegistry;
+use crate::state::metadata::ContestMetadata;
+use crate::state::over_under::{OverUnderContest, OverUnderEntry, OverUnderSide};
+use crate::utils::price::{calc_publish_delay, get_token_price_updates, load_price_feeds};
+use protocol_scoring::payout::{calc_fee_amount, calc_parimutuel_payout};
+
+#[derive(Accounts)]
+pub struct CreateOverUnderContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + OverUnderContest::INIT_SPACE,
+        seeds = [SEED_OVER_UNDER_CONTEST, contest_metadata.over_under_contest_count.to_le_bytes().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub contest: Box<Account<'info, OverUnderContest>>,
+
+    #[account(
+        seeds = [SEED_FEED_REGISTRY],
+        bump
+    )]
+    pub feed_registry: Box<Account<'info, FeedRegistry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct EnterOverUnderContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, OverUnderContest>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + OverUnderEntry::INIT_SPACE,
+        seeds = [SEED_OVER_UNDER_ENTRY, contest.key().as_ref(), signer.key().as_ref()],
+        bump,
+    )]
+    pub contest_entry: Box<Account<'info, OverUnderEntry>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveOverUnderContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, OverUnderContest>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimOverUnderContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    pub contest: Box<Account<'info, OverUnderContest>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_OVER_UNDER_ENTRY, contest.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub contest_entry: Box<Account<'info, OverUnderEntry>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_over_under_contest(
+    ctx: Context<CreateOverUnderContest>,
+    start_time: u64,
+    end_time: u64,
+    feed_id: Pubkey,
+    strike_price: f64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    // Contest must start in some future time
+    require!(start_time > current_time, ContestError::InvalidDuration);
+
+    // Contest must end later than it starts
+    require!(end_time > start_time, ContestError::InvalidDuration);
+
+    require!(
+        strike_price.is_finite() && strike_price > 0.0,
+        ContestError::InvalidStrikePrice
+    );
+
+    // Check that a valid feed is provided
+    load_price_feeds(ctx.remaining_accounts, 1)?;
+
+    // Only enabled feeds from the registry can be used
+    let min_confidence_ratios = ctx
+        .accounts
+        .feed_registry
+        .get_min_confidence_ratios(&[feed_id])?;
+
+    // Set contest parameters
+    let contest = &mut ctx.accounts.contest;
+    contest.id = ctx.accounts.contest_metadata.over_under_contest_count;
+    contest.creator = ctx.accounts.signer.key();
+    contest.start_time = start_time;
+    contest.end_time = end_time;
+    contest.feed_id = feed_id;
+    contest.min_confidence_ratio = min_confidence_ratios[0];
+    contest.strike_price = strike_price;
+
+    // Update contest metadata
+    ctx.accounts.contest_metadata.over_under_contest_count += 1;
+
+    emit!(OverUnderContestCreated {
+        contest: ctx.accounts.contest.key(),
+        id: ctx.accounts.contest.id,
+        creator: ctx.accounts.signer.key(),
+        start_time,
+        end_time,
+        feed_id,
+        strike_price,
+    });
+
+    Ok(())
+}
+
+pub fn enter_over_under_contest(
+    ctx: Context<EnterOverUnderContest>,
+    side: OverUnderSide,
+    amount: u64,
+) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+
+    // Check if the contest entry is closed
+    require!(contest.is_entry_active(), ContestError::EntryClosed);
+
+    require!(amount > 0, ContestError::InsufficientAmount);
+
+    // Transfer the stake from the user's token account to the program's token account
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.signer_token_account.to_account_info(),
+        to: ctx.accounts.program_token_account.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+    transfer_checked(cpi_context, amount, ctx.accounts.mint.decimals)?;
+
+    // Update the side totals
+    match side {
+        OverUnderSide::Over => contest.over_amount += amount,
+        OverUnderSide::Under => contest.under_amount += amount,
+    }
+
+    // Create a new participation record
+    let contest_entry = &mut ctx.accounts.contest_entry;
+    contest_entry.user = ctx.accounts.signer.key();
+    contest_entry.contest_key = contest.key();
+    contest_entry.side = side;
+    contest_entry.amount = amount;
+
+    emit!(OverUnderEntrySubmitted {
+        contest: contest.key(),
+        entry: contest_entry.key(),
+        user: contest_entry.user,
+        side,
+        amount,
+    });
+
+    Ok(())
+}
+
+pub fn resolve_over_under_contest(ctx: Context<ResolveOverUnderContest>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    // Check that end time has passed
+    require!(contest.has_ended(), ContestError::ContestNotEnded);
+
+    require!(!contest.is_resolved, ContestError::AlreadyResolved);
+
+    // The end price must be published right at the end time so the resolver cannot pick it
+    let (end_prices, publish_times) = get_token_price_updates(
+        ctx.remaining_accounts,
+        &[contest.feed_id],
+        &[contest.min_confidence_ratio],
+    )?;
+    calc_publish_delay(&publish_times, contest.end_time)?;
+    let end_price = end_prices[0];
+
+    let outcome = if end_price > contest.strike_price {
+        Some(OverUnderSide::Over)
+    } else if end_price < contest.strike_price {
+        Some(OverUnderSide::Under)
+    } else {
+        None
+    };
+    let outcome = outcome.filter(|&side| contest.side_amount(side) > 0);
+
+    let contest = &mut ctx.accounts.contest;
+    contest.end_price = end_price;
+    contest.outcome = outcome;
+    contest.is_resolved = true;
+
+    // Accumulate the fee amount from this contest, void markets are refunded in full
+    let mut fee_amount = 0;
+    if outcome.is_some() {
+        let total_pool_amount = contest.pool_amount();
+        fee_amount = calc_fee_amount(
+            total_pool_amount,
+            ctx.accounts
+                .contest_metadata
+                .token_draft_contest_fee_percent,
+        );
+        ctx.accounts.contest_metadata.token_draft_contest_fee_amount += fee_amount;
+    }
+
+    emit!(OverUnderContestResolved {
+        contest: contest.key(),
+        end_price,
+        publish_time: publish_times[0],
+        outcome,
+        fee_amount,
+    });
+
+    Ok(())
+}
+
+pub fn claim_over_under_contest(ctx: Context<ClaimOverUnderContest>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    let contest_entry = &mut ctx.accounts.contest_entry;
+
+    // Check if the contest has already been resolved
+    require!(contest.is_resolved, ContestError::ContestNotResolved);
+
+    // Check if the user has already claimed their rewards
+    require!(!contest_entry.has_claimed, ContestError::AlreadyClaimed);
+
+    // Calculate the user reward amount based on their share of the winning side
+    let user_reward_amount = match contest.outcome {
+        Some(side) => {
+            require!(contest_entry.side == side, ContestError::NotWinner);
+
+            let total_pool_amount = contest.pool_amount();
+            let fee_amount = calc_fee_amount(
+                total_pool_amount,
+                ctx.accounts
+                    .contest_metadata
+                    .token_draft_contest_fee_percent,
+            );
+            calc_parimutuel_payout(
+                contest_entry.amount,
+                contest.side_amount(side),
+                total_pool_amount,
+                fee_amount,
+            )
+        }
+        None => contest_entry.amount,
+    };
+
+    // Transfer the reward to the user's token account
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.program_token_account.to_account_info(),
+        to: ctx.accounts.signer_token_account.to_account_info(),
+        authority: ctx.accounts.program_token_account.to_account_info(),
+    };
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_PROGRAM_TOKEN_ACCOUNT,
+        mint_key.as_ref(),
+        &[ctx.bumps.program_token_account],
+    ]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_context, user_reward_amount, ctx.accounts.mint.decimals)?;
+
+    // Mark the entry as claimed
+    contest_entry.has_claimed = true;
+
+    emit!(RewardClaimed {
+        contest: contest.key(),
+        entry: contest_entry.key(),
+        user: contest_entry.user,
+        amount: user_reward_amount,
+    });
+
+    Ok(())
+}
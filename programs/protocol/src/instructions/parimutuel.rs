@@ -0,0 +1,475 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::seeds::{
+    SEED_CONFIG, SEED_CONTEST_METADATA, SEED_FEED_REGISTRY, SEED_PARIMUTUEL_CONTEST,
+    SEED_PARIMUTUEL_ENTRY, SEED_PROGRAM_TOKEN_ACCOUNT,
+};
+use crate::errors::ContestError;
+use crate::events::{
+    ParimutuelContestCancelled, ParimutuelContestCreated, ParimutuelContestResolved,
+    ParimutuelEntrySubmitted, PriceSnapshot, PricesPosted, RewardClaimed,
+};
+use crate::state::config::Config;
+use crate::state::contest::MAX_TOKEN_PER_DRAFT;
+use crate::state::feed_registry::FeedRegistry;
+use crate::state::metadata::ContestMetadata;
+use crate::state::parimutuel::{ParimutuelContest, ParimutuelEntry};
+use crate::utils::price::{calc_publish_delay, get_token_price_updates, load_price_feeds};
+use crate::utils::roi::get_token_rois;
+use protocol_scoring::payout::{calc_fee_amount, calc_parimutuel_payout};
+
+#[derive(Accounts)]
+#[instruction(start_time: u64, end_time: u64, token_feed_ids: Vec<Pubkey>)]
+pub struct CreateParimutuelContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + ParimutuelContest::space(token_feed_ids.len()),
+        seeds = [SEED_PARIMUTUEL_CONTEST, contest_metadata.parimutuel_contest_count.to_le_bytes().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub contest: Box<Account<'info, ParimutuelContest>>,
+
+    #[account(
+        seeds = [SEED_FEED_REGISTRY],
+        bump
+    )]
+    pub feed_registry: Box<Account<'info, FeedRegistry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct EnterParimutuelContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, ParimutuelContest>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + ParimutuelEntry::INIT_SPACE,
+        seeds = [SEED_PARIMUTUEL_ENTRY, contest.key().as_ref(), signer.key().as_ref()],
+        bump,
+    )]
+    pub contest_entry: Box<Account<'info, ParimutuelEntry>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PostParimutuelContestPrices<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, ParimutuelContest>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelParimutuelContest<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, ParimutuelContest>>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveParimutuelContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, ParimutuelContest>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimParimutuelContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    pub contest: Box<Account<'info, ParimutuelContest>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PARIMUTUEL_ENTRY, contest.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub contest_entry: Box<Account<'info, ParimutuelEntry>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_parimutuel_contest(
+    ctx: Context<CreateParimutuelContest>,
+    start_time: u64,
+    end_time: u64,
+    token_feed_ids: Vec<Pubkey>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    // Contest must start in some future time
+    require!(start_time > current_time, ContestError::InvalidDuration);
+
+    // Contest must end later than it starts
+    require!(end_time > start_time, ContestError::InvalidDuration);
+
+    // At least two tokens must race against each other and no more than MAX_TOKEN_PER_DRAFT
+    require!(
+        token_feed_ids.len() > 1 && token_feed_ids.len() <= MAX_TOKEN_PER_DRAFT,
+        ContestError::InvalidDraftTokenCount
+    );
+
+    // Check that valid feeds are provided
+    load_price_feeds(ctx.remaining_accounts, token_feed_ids.len())?;
+
+    // Only enabled feeds from the registry can be used
+    let token_min_confidence_ratios = ctx
+        .accounts
+        .feed_registry
+        .get_min_confidence_ratios(&token_feed_ids)?;
+
+    // Set contest parameters
+    let contest = &mut ctx.accounts.contest;
+    contest.id = ctx.accounts.contest_metadata.parimutuel_contest_count;
+    contest.creator = ctx.accounts.signer.key();
+    contest.start_time = start_time;
+    contest.end_time = end_time;
+    contest.token_stake_amounts = vec![0; token_feed_ids.len()];
+    contest.token_feed_ids = token_feed_ids;
+    contest.token_min_confidence_ratios = token_min_confidence_ratios;
+
+    // Update contest metadata
+    ctx.accounts.contest_metadata.parimutuel_contest_count += 1;
+
+    let contest = &ctx.accounts.contest;
+    emit!(ParimutuelContestCreated {
+        contest: contest.key(),
+        id: contest.id,
+        creator: contest.creator,
+        start_time,
+        end_time,
+        token_feed_ids: contest.token_feed_ids.clone(),
+    });
+
+    Ok(())
+}
+
+pub fn enter_parimutuel_contest(
+    ctx: Context<EnterParimutuelContest>,
+    token_index: u8,
+    amount: u64,
+) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+
+    // Check if the contest entry is closed
+    require!(contest.is_entry_active(), ContestError::EntryClosed);
+
+    require!(
+        (token_index as usize) < contest.token_feed_ids.len(),
+        ContestError::InvalidTokenIndex
+    );
+    require!(amount > 0, ContestError::InsufficientAmount);
+
+    // Transfer the stake from the user's token account to the program's token account
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.signer_token_account.to_account_info(),
+        to: ctx.accounts.program_token_account.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+    transfer_checked(cpi_context, amount, ctx.accounts.mint.decimals)?;
+
+    // Update the token totals
+    contest.token_stake_amounts[token_index as usize] += amount;
+
+    // Create a new participation record
+    let contest_entry = &mut ctx.accounts.contest_entry;
+    contest_entry.user = ctx.accounts.signer.key();
+    contest_entry.contest_key = contest.key();
+    contest_entry.token_index = token_index;
+    contest_entry.amount = amount;
+
+    emit!(ParimutuelEntrySubmitted {
+        contest: contest.key(),
+        entry: contest_entry.key(),
+        user: contest_entry.user,
+        token_index,
+        amount,
+    });
+
+    Ok(())
+}
+
+pub fn post_parimutuel_contest_prices(ctx: Context<PostParimutuelContestPrices>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    require!(!contest.is_entry_active(), ContestError::ContestNotStarted);
+    require!(
+        contest.token_start_prices.is_empty(),
+        ContestError::PricesAlreadyPosted
+    );
+
+    // Once the posting window is missed the market can only be cancelled
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    require!(
+        current_time <= contest.start_price_deadline(),
+        ContestError::PricePostingClosed
+    );
+
+    // Set start prices for each token, published right at the start
+    let (token_start_prices, publish_times) = get_token_price_updates(
+        ctx.remaining_accounts,
+        &contest.token_feed_ids,
+        &contest.token_min_confidence_ratios,
+    )?;
+    calc_publish_delay(&publish_times, contest.start_time)?;
+    ctx.accounts.contest.token_start_prices = token_start_prices.clone();
+
+    emit!(PricesPosted {
+        contest: ctx.accounts.contest.key(),
+        snapshot: PriceSnapshot::Start,
+        token_prices: token_start_prices,
+        publish_times,
+    });
+
+    Ok(())
+}
+
+// Anyone can void a market whose start prices were not posted within the posting window, every
+// stake is then refunded in full through the claim instruction
+pub fn cancel_parimutuel_contest(ctx: Context<CancelParimutuelContest>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+
+    require!(!contest.is_resolved, ContestError::AlreadyResolved);
+    require!(
+        contest.token_start_prices.is_empty(),
+        ContestError::PricesAlreadyPosted
+    );
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    require!(
+        current_time > contest.start_price_deadline(),
+        ContestError::PricePostingOpen
+    );
+
+    contest.winning_token = None;
+    contest.is_resolved = true;
+
+    emit!(ParimutuelContestCancelled {
+        contest: contest.key(),
+        pool_amount: contest.pool_amount(),
+    });
+
+    Ok(())
+}
+
+pub fn resolve_parimutuel_contest(ctx: Context<ResolveParimutuelContest>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    require!(
+        !contest.token_start_prices.is_empty(),
+        ContestError::ContestPriceNotSet
+    );
+
+    // Check that end time has passed
+    require!(contest.has_ended(), ContestError::ContestNotEnded);
+
+    require!(!contest.is_resolved, ContestError::AlreadyResolved);
+
+    let token_rois = get_token_rois(
+        ctx.remaining_accounts,
+        &contest.token_feed_ids,
+        &contest.token_start_prices,
+        &contest.token_min_confidence_ratios,
+        contest.end_time,
+    )?;
+
+    // The token with the best ROI wins unless it is tied or nobody backed it
+    let best_roi = token_rois.iter().copied().fold(f64::MIN, f64::max);
+    let mut best_tokens = (0..token_rois.len()).filter(|&i| token_rois[i] == best_roi);
+    let winning_token = match (best_tokens.next(), best_tokens.next()) {
+        (Some(token_index), None) if contest.token_stake_amounts[token_index] > 0 => {
+            Some(token_index as u8)
+        }
+        _ => None,
+    };
+
+    let contest = &mut ctx.accounts.contest;
+    contest.token_rois = token_rois.clone();
+    contest.winning_token = winning_token;
+    contest.is_resolved = true;
+
+    // Accumulate the fee amount from this contest, void markets are refunded in full
+    let mut fee_amount = 0;
+    if winning_token.is_some() {
+        let total_pool_amount = contest.pool_amount();
+        fee_amount = calc_fee_amount(
+            total_pool_amount,
+            ctx.accounts
+                .contest_metadata
+                .token_draft_contest_fee_percent,
+        );
+        ctx.accounts.contest_metadata.token_draft_contest_fee_amount += fee_amount;
+    }
+
+    emit!(ParimutuelContestResolved {
+        contest: contest.key(),
+        token_rois,
+        winning_token,
+        fee_amount,
+    });
+
+    Ok(())
+}
+
+pub fn claim_parimutuel_contest(ctx: Context<ClaimParimutuelContest>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    let contest_entry = &mut ctx.accounts.contest_entry;
+
+    // Check if the contest has already been resolved
+    require!(contest.is_resolved, ContestError::ContestNotResolved);
+
+    // Check if the user has already claimed their rewards
+    require!(!contest_entry.has_claimed, ContestError::AlreadyClaimed);
+
+    // Calculate the user reward amount based on their share of the winning token's stakes
+    let user_reward_amount = match contest.winning_token {
+        Some(winning_token) => {
+            require!(
+                contest_entry.token_index == winning_token,
+                ContestError::NotWinner
+            );
+
+            let total_pool_amount = contest.pool_amount();
+            let fee_amount = calc_fee_amount(
+                total_pool_amount,
+                ctx.accounts
+                    .contest_metadata
+                    .token_draft_contest_fee_percent,
+            );
+            calc_parimutuel_payout(
+                contest_entry.amount,
+                contest.token_stake_amounts[winning_token as usize],
+                total_pool_amount,
+                fee_amount,
+            )
+        }
+        None => contest_entry.amount,
+    };
+
+    // Transfer the reward to the user's token account
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.program_token_account.to_account_info(),
+        to: ctx.accounts.signer_token_account.to_account_info(),
+        authority: ctx.accounts.program_token_account.to_account_info(),
+    };
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_PROGRAM_TOKEN_ACCOUNT,
+        mint_key.as_ref(),
+        &[ctx.bumps.program_token_account],
+    ]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_context, user_reward_amount, ctx.accounts.mint.decimals)?;
+
+    // Mark the entry as claimed
+    contest_entry.has_claimed = true;
+
+    emit!(RewardClaimed {
+        contest: contest.key(),
+        entry: contest_entry.key(),
+        user: contest_entry.user,
+        amount: user_reward_amount,
+    });
+
+    Ok(())
+}
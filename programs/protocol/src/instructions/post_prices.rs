@@ -1,7 +1,8 @@
 use crate::errors::ContestError;
+use crate::events::{PriceSnapshot, PricesPosted};
 use crate::state::contest::TokenDraftContest;
+use crate::utils::price::{calc_publish_delay, get_token_price_updates};
 use anchor_lang::prelude::*;
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 #[derive(Accounts)]
 pub struct PostTokenDraftContestPrices<'info> {
@@ -11,53 +12,109 @@ pub struct PostTokenDraftContestPrices<'info> {
     #[account(mut)]
     pub contest: Box<Account<'info, TokenDraftContest>>,
 
-    pub feed0: Option<Box<Account<'info, PriceUpdateV2>>>,
-    pub feed1: Option<Box<Account<'info, PriceUpdateV2>>>,
-    pub feed2: Option<Box<Account<'info, PriceUpdateV2>>>,
-    pub feed3: Option<Box<Account<'info, PriceUpdateV2>>>,
-    pub feed4: Option<Box<Account<'info, PriceUpdateV2>>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PostTokenDraftContestCheckpoint<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, TokenDraftContest>>,
 
     pub system_program: Program<'info, System>,
 }
 
 pub fn post_token_draft_contest_prices(ctx: Context<PostTokenDraftContestPrices>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    require!(!contest.is_entry_active(), ContestError::ContestNotStarted);
+
+    // Start prices are locked once posted so keepers cannot race to overwrite them
+    require!(
+        contest.token_start_prices.is_empty(),
+        ContestError::PricesAlreadyPosted
+    );
+
+    // Once the posting window is missed the contest can only be cancelled
+    let current_time = Clock::get()?.unix_timestamp as u64;
     require!(
-        !ctx.accounts.contest.is_entry_active(),
-        ContestError::ContestNotStarted
+        current_time <= contest.start_price_deadline(),
+        ContestError::PricePostingClosed
     );
 
     // Set start prices for each token
-    let feed_accounts: Vec<&Option<Box<Account<'_, PriceUpdateV2>>>> = vec![
-        &ctx.accounts.feed0,
-        &ctx.accounts.feed1,
-        &ctx.accounts.feed2,
-        &ctx.accounts.feed3,
-        &ctx.accounts.feed4,
-    ];
-
-    let clock = Clock::get()?;
-    let mut token_start_prices: Vec<f64> = Vec::new();
-    for (i, feed_id) in ctx.accounts.contest.token_feed_ids.iter().enumerate() {
-        require!(feed_accounts[i].is_some(), ContestError::InvalidFeeds);
-        let feed_account = feed_accounts[i].as_ref().unwrap();
-        let price = get_token_price(&clock, &feed_id, feed_account)?;
-        token_start_prices.push(price);
-    }
-    ctx.accounts.contest.token_start_prices = token_start_prices;
+    let (token_start_prices, publish_times) = get_token_price_updates(
+        ctx.remaining_accounts,
+        &contest.token_feed_ids,
+        &contest.token_min_confidence_ratios,
+    )?;
+
+    // Every price must be published at the start or right after it, so a keeper cannot wait for
+    // a favourable price within the posting window
+    calc_publish_delay(&publish_times, contest.start_time)?;
+
+    ctx.accounts.contest.token_start_prices = token_start_prices.clone();
+    ctx.accounts.contest.price_poster = Some(ctx.accounts.signer.key());
+
+    emit!(PricesPosted {
+        contest: ctx.accounts.contest.key(),
+        snapshot: PriceSnapshot::Start,
+        token_prices: token_start_prices,
+        publish_times,
+    });
 
     Ok(())
 }
 
-fn get_token_price(
-    clock: &Clock,
-    _feed_id: &Pubkey,
-    feed: &Account<'_, PriceUpdateV2>,
-) -> Result<f64> {
-    let maximum_age = 60;
-    let feed_id = _feed_id.to_bytes();
-    // let price_data = feed.get_price_no_older_than(clock, maximum_age, &feed_id)?;
-    let price_data = feed.get_price_unchecked(&feed_id)?;
-    let exp = (-price_data.exponent) as u32;
-    let price = (price_data.price as u64 as f64) / (10u64.pow(exp) as f64);
-    Ok(price)
+pub fn post_token_draft_contest_checkpoint(
+    ctx: Context<PostTokenDraftContestCheckpoint>,
+) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    require!(!contest.is_resolved, ContestError::AlreadyResolved);
+    require!(
+        !contest.token_start_prices.is_empty(),
+        ContestError::ContestPriceNotSet
+    );
+
+    // Checkpoints are posted in order, each once its time has been reached
+    let checkpoint = contest.num_checkpoints_posted();
+    require!(
+        checkpoint < contest.rebalance_times.len(),
+        ContestError::PricesAlreadyPosted
+    );
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    require!(
+        current_time >= contest.rebalance_times[checkpoint],
+        ContestError::CheckpointNotReached
+    );
+    require!(
+        current_time <= contest.checkpoint_deadline(checkpoint),
+        ContestError::PricePostingClosed
+    );
+
+    // Snapshot the prices that close the current segment and open the next one
+    let (token_prices, publish_times) = get_token_price_updates(
+        ctx.remaining_accounts,
+        &contest.token_feed_ids,
+        &contest.token_min_confidence_ratios,
+    )?;
+    calc_publish_delay(&publish_times, contest.rebalance_times[checkpoint])?;
+    ctx.accounts
+        .contest
+        .checkpoint_prices
+        .extend_from_slice(&token_prices);
+
+    emit!(PricesPosted {
+        contest: ctx.accounts.contest.key(),
+        snapshot: PriceSnapshot::Checkpoint {
+            index: checkpoint as u8,
+        },
+        token_prices,
+        publish_times,
+    });
+
+    Ok(())
 }
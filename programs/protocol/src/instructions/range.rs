@@ -0,0 +1,476 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::seeds::{
+    SEED_CONFIG, SEED_CONTEST_METADATA, SEED_FEED_REGISTRY, SEED_PROGRAM_TOKEN_ACCOUNT,
+    SEED_RANGE_CONTEST, SEED_RANGE_CONTEST_ENTRY, SEED_RANGE_CONTEST_PREDICTIONS,
+};
+use crate::errors::ContestError;
+use crate::events::{
+    PriceSnapshot, PricesPosted, RangeContestCreated, RangeContestResolved, RangeEntrySubmitted,
+    RewardClaimed,
+};
+use crate::state::config::Config;
+use crate::state::feed_registry::FeedRegistry;
+use crate::state::metadata::ContestMetadata;
+use crate::state::range::{RangeContest, RangeContestEntry, RangeContestPredictions, RangeTarget};
+use crate::utils::price::{calc_publish_delay, get_token_price_updates, load_price_feeds};
+use protocol_scoring::payout::{calc_fee_amount, calc_reward_share};
+use protocol_scoring::rank::find_top_n;
+
+#[derive(Accounts)]
+#[instruction(
+    start_time: u64,
+    end_time: u64,
+    entry_fee: u64,
+    max_entries: u32,
+    feed_id: Pubkey,
+    target: RangeTarget,
+    reward_allocation: Vec<u8>,
+)]
+pub struct CreateRangeContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + RangeContest::space(reward_allocation.len()),
+        seeds = [SEED_RANGE_CONTEST, contest_metadata.range_contest_count.to_le_bytes().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub contest: Box<Account<'info, RangeContest>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + RangeContestPredictions::INIT_SPACE,
+        seeds = [SEED_RANGE_CONTEST_PREDICTIONS, contest.key().as_ref()],
+        bump
+    )]
+    pub contest_predictions: Box<Account<'info, RangeContestPredictions>>,
+
+    #[account(
+        seeds = [SEED_FEED_REGISTRY],
+        bump
+    )]
+    pub feed_registry: Box<Account<'info, FeedRegistry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct EnterRangeContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, RangeContest>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + RangeContestEntry::INIT_SPACE,
+        seeds = [SEED_RANGE_CONTEST_ENTRY, contest.key().as_ref(), signer.key().as_ref()],
+        bump,
+    )]
+    pub contest_entry: Box<Account<'info, RangeContestEntry>>,
+
+    #[account(
+        mut,
+        realloc = contest_predictions.to_account_info().data_len() + std::mem::size_of::<f64>(),
+        realloc::payer = signer,
+        realloc::zero = false,
+        seeds = [SEED_RANGE_CONTEST_PREDICTIONS, contest.key().as_ref()],
+        bump
+    )]
+    pub contest_predictions: Box<Account<'info, RangeContestPredictions>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PostRangeContestPrice<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, RangeContest>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveRangeContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, RangeContest>>,
+
+    #[account(
+        seeds = [SEED_RANGE_CONTEST_PREDICTIONS, contest.key().as_ref()],
+        bump
+    )]
+    pub contest_predictions: Box<Account<'info, RangeContestPredictions>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRangeContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    pub contest: Box<Account<'info, RangeContest>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_RANGE_CONTEST_ENTRY, contest.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub contest_entry: Box<Account<'info, RangeContestEntry>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_range_contest(
+    ctx: Context<CreateRangeContest>,
+    start_time: u64,
+    end_time: u64,
+    entry_fee: u64,
+    max_entries: u32,
+    feed_id: Pubkey,
+    target: RangeTarget,
+    reward_allocation: Vec<u8>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    // Contest must start in some future time
+    require!(start_time > current_time, ContestError::InvalidDuration);
+
+    // Contest must end later than it starts
+    require!(end_time > start_time, ContestError::InvalidDuration);
+
+    // Reward allocation must be sorted in descending order and sum to 100
+    let is_allocation_good = reward_allocation.windows(2).all(|v| v[0] >= v[1])
+        && reward_allocation.iter().map(|&v| v as u32).sum::<u32>() == 100;
+    require!(is_allocation_good, ContestError::InvalidRewardAllocation);
+
+    // Check that a valid feed is provided
+    load_price_feeds(ctx.remaining_accounts, 1)?;
+
+    // Only enabled feeds from the registry can be used
+    let min_confidence_ratios = ctx
+        .accounts
+        .feed_registry
+        .get_min_confidence_ratios(&[feed_id])?;
+
+    // Set contest parameters
+    let contest = &mut ctx.accounts.contest;
+    contest.id = ctx.accounts.contest_metadata.range_contest_count;
+    contest.creator = ctx.accounts.signer.key();
+    contest.start_time = start_time;
+    contest.end_time = end_time;
+    contest.entry_fee = entry_fee;
+    contest.max_entries = max_entries;
+    contest.feed_id = feed_id;
+    contest.min_confidence_ratio = min_confidence_ratios[0];
+    contest.target = target;
+    contest.winner_reward_allocation = reward_allocation.clone();
+
+    // Initialize prediction data
+    ctx.accounts.contest_predictions.contest_key = contest.key();
+
+    // Update contest metadata
+    ctx.accounts.contest_metadata.range_contest_count += 1;
+
+    emit!(RangeContestCreated {
+        contest: ctx.accounts.contest.key(),
+        id: ctx.accounts.contest.id,
+        creator: ctx.accounts.signer.key(),
+        start_time,
+        end_time,
+        entry_fee,
+        max_entries,
+        feed_id,
+        target,
+        reward_allocation,
+    });
+
+    Ok(())
+}
+
+pub fn enter_range_contest(ctx: Context<EnterRangeContest>, prediction: f64) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+
+    // Check if the contest entry is closed
+    require!(contest.is_entry_active(), ContestError::EntryClosed);
+
+    // Check if the contest is already full
+    require!(
+        contest.num_entries < contest.max_entries,
+        ContestError::AlreadyFull
+    );
+
+    require!(prediction.is_finite(), ContestError::InvalidPrediction);
+
+    // Transfer entry fee from the user's token account to the program's token account
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.signer_token_account.to_account_info(),
+        to: ctx.accounts.program_token_account.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+    transfer_checked(cpi_context, contest.entry_fee, ctx.accounts.mint.decimals)?;
+
+    // Update number of entries
+    contest.num_entries += 1;
+
+    // Create a new participation record
+    let contest_entry = &mut ctx.accounts.contest_entry;
+    contest_entry.user = ctx.accounts.signer.key();
+    contest_entry.id = contest.num_entries - 1;
+    contest_entry.contest_key = contest.key();
+    contest_entry.prediction = prediction;
+
+    // Append to prediction account
+    ctx.accounts
+        .contest_predictions
+        .predictions
+        .push(prediction);
+
+    emit!(RangeEntrySubmitted {
+        contest: contest.key(),
+        entry: contest_entry.key(),
+        user: contest_entry.user,
+        entry_id: contest_entry.id,
+        prediction,
+    });
+
+    Ok(())
+}
+
+pub fn post_range_contest_price(ctx: Context<PostRangeContestPrice>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    require!(!contest.is_entry_active(), ContestError::ContestNotStarted);
+    require!(
+        contest.start_price.is_none(),
+        ContestError::PricesAlreadyPosted
+    );
+
+    // Set the start price for percentage change predictions, published right at the start time
+    let (start_prices, publish_times) = get_token_price_updates(
+        ctx.remaining_accounts,
+        &[contest.feed_id],
+        &[contest.min_confidence_ratio],
+    )?;
+    calc_publish_delay(&publish_times, contest.start_time)?;
+    ctx.accounts.contest.start_price = Some(start_prices[0]);
+
+    emit!(PricesPosted {
+        contest: ctx.accounts.contest.key(),
+        snapshot: PriceSnapshot::Start,
+        token_prices: start_prices,
+        publish_times,
+    });
+
+    Ok(())
+}
+
+pub fn resolve_range_contest(ctx: Context<ResolveRangeContest>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    // Check that end time has passed
+    require!(contest.has_ended(), ContestError::ContestNotEnded);
+
+    require!(!contest.is_resolved, ContestError::AlreadyResolved);
+
+    // The end price must be published right at the end time so the resolver cannot pick it
+    let (end_prices, publish_times) = get_token_price_updates(
+        ctx.remaining_accounts,
+        &[contest.feed_id],
+        &[contest.min_confidence_ratio],
+    )?;
+    calc_publish_delay(&publish_times, contest.end_time)?;
+    let end_price = end_prices[0];
+    let settled_value = contest.settled_value(end_price);
+    require!(settled_value.is_some(), ContestError::ContestPriceNotSet);
+    let settled_value = settled_value.unwrap();
+
+    // Rank entries by closeness, the smallest absolute error gets the highest score
+    let predictions = &ctx.accounts.contest_predictions.predictions;
+    let scores: Vec<(usize, f64)> = predictions
+        .iter()
+        .enumerate()
+        .map(|(i, &prediction)| (i, -(prediction - settled_value).abs()))
+        .collect();
+
+    // Find the top N users
+    let num_top_users = contest.winner_reward_allocation.len();
+    let winners = find_top_n(&scores, num_top_users);
+
+    // Store the top N users
+    let winner_ids: Vec<u32> = winners.iter().map(|v| v.0 as u32).collect();
+    let contest = &mut ctx.accounts.contest;
+    contest.end_price = end_price;
+    contest.winner_ids = winner_ids.clone();
+    contest.is_resolved = true;
+
+    // Accumulate the fee amount from this contest
+    let total_pool_amount = contest.pool_amount();
+    let fee_amount = calc_fee_amount(
+        total_pool_amount,
+        ctx.accounts
+            .contest_metadata
+            .token_draft_contest_fee_percent,
+    );
+    ctx.accounts.contest_metadata.token_draft_contest_fee_amount += fee_amount;
+
+    emit!(RangeContestResolved {
+        contest: contest.key(),
+        end_price,
+        publish_time: publish_times[0],
+        settled_value,
+        winner_ids,
+        fee_amount,
+    });
+
+    Ok(())
+}
+
+pub fn claim_range_contest(ctx: Context<ClaimRangeContest>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    let contest_entry = &mut ctx.accounts.contest_entry;
+
+    // Check if the contest has already been resolved
+    require!(contest.is_resolved, ContestError::ContestNotResolved);
+
+    // Check if the user has already claimed their rewards
+    require!(!contest_entry.has_claimed, ContestError::AlreadyClaimed);
+
+    let pos = contest
+        .winner_ids
+        .iter()
+        .position(|&id| id == contest_entry.id)
+        .ok_or(ContestError::NotWinner)?;
+    let alloc = contest.winner_reward_allocation[pos];
+
+    // Calculate the user reward amount based on the reward allocation
+    let total_pool_amount = contest.pool_amount();
+    let fee_amount = calc_fee_amount(
+        total_pool_amount,
+        ctx.accounts
+            .contest_metadata
+            .token_draft_contest_fee_percent,
+    );
+    let total_reward_amount = total_pool_amount - fee_amount;
+    let user_reward_amount = calc_reward_share(total_reward_amount, alloc);
+
+    // Transfer the reward to the user's token account
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.program_token_account.to_account_info(),
+        to: ctx.accounts.signer_token_account.to_account_info(),
+        authority: ctx.accounts.program_token_account.to_account_info(),
+    };
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_PROGRAM_TOKEN_ACCOUNT,
+        mint_key.as_ref(),
+        &[ctx.bumps.program_token_account],
+    ]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_context, user_reward_amount, ctx.accounts.mint.decimals)?;
+
+    // Mark the entry as claimed
+    contest_entry.has_claimed = true;
+
+    emit!(RewardClaimed {
+        contest: contest.key(),
+        entry: contest_entry.key(),
+        user: contest_entry.user,
+        amount: user_reward_amount,
+    });
+
+    Ok(())
+}
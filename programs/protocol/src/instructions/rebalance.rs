@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::seeds::{SEED_TOKEN_DRAFT_CONTEST_CREDITS, SEED_TOKEN_DRAFT_CONTEST_ENTRY};
+use crate::errors::ContestError;
+use crate::events::EntryRebalanced;
+use crate::state::contest::TokenDraftContest;
+use crate::state::credit::TokenDraftContestCredits;
+use crate::state::entry::TokenDraftContestEntry;
+
+#[derive(Accounts)]
+pub struct RebalanceTokenDraftContestEntry<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub contest: Box<Account<'info, TokenDraftContest>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TOKEN_DRAFT_CONTEST_ENTRY, contest.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub contest_entry: Box<Account<'info, TokenDraftContestEntry>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TOKEN_DRAFT_CONTEST_CREDITS, contest.key().as_ref()],
+        bump
+    )]
+    pub contest_credits: Box<Account<'info, TokenDraftContestCredits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn rebalance_token_draft_contest_entry(
+    ctx: Context<RebalanceTokenDraftContestEntry>,
+    credit_allocation: Vec<i8>,
+) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    let contest_entry = &mut ctx.accounts.contest_entry;
+
+    // Rebalances apply from the next checkpoint so the ROI accumulated up to it stays locked in
+    let segment = contest.next_rebalance_segment();
+    require!(segment.is_some(), ContestError::RebalanceClosed);
+    let segment = segment.unwrap();
+
+    // Check if allocation is valid, the captain is fixed at entry
+    contest.validate_credit_allocation(&credit_allocation)?;
+    contest.validate_captain(&credit_allocation, contest_entry.captain)?;
+
+    // Overwrite the entry's allocation for the next segment and every one after it
+    let num_tokens = contest.token_feed_ids.len();
+    let row_start = contest_entry.id as usize * contest.credit_row_len();
+    let credit_allocations = &mut ctx.accounts.contest_credits.credit_allocations;
+    for i in segment..contest.num_segments() {
+        let segment_start = row_start + i * num_tokens;
+        credit_allocations[segment_start..segment_start + num_tokens]
+            .copy_from_slice(&credit_allocation);
+    }
+
+    emit!(EntryRebalanced {
+        contest: contest.key(),
+        entry: contest_entry.key(),
+        user: contest_entry.user,
+        entry_id: contest_entry.id,
+        segment: segment as u8,
+        credit_allocation: credit_allocation.clone(),
+    });
+
+    contest_entry.credit_allocation = credit_allocation;
+
+    Ok(())
+}
@@ -0,0 +1,407 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::seeds::{
+    SEED_CONFIG, SEED_CONTEST_METADATA, SEED_PROGRAM_TOKEN_ACCOUNT, SEED_SEASON,
+    SEED_SEASON_STANDING,
+};
+use crate::errors::{ConfigError, ContestError};
+use crate::events::{
+    SeasonContestAdded, SeasonContestSettled, SeasonCreated, SeasonFinalized, SeasonPointsAwarded,
+    SeasonPrizeClaimed,
+};
+use crate::state::config::Config;
+use crate::state::contest::TokenDraftContest;
+use crate::state::entry::TokenDraftContestEntry;
+use crate::state::metadata::ContestMetadata;
+use crate::state::season::{Season, SeasonStanding};
+use protocol_scoring::payout::calc_reward_share;
+
+#[derive(Accounts)]
+#[instruction(
+    start_time: u64,
+    end_time: u64,
+    points_table: Vec<u32>,
+    fee_share_percent: u8,
+    prize_allocation: Vec<u8>,
+)]
+pub struct CreateSeason<'info> {
+    #[account(
+        mut,
+        address = config.admin @ ConfigError::Unauthorized
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + Season::space(points_table.len(), prize_allocation.len()),
+        seeds = [SEED_SEASON, contest_metadata.season_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub season: Box<Account<'info, Season>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddSeasonContest<'info> {
+    #[account(address = config.admin @ ConfigError::Unauthorized)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mut)]
+    pub season: Box<Account<'info, Season>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, TokenDraftContest>>,
+}
+
+#[derive(Accounts)]
+pub struct SettleSeasonContest<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub season: Box<Account<'info, Season>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, TokenDraftContest>>,
+}
+
+#[derive(Accounts)]
+pub struct AwardSeasonPoints<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub season: Box<Account<'info, Season>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, TokenDraftContest>>,
+
+    #[account(
+        mut,
+        constraint = contest_entry.contest_key == contest.key() @ ContestError::SeasonMismatch
+    )]
+    pub contest_entry: Box<Account<'info, TokenDraftContestEntry>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + SeasonStanding::INIT_SPACE,
+        seeds = [SEED_SEASON_STANDING, season.key().as_ref(), contest_entry.user.as_ref()],
+        bump
+    )]
+    pub season_standing: Box<Account<'info, SeasonStanding>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeSeason<'info> {
+    #[account(address = config.admin @ ConfigError::Unauthorized)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mut)]
+    pub season: Box<Account<'info, Season>>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimSeasonPrize<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    pub season: Box<Account<'info, Season>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SEASON_STANDING, season.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub season_standing: Box<Account<'info, SeasonStanding>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_season(
+    ctx: Context<CreateSeason>,
+    start_time: u64,
+    end_time: u64,
+    points_table: Vec<u32>,
+    fee_share_percent: u8,
+    prize_allocation: Vec<u8>,
+) -> Result<()> {
+    // Season must end later than it starts
+    require!(end_time > start_time, ContestError::InvalidDuration);
+
+    // Points must be awarded to at least one position and never increase down the table
+    let is_points_table_good =
+        !points_table.is_empty() && points_table.windows(2).all(|v| v[0] >= v[1]);
+    require!(
+        is_points_table_good && fee_share_percent <= 100,
+        ContestError::InvalidSeasonConfig
+    );
+
+    // Prize allocation must be sorted in descending order and sum to 100
+    let is_allocation_good = prize_allocation.windows(2).all(|v| v[0] >= v[1])
+        && prize_allocation.iter().map(|&v| v as u32).sum::<u32>() == 100;
+    require!(is_allocation_good, ContestError::InvalidRewardAllocation);
+
+    // Set season parameters
+    let season = &mut ctx.accounts.season;
+    season.id = ctx.accounts.contest_metadata.season_count;
+    season.start_time = start_time;
+    season.end_time = end_time;
+    season.points_table = points_table;
+    season.fee_share_percent = fee_share_percent;
+    season.prize_allocation = prize_allocation;
+
+    // Update contest metadata
+    ctx.accounts.contest_metadata.season_count += 1;
+
+    emit!(SeasonCreated {
+        season: season.key(),
+        id: season.id,
+        start_time,
+        end_time,
+        points_table: season.points_table.clone(),
+        fee_share_percent,
+        prize_allocation: season.prize_allocation.clone(),
+    });
+
+    Ok(())
+}
+
+pub fn add_season_contest(ctx: Context<AddSeasonContest>) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let contest = &mut ctx.accounts.contest;
+
+    require!(!season.is_finalized, ContestError::SeasonFinalized);
+    require!(contest.season.is_none(), ContestError::AlreadyInSeason);
+
+    // Contests can only be linked before they start
+    require!(contest.is_entry_active(), ContestError::EntryClosed);
+
+    // Check that the contest is played within the season
+    require!(
+        contest.start_time >= season.start_time && contest.end_time <= season.end_time,
+        ContestError::SeasonMismatch
+    );
+
+    contest.season = Some(season.key());
+    contest.season_fee_share_percent = season.fee_share_percent;
+    contest.season_scoring_positions = season.points_table.len() as u32;
+    season.num_contests += 1;
+
+    emit!(SeasonContestAdded {
+        season: season.key(),
+        contest: contest.key(),
+    });
+
+    Ok(())
+}
+
+pub fn settle_season_contest(ctx: Context<SettleSeasonContest>) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let contest = &mut ctx.accounts.contest;
+
+    require!(!season.is_finalized, ContestError::SeasonFinalized);
+    require!(
+        contest.season == Some(season.key()),
+        ContestError::SeasonMismatch
+    );
+    require!(
+        contest.is_resolved || contest.is_cancelled,
+        ContestError::ContestNotResolved
+    );
+    require!(!contest.is_season_settled, ContestError::AlreadySettled);
+
+    // Every season point from the contest must be in the standings before it is settled
+    require!(
+        contest.num_pending_season_points == 0,
+        ContestError::SeasonPointsPending
+    );
+
+    // Move the season's cut held back at resolve into the prize pool, cancelled contests have none
+    season.prize_pool_amount += contest.season_prize_amount;
+    season.num_settled_contests += 1;
+
+    contest.is_season_settled = true;
+
+    emit!(SeasonContestSettled {
+        season: season.key(),
+        contest: contest.key(),
+        prize_amount: contest.season_prize_amount,
+    });
+
+    Ok(())
+}
+
+pub fn award_season_points(ctx: Context<AwardSeasonPoints>) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let contest = &mut ctx.accounts.contest;
+    let contest_entry = &mut ctx.accounts.contest_entry;
+
+    require!(!season.is_finalized, ContestError::SeasonFinalized);
+    require!(
+        contest.season == Some(season.key()),
+        ContestError::SeasonMismatch
+    );
+    require!(contest.is_resolved, ContestError::ContestNotResolved);
+    require!(
+        !contest_entry.has_season_points,
+        ContestError::AlreadyClaimed
+    );
+
+    // Points are awarded by finishing position, positions past the table score nothing
+    let points = contest
+        .winner_position(contest_entry)
+        .and_then(|pos| season.points_table.get(pos))
+        .copied()
+        .ok_or(ContestError::NotWinner)?;
+
+    let season_standing = &mut ctx.accounts.season_standing;
+    season_standing.user = contest_entry.user;
+    season_standing.season_key = season.key();
+    season_standing.points += points as u64;
+    season_standing.num_contests += 1;
+
+    season.update_leaders(season_standing.user, season_standing.points);
+
+    contest_entry.has_season_points = true;
+    contest.num_pending_season_points -= 1;
+
+    emit!(SeasonPointsAwarded {
+        season: season.key(),
+        contest: contest.key(),
+        entry: contest_entry.key(),
+        user: season_standing.user,
+        points,
+        total_points: season_standing.points,
+    });
+
+    Ok(())
+}
+
+pub fn finalize_season(ctx: Context<FinalizeSeason>) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+
+    require!(season.has_ended(), ContestError::ContestNotEnded);
+    require!(!season.is_finalized, ContestError::SeasonFinalized);
+
+    // Prizes are only fixed once every linked contest has paid into the pool
+    require!(
+        season.num_settled_contests == season.num_contests,
+        ContestError::SeasonNotSettled
+    );
+
+    season.is_finalized = true;
+
+    emit!(SeasonFinalized {
+        season: season.key(),
+        prize_pool_amount: season.prize_pool_amount,
+        leaders: season.leaders.iter().map(|leader| leader.user).collect(),
+    });
+
+    Ok(())
+}
+
+pub fn claim_season_prize(ctx: Context<ClaimSeasonPrize>) -> Result<()> {
+    let season = &ctx.accounts.season;
+    let season_standing = &mut ctx.accounts.season_standing;
+
+    require!(season.is_finalized, ContestError::SeasonNotFinalized);
+
+    // Check if the user has already claimed their prize
+    require!(!season_standing.has_claimed, ContestError::AlreadyClaimed);
+
+    let pos = season
+        .leaders
+        .iter()
+        .position(|leader| leader.user == season_standing.user)
+        .ok_or(ContestError::NotWinner)?;
+    let alloc = season.prize_allocation[pos];
+
+    // Calculate the user prize amount based on the prize allocation
+    let user_prize_amount = calc_reward_share(season.prize_pool_amount, alloc);
+
+    // Transfer the prize to the user's token account
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.program_token_account.to_account_info(),
+        to: ctx.accounts.signer_token_account.to_account_info(),
+        authority: ctx.accounts.program_token_account.to_account_info(),
+    };
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_PROGRAM_TOKEN_ACCOUNT,
+        mint_key.as_ref(),
+        &[ctx.bumps.program_token_account],
+    ]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_context, user_prize_amount, ctx.accounts.mint.decimals)?;
+
+    // Mark the standing as claimed
+    season_standing.has_claimed = true;
+
+    emit!(SeasonPrizeClaimed {
+        season: season.key(),
+        user: season_standing.user,
+        amount: user_prize_amount,
+    });
+
+    Ok(())
+}
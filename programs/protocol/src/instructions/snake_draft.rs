@@ -0,0 +1,638 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::seeds::{
+    SEED_CONFIG, SEED_CONTEST_METADATA, SEED_FEED_REGISTRY, SEED_PROGRAM_TOKEN_ACCOUNT,
+    SEED_SNAKE_DRAFT_CONTEST,
+};
+use crate::errors::ContestError;
+use crate::events::{
+    PlayerRefunded, PlayerRewardClaimed, PriceSnapshot, PricesPosted, SnakeDraftContestCreated,
+    SnakeDraftContestResolved, SnakeDraftPlayerJoined, SnakeDraftTokenPicked,
+};
+use crate::state::config::Config;
+use crate::state::contest::MAX_TOKEN_PER_DRAFT;
+use crate::state::feed_registry::FeedRegistry;
+use crate::state::metadata::ContestMetadata;
+use crate::state::snake_draft::{
+    SnakeDraftContest, MAX_SNAKE_DRAFT_PLAYERS, MAX_SNAKE_DRAFT_POOL, MIN_SNAKE_DRAFT_PLAYERS,
+};
+use crate::utils::price::{calc_publish_delay, get_token_price_updates, load_price_feeds};
+use crate::utils::roi::get_token_rois;
+use protocol_scoring::payout::{calc_fee_amount, calc_reward_share};
+use protocol_scoring::rank::find_top_n;
+use protocol_scoring::roi::calc_avg_roi;
+
+#[derive(Accounts)]
+#[instruction(
+    entry_fee: u64,
+    max_players: u8,
+    picks_per_player: u8,
+    pick_duration: u64,
+    contest_duration: u64,
+    feed_indices: Vec<u8>,
+    reward_allocation: Vec<u8>,
+)]
+pub struct CreateSnakeDraftContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + SnakeDraftContest::space(
+            max_players as usize,
+            feed_indices.len(),
+            max_players as usize * picks_per_player as usize,
+            reward_allocation.len(),
+        ),
+        seeds = [SEED_SNAKE_DRAFT_CONTEST, contest_metadata.snake_draft_contest_count.to_le_bytes().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub contest: Box<Account<'info, SnakeDraftContest>>,
+
+    #[account(
+        seeds = [SEED_FEED_REGISTRY],
+        bump
+    )]
+    pub feed_registry: Box<Account<'info, FeedRegistry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct JoinSnakeDraftContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, SnakeDraftContest>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RefundSnakeDraftContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, SnakeDraftContest>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct PickSnakeDraftToken<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, SnakeDraftContest>>,
+}
+
+#[derive(Accounts)]
+pub struct AutoPickSnakeDraftToken<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, SnakeDraftContest>>,
+}
+
+#[derive(Accounts)]
+pub struct PostSnakeDraftContestPrices<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, SnakeDraftContest>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveSnakeDraftContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, SnakeDraftContest>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimSnakeDraftContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, SnakeDraftContest>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_snake_draft_contest(
+    ctx: Context<CreateSnakeDraftContest>,
+    entry_fee: u64,
+    max_players: u8,
+    picks_per_player: u8,
+    pick_duration: u64,
+    contest_duration: u64,
+    feed_indices: Vec<u8>,
+    reward_allocation: Vec<u8>,
+    fill_duration: u64,
+) -> Result<()> {
+    // Filling the lobby, picks and the contest itself must last for some time
+    require!(
+        fill_duration > 0 && pick_duration > 0 && contest_duration > 0,
+        ContestError::InvalidDuration
+    );
+
+    // Lobbies hold at least two players and every drafted token must fit in a single scoring
+    let total_picks = max_players as usize * picks_per_player as usize;
+    require!(
+        (MIN_SNAKE_DRAFT_PLAYERS..=MAX_SNAKE_DRAFT_PLAYERS).contains(&max_players)
+            && picks_per_player > 0
+            && total_picks <= MAX_TOKEN_PER_DRAFT,
+        ContestError::InvalidSnakeDraftConfig
+    );
+
+    // Pool must hold enough distinct tokens for every pick
+    let mut sorted_indices = feed_indices.clone();
+    sorted_indices.sort_unstable();
+    sorted_indices.dedup();
+    require!(
+        sorted_indices.len() == feed_indices.len()
+            && feed_indices.len() >= total_picks
+            && feed_indices.len() <= MAX_SNAKE_DRAFT_POOL,
+        ContestError::InvalidDraftTokenCount
+    );
+
+    // Reward allocation must be sorted in descending order and sum to 100
+    let is_allocation_good = reward_allocation.len() <= max_players as usize
+        && reward_allocation.windows(2).all(|v| v[0] >= v[1])
+        && reward_allocation.iter().map(|&v| v as u32).sum::<u32>() == 100;
+    require!(is_allocation_good, ContestError::InvalidRewardAllocation);
+
+    // Only enabled feeds from the registry can be drafted
+    let feed_registry = &ctx.accounts.feed_registry;
+    let token_feed_ids = feed_registry.get_feed_ids(&feed_indices)?;
+    let token_min_confidence_ratios = feed_registry.get_min_confidence_ratios(&token_feed_ids)?;
+
+    // Check that valid feeds are provided
+    load_price_feeds(ctx.remaining_accounts, token_feed_ids.len())?;
+
+    // Set contest parameters, the draft starts once the lobby fills up
+    let contest = &mut ctx.accounts.contest;
+    contest.id = ctx.accounts.contest_metadata.snake_draft_contest_count;
+    contest.creator = ctx.accounts.signer.key();
+    contest.entry_fee = entry_fee;
+    contest.max_players = max_players;
+    contest.picks_per_player = picks_per_player;
+    contest.pick_duration = pick_duration;
+    contest.contest_duration = contest_duration;
+    contest.fill_deadline = Clock::get()?.unix_timestamp as u64 + fill_duration;
+    contest.token_owners = vec![None; token_feed_ids.len()];
+    contest.token_feed_ids = token_feed_ids;
+    contest.token_min_confidence_ratios = token_min_confidence_ratios;
+    contest.winner_reward_allocation = reward_allocation.clone();
+
+    // Update contest metadata
+    ctx.accounts.contest_metadata.snake_draft_contest_count += 1;
+
+    let contest = &ctx.accounts.contest;
+    emit!(SnakeDraftContestCreated {
+        contest: contest.key(),
+        id: contest.id,
+        creator: contest.creator,
+        entry_fee,
+        max_players,
+        picks_per_player,
+        pick_duration,
+        contest_duration,
+        fill_deadline: contest.fill_deadline,
+        token_feed_ids: contest.token_feed_ids.clone(),
+        reward_allocation,
+    });
+
+    Ok(())
+}
+
+pub fn join_snake_draft_contest(ctx: Context<JoinSnakeDraftContest>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let player = ctx.accounts.signer.key();
+
+    // Check if the lobby is already full
+    require!(!contest.is_lobby_full(), ContestError::AlreadyFull);
+
+    // A lobby that did not fill in time is refunded instead
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    require!(
+        current_time <= contest.fill_deadline,
+        ContestError::EntryClosed
+    );
+
+    // Each wallet holds a single seat
+    require!(
+        !contest.players.contains(&player),
+        ContestError::AlreadyJoined
+    );
+
+    // Transfer entry fee from the user's token account to the program's token account
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.signer_token_account.to_account_info(),
+        to: ctx.accounts.program_token_account.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+    transfer_checked(cpi_context, contest.entry_fee, ctx.accounts.mint.decimals)?;
+
+    // Seats are taken in join order, which is also the first round's pick order
+    contest.players.push(player);
+    contest.has_claimed.push(false);
+
+    // Start the draft clock once the last seat is filled
+    if contest.is_lobby_full() {
+        contest.pick_deadline = current_time + contest.pick_duration;
+    }
+
+    emit!(SnakeDraftPlayerJoined {
+        contest: contest.key(),
+        player,
+        player_id: (contest.players.len() - 1) as u8,
+    });
+
+    Ok(())
+}
+
+pub fn pick_snake_draft_token(ctx: Context<PickSnakeDraftToken>, token_index: u8) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    require!(contest.is_draft_active(), ContestError::DraftNotActive);
+
+    // Check that it is the signer's turn and their pick clock has not run out
+    require!(
+        contest.players[contest.current_picker()] == ctx.accounts.signer.key(),
+        ContestError::NotYourTurn
+    );
+    require!(
+        current_time <= contest.pick_deadline,
+        ContestError::PickDeadlinePassed
+    );
+
+    // Each token can only be owned once
+    let owner = contest
+        .token_owners
+        .get(token_index as usize)
+        .ok_or(ContestError::InvalidTokenIndex)?;
+    require!(owner.is_none(), ContestError::TokenAlreadyOwned);
+
+    contest.make_pick(token_index as usize);
+
+    emit!(SnakeDraftTokenPicked {
+        contest: contest.key(),
+        player: ctx.accounts.signer.key(),
+        token_index,
+        is_auto_pick: false,
+    });
+
+    Ok(())
+}
+
+pub fn auto_pick_snake_draft_token(ctx: Context<AutoPickSnakeDraftToken>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    require!(contest.is_draft_active(), ContestError::DraftNotActive);
+
+    // Anyone can pick on behalf of a player once their pick clock has run out
+    require!(
+        current_time > contest.pick_deadline,
+        ContestError::PickDeadlineNotReached
+    );
+
+    // Auto-pick takes the first token left in the pool
+    let token_index = contest
+        .token_owners
+        .iter()
+        .position(|owner| owner.is_none())
+        .ok_or(ContestError::InvalidTokenIndex)?;
+
+    let player = contest.players[contest.current_picker()];
+    contest.make_pick(token_index);
+
+    emit!(SnakeDraftTokenPicked {
+        contest: contest.key(),
+        player,
+        token_index: token_index as u8,
+        is_auto_pick: true,
+    });
+
+    Ok(())
+}
+
+pub fn post_snake_draft_contest_prices(ctx: Context<PostSnakeDraftContestPrices>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    require!(contest.is_draft_complete(), ContestError::DraftNotComplete);
+    require!(
+        contest.token_start_prices.is_empty(),
+        ContestError::PricesAlreadyPosted
+    );
+
+    // Once the posting window is missed every entry fee is refunded instead
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    require!(
+        current_time <= contest.start_price_deadline(),
+        ContestError::PricePostingClosed
+    );
+
+    // Set start prices for the picked tokens, published right at the start
+    let (token_start_prices, publish_times) = get_token_price_updates(
+        ctx.remaining_accounts,
+        &contest.picked_feed_ids(),
+        &contest.picked_min_confidence_ratios(),
+    )?;
+    calc_publish_delay(&publish_times, contest.start_time)?;
+    ctx.accounts.contest.token_start_prices = token_start_prices.clone();
+
+    emit!(PricesPosted {
+        contest: ctx.accounts.contest.key(),
+        snapshot: PriceSnapshot::Start,
+        token_prices: token_start_prices,
+        publish_times,
+    });
+
+    Ok(())
+}
+
+// Players take their entry fee back from a contest that can never be resolved
+pub fn refund_snake_draft_contest(ctx: Context<RefundSnakeDraftContest>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+
+    require!(contest.is_refundable(), ContestError::NotRefundable);
+
+    let player_id = contest
+        .players
+        .iter()
+        .position(|&player| player == ctx.accounts.signer.key())
+        .ok_or(ContestError::NotParticipant)?;
+    require!(
+        !contest.has_claimed[player_id],
+        ContestError::AlreadyClaimed
+    );
+
+    // Refund the entry fee to the player
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.program_token_account.to_account_info(),
+        to: ctx.accounts.signer_token_account.to_account_info(),
+        authority: ctx.accounts.program_token_account.to_account_info(),
+    };
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_PROGRAM_TOKEN_ACCOUNT,
+        mint_key.as_ref(),
+        &[ctx.bumps.program_token_account],
+    ]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_context, contest.entry_fee, ctx.accounts.mint.decimals)?;
+
+    // Mark the player as refunded
+    contest.has_claimed[player_id] = true;
+
+    emit!(PlayerRefunded {
+        contest: contest.key(),
+        user: ctx.accounts.signer.key(),
+        amount: contest.entry_fee,
+    });
+
+    Ok(())
+}
+
+pub fn resolve_snake_draft_contest(ctx: Context<ResolveSnakeDraftContest>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    require!(!contest.is_resolved, ContestError::AlreadyResolved);
+
+    require!(
+        !contest.token_start_prices.is_empty(),
+        ContestError::ContestPriceNotSet
+    );
+
+    // Check if the contest has ended
+    require!(contest.has_ended(), ContestError::ContestNotEnded);
+
+    let token_rois = get_token_rois(
+        ctx.remaining_accounts,
+        &contest.picked_feed_ids(),
+        &contest.token_start_prices,
+        &contest.picked_min_confidence_ratios(),
+        contest.end_time,
+    )?;
+
+    // Score each player by the average ROI of the tokens they own
+    let scores: Vec<(usize, f64)> = (0..contest.players.len())
+        .map(|i| {
+            let allocation = contest.player_allocation(i);
+            (i, calc_avg_roi(&allocation, &token_rois, None))
+        })
+        .collect();
+
+    let num_winners = contest.winner_reward_allocation.len();
+    let winner_ids: Vec<u32> = find_top_n(&scores, num_winners)
+        .iter()
+        .map(|&(i, _)| i as u32)
+        .collect();
+
+    let contest = &mut ctx.accounts.contest;
+    contest.winner_ids = winner_ids.clone();
+    contest.is_resolved = true;
+
+    // Accumulate the fee amount from this contest
+    let total_pool_amount = contest.pool_amount();
+    let fee_amount = calc_fee_amount(
+        total_pool_amount,
+        ctx.accounts
+            .contest_metadata
+            .token_draft_contest_fee_percent,
+    );
+    ctx.accounts.contest_metadata.token_draft_contest_fee_amount += fee_amount;
+
+    emit!(SnakeDraftContestResolved {
+        contest: contest.key(),
+        token_rois,
+        winner_ids,
+        fee_amount,
+    });
+
+    Ok(())
+}
+
+pub fn claim_snake_draft_contest(ctx: Context<ClaimSnakeDraftContest>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    // Check if the contest has already been resolved
+    require!(contest.is_resolved, ContestError::ContestNotResolved);
+
+    let player_id = contest
+        .players
+        .iter()
+        .position(|&player| player == ctx.accounts.signer.key())
+        .ok_or(ContestError::NotParticipant)?;
+
+    // Check if the user has already claimed their rewards
+    require!(
+        !contest.has_claimed[player_id],
+        ContestError::AlreadyClaimed
+    );
+
+    let pos = contest
+        .winner_ids
+        .iter()
+        .position(|&id| id == player_id as u32)
+        .ok_or(ContestError::NotWinner)?;
+    let alloc = contest.winner_reward_allocation[pos];
+
+    // Calculate the user reward amount based on the reward allocation
+    let total_pool_amount = contest.pool_amount();
+    let fee_amount = calc_fee_amount(
+        total_pool_amount,
+        ctx.accounts
+            .contest_metadata
+            .token_draft_contest_fee_percent,
+    );
+    let total_reward_amount = total_pool_amount - fee_amount;
+    let user_reward_amount = calc_reward_share(total_reward_amount, alloc);
+
+    // Transfer the reward to the user's token account
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.program_token_account.to_account_info(),
+        to: ctx.accounts.signer_token_account.to_account_info(),
+        authority: ctx.accounts.program_token_account.to_account_info(),
+    };
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_PROGRAM_TOKEN_ACCOUNT,
+        mint_key.as_ref(),
+        &[ctx.bumps.program_token_account],
+    ]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_context, user_reward_amount, ctx.accounts.mint.decimals)?;
+
+    // Mark the player as claimed
+    ctx.accounts.contest.has_claimed[player_id] = true;
+
+    emit!(PlayerRewardClaimed {
+        contest: ctx.accounts.contest.key(),
+        user: ctx.accounts.signer.key(),
+        amount: user_reward_amount,
+    });
+
+    Ok(())
+}
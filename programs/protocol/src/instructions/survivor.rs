@@ -0,0 +1,678 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::seeds::{
+    SEED_CONFIG, SEED_CONTEST_METADATA, SEED_FEED_REGISTRY, SEED_PROGRAM_TOKEN_ACCOUNT,
+    SEED_SURVIVOR_CONTEST, SEED_SURVIVOR_CONTEST_CREDITS, SEED_SURVIVOR_CONTEST_ENTRY,
+};
+use crate::errors::ContestError;
+use crate::events::{
+    EntryRefunded, PriceSnapshot, PricesPosted, RewardClaimed, SurvivorContestCreated,
+    SurvivorEntryRedrafted, SurvivorEntrySubmitted, SurvivorRoundResolved,
+};
+use crate::state::config::Config;
+use crate::state::contest::MAX_TOKEN_PER_DRAFT;
+use crate::state::feed_registry::FeedRegistry;
+use crate::state::metadata::ContestMetadata;
+use crate::state::survivor::{SurvivorContest, SurvivorContestCredits, SurvivorContestEntry};
+use crate::utils::price::{calc_publish_delay, get_token_price_updates, load_price_feeds};
+use crate::utils::roi::get_token_rois;
+use protocol_scoring::payout::calc_fee_amount;
+use protocol_scoring::rank::find_top_n;
+use protocol_scoring::roi::calc_avg_roi;
+
+#[derive(Accounts)]
+#[instruction(
+    start_time: u64,
+    draft_duration: u64,
+    round_duration: u64,
+    num_rounds: u8,
+    elimination_percent: u8,
+    entry_fee: u64,
+    max_entries: u32,
+    token_feed_ids: Vec<Pubkey>,
+)]
+pub struct CreateSurvivorContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + SurvivorContest::space(token_feed_ids.len()),
+        seeds = [SEED_SURVIVOR_CONTEST, contest_metadata.survivor_contest_count.to_le_bytes().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub contest: Box<Account<'info, SurvivorContest>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + SurvivorContestCredits::INIT_SPACE,
+        seeds = [SEED_SURVIVOR_CONTEST_CREDITS, contest.key().as_ref()],
+        bump
+    )]
+    pub contest_credits: Box<Account<'info, SurvivorContestCredits>>,
+
+    #[account(
+        seeds = [SEED_FEED_REGISTRY],
+        bump
+    )]
+    pub feed_registry: Box<Account<'info, FeedRegistry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct EnterSurvivorContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, SurvivorContest>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + SurvivorContestEntry::INIT_SPACE,
+        seeds = [SEED_SURVIVOR_CONTEST_ENTRY, contest.key().as_ref(), signer.key().as_ref()],
+        bump,
+    )]
+    pub contest_entry: Box<Account<'info, SurvivorContestEntry>>,
+
+    #[account(
+        mut,
+        realloc = contest_credits.to_account_info().data_len() + std::mem::size_of::<bool>() + contest.token_feed_ids.len(),
+        realloc::payer = signer,
+        realloc::zero = false,
+        seeds = [SEED_SURVIVOR_CONTEST_CREDITS, contest.key().as_ref()],
+        bump
+    )]
+    pub contest_credits: Box<Account<'info, SurvivorContestCredits>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RedraftSurvivorContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub contest: Box<Account<'info, SurvivorContest>>,
+
+    #[account(
+        seeds = [SEED_SURVIVOR_CONTEST_ENTRY, contest.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub contest_entry: Box<Account<'info, SurvivorContestEntry>>,
+
+    #[account(
+        mut,
+        realloc = contest_credits.to_account_info().data_len() + SurvivorContestCredits::redraft_space(contest.token_feed_ids.len()),
+        realloc::payer = signer,
+        realloc::zero = false,
+        seeds = [SEED_SURVIVOR_CONTEST_CREDITS, contest.key().as_ref()],
+        bump
+    )]
+    pub contest_credits: Box<Account<'info, SurvivorContestCredits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RefundSurvivorContestEntry<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    pub contest: Box<Account<'info, SurvivorContest>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SURVIVOR_CONTEST_ENTRY, contest.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub contest_entry: Box<Account<'info, SurvivorContestEntry>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct PostSurvivorRoundPrices<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, SurvivorContest>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveSurvivorRound<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, SurvivorContest>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SURVIVOR_CONTEST_CREDITS, contest.key().as_ref()],
+        bump
+    )]
+    pub contest_credits: Box<Account<'info, SurvivorContestCredits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimSurvivorContest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_CONFIG],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    pub contest: Box<Account<'info, SurvivorContest>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SURVIVOR_CONTEST_ENTRY, contest.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub contest_entry: Box<Account<'info, SurvivorContestEntry>>,
+
+    #[account(
+        seeds = [SEED_SURVIVOR_CONTEST_CREDITS, contest.key().as_ref()],
+        bump
+    )]
+    pub contest_credits: Box<Account<'info, SurvivorContestCredits>>,
+
+    #[account(address = config.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        seeds = [SEED_PROGRAM_TOKEN_ACCOUNT, mint.key().to_bytes().as_ref()],
+        bump
+    )]
+    pub program_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = signer,
+    )]
+    pub signer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_survivor_contest(
+    ctx: Context<CreateSurvivorContest>,
+    start_time: u64,
+    draft_duration: u64,
+    round_duration: u64,
+    num_rounds: u8,
+    elimination_percent: u8,
+    entry_fee: u64,
+    max_entries: u32,
+    token_feed_ids: Vec<Pubkey>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    // Contest must start in some future time
+    require!(start_time > current_time, ContestError::InvalidDuration);
+
+    // Rounds must last for some time
+    require!(round_duration > 0, ContestError::InvalidDuration);
+
+    // At least one round must be played and each round must eliminate some but not all entries
+    require!(
+        num_rounds > 0 && elimination_percent > 0 && elimination_percent < 100,
+        ContestError::InvalidRoundConfig
+    );
+
+    // At least one token must be selected for the draft and no more than MAX_TOKEN_PER_DRAFT
+    require!(
+        !token_feed_ids.is_empty() && token_feed_ids.len() <= MAX_TOKEN_PER_DRAFT,
+        ContestError::InvalidDraftTokenCount
+    );
+
+    // Check that valid feeds are provided
+    load_price_feeds(ctx.remaining_accounts, token_feed_ids.len())?;
+
+    // Only enabled feeds from the registry can be drafted
+    let token_min_confidence_ratios = ctx
+        .accounts
+        .feed_registry
+        .get_min_confidence_ratios(&token_feed_ids)?;
+
+    // Set contest parameters, the first round starts once the initial draft closes
+    let contest = &mut ctx.accounts.contest;
+    contest.id = ctx.accounts.contest_metadata.survivor_contest_count;
+    contest.creator = ctx.accounts.signer.key();
+    contest.entry_fee = entry_fee;
+    contest.max_entries = max_entries;
+    contest.num_rounds = num_rounds;
+    contest.elimination_percent = elimination_percent;
+    contest.draft_duration = draft_duration;
+    contest.round_duration = round_duration;
+    contest.round_start_time = start_time;
+    contest.round_end_time = start_time + round_duration;
+    contest.token_feed_ids = token_feed_ids;
+    contest.token_min_confidence_ratios = token_min_confidence_ratios;
+
+    // Initialize credit data
+    ctx.accounts.contest_credits.contest_key = contest.key();
+
+    // Update contest metadata
+    ctx.accounts.contest_metadata.survivor_contest_count += 1;
+
+    let contest = &ctx.accounts.contest;
+    emit!(SurvivorContestCreated {
+        contest: contest.key(),
+        id: contest.id,
+        creator: contest.creator,
+        start_time,
+        draft_duration,
+        round_duration,
+        num_rounds,
+        elimination_percent,
+        entry_fee,
+        max_entries,
+        token_feed_ids: contest.token_feed_ids.clone(),
+    });
+
+    Ok(())
+}
+
+pub fn enter_survivor_contest(
+    ctx: Context<EnterSurvivorContest>,
+    credit_allocation: Vec<i8>,
+) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+
+    // Check if the contest entry is closed, entries are only taken before the first round
+    require!(
+        contest.current_round == 0 && contest.is_draft_active(),
+        ContestError::EntryClosed
+    );
+
+    // Check if the contest is already full
+    require!(
+        contest.num_entries < contest.max_entries,
+        ContestError::AlreadyFull
+    );
+
+    contest.validate_credit_allocation(&credit_allocation)?;
+
+    // Transfer entry fee from the user's token account to the program's token account
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.signer_token_account.to_account_info(),
+        to: ctx.accounts.program_token_account.to_account_info(),
+        authority: ctx.accounts.signer.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+    transfer_checked(cpi_context, contest.entry_fee, ctx.accounts.mint.decimals)?;
+
+    // Update number of entries
+    contest.num_entries += 1;
+    contest.num_survivors += 1;
+
+    // Create a new participation record
+    let contest_entry = &mut ctx.accounts.contest_entry;
+    contest_entry.user = ctx.accounts.signer.key();
+    contest_entry.id = contest.num_entries - 1;
+    contest_entry.contest_key = contest.key();
+
+    // Append to credit allocation account
+    let contest_credits = &mut ctx.accounts.contest_credits;
+    contest_credits.is_alive.push(true);
+    contest_credits
+        .credit_allocations
+        .extend_from_slice(&credit_allocation);
+
+    emit!(SurvivorEntrySubmitted {
+        contest: contest.key(),
+        entry: contest_entry.key(),
+        user: contest_entry.user,
+        entry_id: contest_entry.id,
+        credit_allocation,
+    });
+
+    Ok(())
+}
+
+pub fn redraft_survivor_contest(
+    ctx: Context<RedraftSurvivorContest>,
+    credit_allocation: Vec<i8>,
+) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    let entry_id = ctx.accounts.contest_entry.id as usize;
+    let contest_credits = &mut ctx.accounts.contest_credits;
+
+    // Re-drafting is only open between rounds
+    require!(
+        !contest.is_resolved && contest.is_draft_active(),
+        ContestError::EntryClosed
+    );
+
+    // Check that the entry survived the previous rounds
+    require!(contest_credits.is_alive[entry_id], ContestError::Eliminated);
+
+    contest.validate_credit_allocation(&credit_allocation)?;
+
+    // Append a row applying from the next round
+    contest_credits.redraft_entry_ids.push(entry_id as u32);
+    contest_credits.redraft_rounds.push(contest.current_round);
+    contest_credits
+        .redraft_allocations
+        .extend_from_slice(&credit_allocation);
+
+    let contest_entry = &ctx.accounts.contest_entry;
+    emit!(SurvivorEntryRedrafted {
+        contest: contest.key(),
+        entry: contest_entry.key(),
+        user: contest_entry.user,
+        entry_id: contest_entry.id,
+        round: contest.current_round,
+        credit_allocation,
+    });
+
+    Ok(())
+}
+
+pub fn post_survivor_round_prices(ctx: Context<PostSurvivorRoundPrices>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    require!(!contest.is_resolved, ContestError::AlreadyResolved);
+    require!(!contest.is_draft_active(), ContestError::ContestNotStarted);
+    require!(
+        contest.token_start_prices.is_empty(),
+        ContestError::PricesAlreadyPosted
+    );
+
+    // Once the posting window is missed every entry fee is refunded instead
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    require!(
+        current_time <= contest.start_price_deadline(),
+        ContestError::PricePostingClosed
+    );
+
+    // Set start prices of the current round for each token, published right at the round start
+    let (token_start_prices, publish_times) = get_token_price_updates(
+        ctx.remaining_accounts,
+        &contest.token_feed_ids,
+        &contest.token_min_confidence_ratios,
+    )?;
+    calc_publish_delay(&publish_times, contest.round_start_time)?;
+    ctx.accounts.contest.token_start_prices = token_start_prices.clone();
+
+    emit!(PricesPosted {
+        contest: ctx.accounts.contest.key(),
+        snapshot: PriceSnapshot::Round {
+            index: ctx.accounts.contest.current_round,
+        },
+        token_prices: token_start_prices,
+        publish_times,
+    });
+
+    Ok(())
+}
+
+// Every entry, eliminated or not, takes its entry fee back from a contest stuck on a round
+pub fn refund_survivor_contest_entry(ctx: Context<RefundSurvivorContestEntry>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    let contest_entry = &mut ctx.accounts.contest_entry;
+
+    require!(
+        contest.has_missed_price_posting(),
+        ContestError::NotRefundable
+    );
+    require!(!contest_entry.has_claimed, ContestError::AlreadyClaimed);
+
+    // Refund the entry fee to the user
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.program_token_account.to_account_info(),
+        to: ctx.accounts.signer_token_account.to_account_info(),
+        authority: ctx.accounts.program_token_account.to_account_info(),
+    };
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_PROGRAM_TOKEN_ACCOUNT,
+        mint_key.as_ref(),
+        &[ctx.bumps.program_token_account],
+    ]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_context, contest.entry_fee, ctx.accounts.mint.decimals)?;
+
+    // Mark the entry as refunded
+    contest_entry.has_claimed = true;
+
+    emit!(EntryRefunded {
+        contest: contest.key(),
+        entry: contest_entry.key(),
+        user: contest_entry.user,
+        amount: contest.entry_fee,
+    });
+
+    Ok(())
+}
+
+pub fn resolve_survivor_round(ctx: Context<ResolveSurvivorRound>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+
+    require!(!contest.is_resolved, ContestError::AlreadyResolved);
+
+    require!(
+        !contest.token_start_prices.is_empty(),
+        ContestError::ContestPriceNotSet
+    );
+
+    // Check that the round has ended
+    require!(contest.has_round_ended(), ContestError::ContestNotEnded);
+
+    let token_rois = get_token_rois(
+        ctx.remaining_accounts,
+        &contest.token_feed_ids,
+        &contest.token_start_prices,
+        &contest.token_min_confidence_ratios,
+        contest.round_end_time,
+    )?;
+
+    // Score the surviving entries by their ROI over this round
+    let contest_credits = &mut ctx.accounts.contest_credits;
+    let num_tokens = contest.token_feed_ids.len();
+    let scores: Vec<(usize, f64)> = (0..contest_credits.is_alive.len())
+        .filter(|&i| contest_credits.is_alive[i])
+        .map(|i| {
+            let allocation = contest_credits.round_allocation(i, contest.current_round, num_tokens);
+            (i, -calc_avg_roi(allocation, &token_rois, None))
+        })
+        .collect();
+
+    // Eliminate the bottom entries, ranking by negated ROI picks the lowest
+    let num_eliminated = contest.num_eliminated();
+    let mut eliminated_ids: Vec<u32> = Vec::with_capacity(num_eliminated);
+    for (i, _) in find_top_n(&scores, num_eliminated) {
+        contest_credits.is_alive[i] = false;
+        eliminated_ids.push(i as u32);
+    }
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    let contest = &mut ctx.accounts.contest;
+    let round = contest.current_round;
+    contest.num_survivors -= num_eliminated as u32;
+    contest.current_round += 1;
+    contest.token_start_prices = Vec::new();
+
+    let mut fee_amount = 0;
+    if contest.current_round < contest.num_rounds {
+        // Open the re-draft window before the next round starts
+        contest.round_start_time = current_time + contest.draft_duration;
+        contest.round_end_time = contest.round_start_time + contest.round_duration;
+    } else {
+        contest.is_resolved = true;
+
+        // Accumulate the fee amount from this contest
+        let total_pool_amount = contest.pool_amount();
+        fee_amount = calc_fee_amount(
+            total_pool_amount,
+            ctx.accounts
+                .contest_metadata
+                .token_draft_contest_fee_percent,
+        );
+        ctx.accounts.contest_metadata.token_draft_contest_fee_amount += fee_amount;
+    }
+
+    emit!(SurvivorRoundResolved {
+        contest: contest.key(),
+        round,
+        token_rois,
+        eliminated_ids,
+        num_survivors: contest.num_survivors,
+        fee_amount,
+    });
+
+    Ok(())
+}
+
+pub fn claim_survivor_contest(ctx: Context<ClaimSurvivorContest>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    let contest_entry = &mut ctx.accounts.contest_entry;
+
+    // Check if the contest has already been resolved
+    require!(contest.is_resolved, ContestError::ContestNotResolved);
+
+    // Check if the user has already claimed their rewards
+    require!(!contest_entry.has_claimed, ContestError::AlreadyClaimed);
+
+    // Only the last survivors share the pot
+    require!(
+        ctx.accounts.contest_credits.is_alive[contest_entry.id as usize],
+        ContestError::NotWinner
+    );
+
+    // Split the pot equally among the survivors
+    let total_pool_amount = contest.pool_amount();
+    let fee_amount = calc_fee_amount(
+        total_pool_amount,
+        ctx.accounts
+            .contest_metadata
+            .token_draft_contest_fee_percent,
+    );
+    let user_reward_amount = (total_pool_amount - fee_amount) / contest.num_survivors as u64;
+
+    // Transfer the reward to the user's token account
+    let cpi_accounts = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.program_token_account.to_account_info(),
+        to: ctx.accounts.signer_token_account.to_account_info(),
+        authority: ctx.accounts.program_token_account.to_account_info(),
+    };
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SEED_PROGRAM_TOKEN_ACCOUNT,
+        mint_key.as_ref(),
+        &[ctx.bumps.program_token_account],
+    ]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
+    transfer_checked(cpi_context, user_reward_amount, ctx.accounts.mint.decimals)?;
+
+    // Mark the entry as claimed
+    contest_entry.has_claimed = true;
+
+    emit!(RewardClaimed {
+        contest: contest.key(),
+        entry: contest_entry.key(),
+        user: contest_entry.user,
+        amount: user_reward_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use crate::constants::seeds::SEED_CONTEST_METADATA;
+use crate::errors::ContestError;
+use crate::events::PrizesSwept;
+use crate::state::contest::TokenDraftContest;
+use crate::state::metadata::ContestMetadata;
+use crate::state::season::Season;
+use anchor_lang::prelude::*;
+use protocol_scoring::payout::calc_fee_amount;
+
+#[derive(Accounts)]
+pub struct SweepTokenDraftContest<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, TokenDraftContest>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CONTEST_METADATA],
+        bump
+    )]
+    pub contest_metadata: Box<Account<'info, ContestMetadata>>,
+
+    #[account(mut)]
+    pub season: Option<Box<Account<'info, Season>>>,
+}
+
+// Moves prizes nobody claimed before the deadline into the contest's season prize pool, or into the
+// protocol fees when the contest has no season or the season is already finalized. Tokens never
+// leave the program token account, only the accounting changes.
+pub fn sweep_token_draft_contest(ctx: Context<SweepTokenDraftContest>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+
+    require!(contest.is_resolved, ContestError::ContestNotResolved);
+    require!(contest.is_claim_expired(), ContestError::ClaimWindowOpen);
+
+    let total_pool_amount = contest.pool_amount();
+    let fee_amount = calc_fee_amount(
+        total_pool_amount,
+        ctx.accounts
+            .contest_metadata
+            .token_draft_contest_fee_percent,
+    );
+    let unclaimed_amount = total_pool_amount - fee_amount - contest.paid_amount;
+    require!(unclaimed_amount > 0, ContestError::NothingToSweep);
+
+    // Season contests must pass their season so the sweep cannot be routed around the prize pool
+    let season = match contest.season {
+        Some(season_key) => {
+            let season = ctx
+                .accounts
+                .season
+                .as_mut()
+                .ok_or(ContestError::SeasonMismatch)?;
+            require!(season.key() == season_key, ContestError::SeasonMismatch);
+            Some(season)
+        }
+        None => None,
+    };
+
+    match season.filter(|season| !season.is_finalized) {
+        Some(season) => season.prize_pool_amount += unclaimed_amount,
+        None => ctx.accounts.contest_metadata.token_draft_contest_fee_amount += unclaimed_amount,
+    }
+
+    // Every prize is settled one way or another, so the contest can be closed
+    contest.paid_amount += unclaimed_amount;
+    contest.num_pending_claims = 0;
+
+    emit!(PrizesSwept {
+        contest: contest.key(),
+        season: contest.season,
+        amount: unclaimed_amount,
+    });
+
+    Ok(())
+}
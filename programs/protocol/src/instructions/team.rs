@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::seeds::SEED_TEAM;
+use crate::errors::ContestError;
+use crate::events::TeamCreated;
+use crate::state::contest::TokenDraftContest;
+use crate::state::team::Team;
+
+#[derive(Accounts)]
+#[instruction(members: Vec<Pubkey>)]
+pub struct CreateTeam<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub contest: Box<Account<'info, TokenDraftContest>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + Team::space(members.len()),
+        seeds = [SEED_TEAM, contest.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub team: Box<Account<'info, Team>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_team(
+    ctx: Context<CreateTeam>,
+    members: Vec<Pubkey>,
+    member_weights: Vec<u8>,
+) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let signer = ctx.accounts.signer.key();
+
+    // Teams can only form for team contests before they start
+    let Some(team_config) = contest.team_config else {
+        return err!(ContestError::InvalidTeam);
+    };
+    require!(contest.is_entry_active(), ContestError::EntryClosed);
+
+    // Team must include its creator and fit within the contest's team size
+    require!(
+        !members.is_empty()
+            && members.len() <= team_config.max_team_size as usize
+            && members.contains(&signer),
+        ContestError::InvalidTeam
+    );
+
+    // Members must be unique
+    let is_members_good = members
+        .iter()
+        .enumerate()
+        .all(|(i, member)| !members[..i].contains(member));
+    require!(is_members_good, ContestError::InvalidTeam);
+
+    // Every member must have a weight and the weights must sum to 100
+    let is_weights_good = member_weights.len() == members.len()
+        && member_weights.iter().map(|&v| v as u32).sum::<u32>() == 100;
+    require!(is_weights_good, ContestError::InvalidTeam);
+
+    let team = &mut ctx.accounts.team;
+    team.id = contest.num_teams;
+    team.contest_key = contest.key();
+    team.creator = signer;
+    team.members = members;
+    team.member_weights = member_weights;
+
+    contest.num_teams += 1;
+
+    emit!(TeamCreated {
+        contest: contest.key(),
+        team: team.key(),
+        team_id: team.id,
+        creator: signer,
+        members: team.members.clone(),
+        member_weights: team.member_weights.clone(),
+    });
+
+    Ok(())
+}
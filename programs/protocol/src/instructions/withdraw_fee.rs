@@ -1,5 +1,6 @@
 use crate::{
     constants::seeds::{SEED_CONFIG, SEED_CONTEST_METADATA, SEED_PROGRAM_TOKEN_ACCOUNT},
+    events::FeeWithdrawn,
     state::{config::Config, metadata::ContestMetadata},
 };
 use anchor_lang::prelude::*;
@@ -67,5 +68,11 @@ pub fn withdraw_fee(ctx: Context<WithdrawFee>) -> Result<()> {
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(signer_seeds);
     transfer_checked(cpi_context, total_fee_amount, ctx.accounts.mint.decimals)?;
 
+    emit!(FeeWithdrawn {
+        admin: ctx.accounts.signer.key(),
+        withdrawal_token_account: ctx.accounts.withdrawal_token_account.key(),
+        amount: total_fee_amount,
+    });
+
     Ok(())
 }
@@ -3,10 +3,15 @@ use ephemeral_rollups_sdk::anchor::ephemeral;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use instructions::*;
+use state::contest::{ContestAccess, SalaryCap, TeamConfig};
+use state::over_under::OverUnderSide;
+use state::range::RangeTarget;
 
 declare_id!("3xA3kyUTzM9Pa24qSKQDdZmd9JoiD4UBAqJCsSckYeeZ");
 
@@ -26,6 +31,36 @@ pub mod protocol {
         initialize::init_token_accounts(ctx)
     }
 
+    pub fn init_feed_registry(ctx: Context<InitFeedRegistry>, curator: Pubkey) -> Result<()> {
+        feed_registry::init_feed_registry(ctx, curator)
+    }
+
+    pub fn set_feed_registry_curator(
+        ctx: Context<SetFeedRegistryCurator>,
+        curator: Pubkey,
+    ) -> Result<()> {
+        feed_registry::set_feed_registry_curator(ctx, curator)
+    }
+
+    pub fn add_registry_feed(
+        ctx: Context<AddRegistryFeed>,
+        feed_id: Pubkey,
+        symbol: String,
+        min_confidence_ratio: u32,
+    ) -> Result<()> {
+        feed_registry::add_registry_feed(ctx, feed_id, symbol, min_confidence_ratio)
+    }
+
+    pub fn update_registry_feed(
+        ctx: Context<UpdateRegistryFeed>,
+        feed_id: Pubkey,
+        min_confidence_ratio: u32,
+        is_enabled: bool,
+    ) -> Result<()> {
+        feed_registry::update_registry_feed(ctx, feed_id, min_confidence_ratio, is_enabled)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn create_token_draft_contest(
         ctx: Context<CreateTokenDraftContest>,
         start_time: u64,
@@ -34,6 +69,13 @@ pub mod protocol {
         max_entries: u32,
         token_feed_ids: Vec<Pubkey>,
         reward_allocation: Vec<u8>,
+        access: ContestAccess,
+        salary_cap: Option<SalaryCap>,
+        allow_short: bool,
+        captain_multiplier_bps: Option<u16>,
+        team_config: Option<TeamConfig>,
+        rebalance_times: Vec<u64>,
+        claim_window: Option<u64>,
     ) -> Result<()> {
         create::create_token_draft_contest(
             ctx,
@@ -43,14 +85,23 @@ pub mod protocol {
             max_entries,
             token_feed_ids,
             reward_allocation,
+            access,
+            salary_cap,
+            allow_short,
+            captain_multiplier_bps,
+            team_config,
+            rebalance_times,
+            claim_window,
         )
     }
 
     pub fn enter_token_draft_contest(
         ctx: Context<EnterTokenDraftContest>,
-        credit_allocation: Vec<u8>,
+        credit_allocation: Vec<i8>,
+        access_proof: Vec<[u8; 32]>,
+        captain: Option<u8>,
     ) -> Result<()> {
-        enter::enter_token_draft_contest(ctx, credit_allocation)
+        enter::enter_token_draft_contest(ctx, credit_allocation, access_proof, captain)
     }
 
     pub fn post_token_draft_contest_prices(
@@ -67,6 +118,371 @@ pub mod protocol {
         claim::claim_token_draft_contest(ctx)
     }
 
+    pub fn claim_many_token_draft_contests<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimManyTokenDraftContests<'info>>,
+    ) -> Result<()> {
+        claim::claim_many_token_draft_contests(ctx)
+    }
+
+    pub fn close_token_draft_contest_entry(
+        ctx: Context<CloseTokenDraftContestEntry>,
+    ) -> Result<()> {
+        close::close_token_draft_contest_entry(ctx)
+    }
+
+    pub fn close_token_draft_contest(ctx: Context<CloseTokenDraftContest>) -> Result<()> {
+        close::close_token_draft_contest(ctx)
+    }
+
+    pub fn close_team(ctx: Context<CloseTeam>) -> Result<()> {
+        close::close_team(ctx)
+    }
+
+    pub fn distribute_token_draft_contest_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeTokenDraftContestRewards<'info>>,
+    ) -> Result<()> {
+        distribute::distribute_token_draft_contest_rewards(ctx)
+    }
+
+    pub fn cancel_token_draft_contest(ctx: Context<CancelTokenDraftContest>) -> Result<()> {
+        cancel::cancel_token_draft_contest(ctx)
+    }
+
+    pub fn refund_token_draft_contest_entry(
+        ctx: Context<RefundTokenDraftContestEntry>,
+    ) -> Result<()> {
+        cancel::refund_token_draft_contest_entry(ctx)
+    }
+
+    pub fn pay_token_draft_contest_keeper_bounties(
+        ctx: Context<PayTokenDraftContestKeeperBounties>,
+    ) -> Result<()> {
+        keeper::pay_token_draft_contest_keeper_bounties(ctx)
+    }
+
+    pub fn sweep_token_draft_contest(ctx: Context<SweepTokenDraftContest>) -> Result<()> {
+        sweep::sweep_token_draft_contest(ctx)
+    }
+
+    pub fn create_head_to_head_contest(
+        ctx: Context<CreateHeadToHeadContest>,
+        start_time: u64,
+        end_time: u64,
+        stake: u64,
+        token_feed_ids: Vec<Pubkey>,
+        credit_allocation: Vec<i8>,
+    ) -> Result<()> {
+        head_to_head::create_head_to_head_contest(
+            ctx,
+            start_time,
+            end_time,
+            stake,
+            token_feed_ids,
+            credit_allocation,
+        )
+    }
+
+    pub fn accept_head_to_head_contest(
+        ctx: Context<AcceptHeadToHeadContest>,
+        credit_allocation: Vec<i8>,
+    ) -> Result<()> {
+        head_to_head::accept_head_to_head_contest(ctx, credit_allocation)
+    }
+
+    pub fn cancel_head_to_head_contest(ctx: Context<CancelHeadToHeadContest>) -> Result<()> {
+        head_to_head::cancel_head_to_head_contest(ctx)
+    }
+
+    pub fn refund_head_to_head_contest(ctx: Context<RefundHeadToHeadContest>) -> Result<()> {
+        head_to_head::refund_head_to_head_contest(ctx)
+    }
+
+    pub fn post_head_to_head_contest_prices(
+        ctx: Context<PostHeadToHeadContestPrices>,
+    ) -> Result<()> {
+        head_to_head::post_head_to_head_contest_prices(ctx)
+    }
+
+    pub fn resolve_head_to_head_contest(ctx: Context<ResolveHeadToHeadContest>) -> Result<()> {
+        head_to_head::resolve_head_to_head_contest(ctx)
+    }
+
+    pub fn claim_head_to_head_contest(ctx: Context<ClaimHeadToHeadContest>) -> Result<()> {
+        head_to_head::claim_head_to_head_contest(ctx)
+    }
+
+    pub fn create_over_under_contest(
+        ctx: Context<CreateOverUnderContest>,
+        start_time: u64,
+        end_time: u64,
+        feed_id: Pubkey,
+        strike_price: f64,
+    ) -> Result<()> {
+        over_under::create_over_under_contest(ctx, start_time, end_time, feed_id, strike_price)
+    }
+
+    pub fn enter_over_under_contest(
+        ctx: Context<EnterOverUnderContest>,
+        side: OverUnderSide,
+        amount: u64,
+    ) -> Result<()> {
+        over_under::enter_over_under_contest(ctx, side, amount)
+    }
+
+    pub fn resolve_over_under_contest(ctx: Context<ResolveOverUnderContest>) -> Result<()> {
+        over_under::resolve_over_under_contest(ctx)
+    }
+
+    pub fn claim_over_under_contest(ctx: Context<ClaimOverUnderContest>) -> Result<()> {
+        over_under::claim_over_under_contest(ctx)
+    }
+
+    pub fn create_parimutuel_contest(
+        ctx: Context<CreateParimutuelContest>,
+        start_time: u64,
+        end_time: u64,
+        token_feed_ids: Vec<Pubkey>,
+    ) -> Result<()> {
+        parimutuel::create_parimutuel_contest(ctx, start_time, end_time, token_feed_ids)
+    }
+
+    pub fn enter_parimutuel_contest(
+        ctx: Context<EnterParimutuelContest>,
+        token_index: u8,
+        amount: u64,
+    ) -> Result<()> {
+        parimutuel::enter_parimutuel_contest(ctx, token_index, amount)
+    }
+
+    pub fn post_parimutuel_contest_prices(ctx: Context<PostParimutuelContestPrices>) -> Result<()> {
+        parimutuel::post_parimutuel_contest_prices(ctx)
+    }
+
+    pub fn cancel_parimutuel_contest(ctx: Context<CancelParimutuelContest>) -> Result<()> {
+        parimutuel::cancel_parimutuel_contest(ctx)
+    }
+
+    pub fn resolve_parimutuel_contest(ctx: Context<ResolveParimutuelContest>) -> Result<()> {
+        parimutuel::resolve_parimutuel_contest(ctx)
+    }
+
+    pub fn claim_parimutuel_contest(ctx: Context<ClaimParimutuelContest>) -> Result<()> {
+        parimutuel::claim_parimutuel_contest(ctx)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_range_contest(
+        ctx: Context<CreateRangeContest>,
+        start_time: u64,
+        end_time: u64,
+        entry_fee: u64,
+        max_entries: u32,
+        feed_id: Pubkey,
+        target: RangeTarget,
+        reward_allocation: Vec<u8>,
+    ) -> Result<()> {
+        range::create_range_contest(
+            ctx,
+            start_time,
+            end_time,
+            entry_fee,
+            max_entries,
+            feed_id,
+            target,
+            reward_allocation,
+        )
+    }
+
+    pub fn enter_range_contest(ctx: Context<EnterRangeContest>, prediction: f64) -> Result<()> {
+        range::enter_range_contest(ctx, prediction)
+    }
+
+    pub fn post_range_contest_price(ctx: Context<PostRangeContestPrice>) -> Result<()> {
+        range::post_range_contest_price(ctx)
+    }
+
+    pub fn resolve_range_contest(ctx: Context<ResolveRangeContest>) -> Result<()> {
+        range::resolve_range_contest(ctx)
+    }
+
+    pub fn claim_range_contest(ctx: Context<ClaimRangeContest>) -> Result<()> {
+        range::claim_range_contest(ctx)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_survivor_contest(
+        ctx: Context<CreateSurvivorContest>,
+        start_time: u64,
+        draft_duration: u64,
+        round_duration: u64,
+        num_rounds: u8,
+        elimination_percent: u8,
+        entry_fee: u64,
+        max_entries: u32,
+        token_feed_ids: Vec<Pubkey>,
+    ) -> Result<()> {
+        survivor::create_survivor_contest(
+            ctx,
+            start_time,
+            draft_duration,
+            round_duration,
+            num_rounds,
+            elimination_percent,
+            entry_fee,
+            max_entries,
+            token_feed_ids,
+        )
+    }
+
+    pub fn enter_survivor_contest(
+        ctx: Context<EnterSurvivorContest>,
+        credit_allocation: Vec<i8>,
+    ) -> Result<()> {
+        survivor::enter_survivor_contest(ctx, credit_allocation)
+    }
+
+    pub fn redraft_survivor_contest(
+        ctx: Context<RedraftSurvivorContest>,
+        credit_allocation: Vec<i8>,
+    ) -> Result<()> {
+        survivor::redraft_survivor_contest(ctx, credit_allocation)
+    }
+
+    pub fn refund_survivor_contest_entry(
+        ctx: Context<RefundSurvivorContestEntry>,
+    ) -> Result<()> {
+        survivor::refund_survivor_contest_entry(ctx)
+    }
+
+    pub fn post_survivor_round_prices(ctx: Context<PostSurvivorRoundPrices>) -> Result<()> {
+        survivor::post_survivor_round_prices(ctx)
+    }
+
+    pub fn resolve_survivor_round(ctx: Context<ResolveSurvivorRound>) -> Result<()> {
+        survivor::resolve_survivor_round(ctx)
+    }
+
+    pub fn claim_survivor_contest(ctx: Context<ClaimSurvivorContest>) -> Result<()> {
+        survivor::claim_survivor_contest(ctx)
+    }
+
+    pub fn create_season(
+        ctx: Context<CreateSeason>,
+        start_time: u64,
+        end_time: u64,
+        points_table: Vec<u32>,
+        fee_share_percent: u8,
+        prize_allocation: Vec<u8>,
+    ) -> Result<()> {
+        season::create_season(
+            ctx,
+            start_time,
+            end_time,
+            points_table,
+            fee_share_percent,
+            prize_allocation,
+        )
+    }
+
+    pub fn add_season_contest(ctx: Context<AddSeasonContest>) -> Result<()> {
+        season::add_season_contest(ctx)
+    }
+
+    pub fn settle_season_contest(ctx: Context<SettleSeasonContest>) -> Result<()> {
+        season::settle_season_contest(ctx)
+    }
+
+    pub fn award_season_points(ctx: Context<AwardSeasonPoints>) -> Result<()> {
+        season::award_season_points(ctx)
+    }
+
+    pub fn finalize_season(ctx: Context<FinalizeSeason>) -> Result<()> {
+        season::finalize_season(ctx)
+    }
+
+    pub fn claim_season_prize(ctx: Context<ClaimSeasonPrize>) -> Result<()> {
+        season::claim_season_prize(ctx)
+    }
+
+    pub fn create_team(
+        ctx: Context<CreateTeam>,
+        members: Vec<Pubkey>,
+        member_weights: Vec<u8>,
+    ) -> Result<()> {
+        team::create_team(ctx, members, member_weights)
+    }
+
+    pub fn post_token_draft_contest_checkpoint(
+        ctx: Context<PostTokenDraftContestCheckpoint>,
+    ) -> Result<()> {
+        post_prices::post_token_draft_contest_checkpoint(ctx)
+    }
+
+    pub fn rebalance_token_draft_contest_entry(
+        ctx: Context<RebalanceTokenDraftContestEntry>,
+        credit_allocation: Vec<i8>,
+    ) -> Result<()> {
+        rebalance::rebalance_token_draft_contest_entry(ctx, credit_allocation)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_snake_draft_contest(
+        ctx: Context<CreateSnakeDraftContest>,
+        entry_fee: u64,
+        max_players: u8,
+        picks_per_player: u8,
+        pick_duration: u64,
+        contest_duration: u64,
+        feed_indices: Vec<u8>,
+        reward_allocation: Vec<u8>,
+        fill_duration: u64,
+    ) -> Result<()> {
+        snake_draft::create_snake_draft_contest(
+            ctx,
+            entry_fee,
+            max_players,
+            picks_per_player,
+            pick_duration,
+            contest_duration,
+            feed_indices,
+            reward_allocation,
+            fill_duration,
+        )
+    }
+
+    pub fn join_snake_draft_contest(ctx: Context<JoinSnakeDraftContest>) -> Result<()> {
+        snake_draft::join_snake_draft_contest(ctx)
+    }
+
+    pub fn refund_snake_draft_contest(ctx: Context<RefundSnakeDraftContest>) -> Result<()> {
+        snake_draft::refund_snake_draft_contest(ctx)
+    }
+
+    pub fn pick_snake_draft_token(
+        ctx: Context<PickSnakeDraftToken>,
+        token_index: u8,
+    ) -> Result<()> {
+        snake_draft::pick_snake_draft_token(ctx, token_index)
+    }
+
+    pub fn auto_pick_snake_draft_token(ctx: Context<AutoPickSnakeDraftToken>) -> Result<()> {
+        snake_draft::auto_pick_snake_draft_token(ctx)
+    }
+
+    pub fn post_snake_draft_contest_prices(
+        ctx: Context<PostSnakeDraftContestPrices>,
+    ) -> Result<()> {
+        snake_draft::post_snake_draft_contest_prices(ctx)
+    }
+
+    pub fn resolve_snake_draft_contest(ctx: Context<ResolveSnakeDraftContest>) -> Result<()> {
+        snake_draft::resolve_snake_draft_contest(ctx)
+    }
+
+    pub fn claim_snake_draft_contest(ctx: Context<ClaimSnakeDraftContest>) -> Result<()> {
+        snake_draft::claim_snake_draft_contest(ctx)
+    }
+
     pub fn withdraw_fee(ctx: Context<WithdrawFee>) -> Result<()> {
         withdraw_fee::withdraw_fee(ctx)
     }
@@ -1,6 +1,18 @@
+use crate::errors::ContestError;
+use crate::state::entry::{TokenDraftContestEntry, TOTAL_CREDIT_PER_CONTEST};
+use crate::state::team::Team;
+use crate::utils::price::PRICE_POSTING_WINDOW;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use protocol_scoring::payout::{calc_fee_amount, calc_reward_share, calc_weighted_share};
+use protocol_scoring::roi::calc_token_rois;
+use std::mem::size_of;
 
-pub const MAX_TOKEN_PER_DRAFT: usize = 5;
+pub const MAX_TOKEN_PER_DRAFT: usize = 20;
+pub const CAPTAIN_MULTIPLIER_BPS_BASE: u16 = 10_000;
+pub const MAX_REBALANCE_CHECKPOINTS: usize = 4;
+pub const DISTRIBUTE_REWARDS_BOUNTY_BPS: u16 = 50;
+pub const KEEPER_BOUNTY_BPS: u16 = 1_000;
 
 #[account]
 #[derive(InitSpace)]
@@ -19,13 +31,13 @@ pub struct TokenDraftContest {
 
     pub num_entries: u32,
 
-    #[max_len(MAX_TOKEN_PER_DRAFT)]
+    #[max_len(0)]
     pub token_feed_ids: Vec<Pubkey>,
 
-    #[max_len(MAX_TOKEN_PER_DRAFT)]
+    #[max_len(0)]
     pub token_start_prices: Vec<f64>,
 
-    #[max_len(MAX_TOKEN_PER_DRAFT)]
+    #[max_len(0)]
     pub token_rois: Vec<f64>,
 
     #[max_len(0)]
@@ -35,9 +47,123 @@ pub struct TokenDraftContest {
     pub winner_reward_allocation: Vec<u8>,
 
     pub is_resolved: bool,
+
+    pub access: ContestAccess,
+
+    #[max_len(0)]
+    pub token_min_confidence_ratios: Vec<u32>,
+
+    pub salary_cap: Option<SalaryCap>,
+
+    pub allow_short: bool,
+
+    pub captain_multiplier_bps: Option<u16>,
+
+    pub season: Option<Pubkey>,
+
+    pub is_season_settled: bool,
+
+    pub team_config: Option<TeamConfig>,
+
+    pub num_teams: u32,
+
+    #[max_len(0)]
+    pub rebalance_times: Vec<u64>,
+
+    // Token prices snapshotted at each posted checkpoint, one row of token prices per checkpoint
+    #[max_len(0)]
+    pub checkpoint_prices: Vec<f64>,
+
+    // Winning entries that have not been paid yet, the contest can only be closed once this is zero
+    pub num_pending_claims: u32,
+
+    // Seconds winners have to claim after resolution, unclaimed prizes can be swept once it passes
+    pub claim_window: Option<u64>,
+
+    pub claim_deadline: Option<u64>,
+
+    // Rewards paid out so far, whatever is left of the reward pool after the deadline is swept
+    pub paid_amount: u64,
+
+    // Keepers that posted the start prices and resolved the contest, each is owed
+    // keeper_bounty_amount out of the contest fee
+    pub price_poster: Option<Pubkey>,
+
+    pub resolver: Option<Pubkey>,
+
+    pub keeper_bounty_amount: u64,
+
+    pub has_paid_keeper_bounties: bool,
+
+    // Set when nobody posted start prices in time, every entry fee is refunded instead
+    pub is_cancelled: bool,
+
+    // Copied from the season when the contest is linked so the season's cut can be taken at resolve
+    pub season_fee_share_percent: u8,
+
+    pub season_scoring_positions: u32,
+
+    // Season's cut of the fee held back at resolve until the contest is settled into the season
+    pub season_prize_amount: u64,
+
+    // Entries finishing within the season points table that have not been awarded their points
+    pub num_pending_season_points: u32,
+
+    // The contest closes once every entry is closed and its credit row rent refunded
+    pub num_closed_entries: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ContestAccess {
+    Public,
+    Allowlist { merkle_root: [u8; 32] },
+    // The invite code is the secret key of the invite keypair, entrants co-sign with it so the
+    // code never appears on chain
+    InviteCode { invite_key: Pubkey },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct SalaryCap {
+    pub cap: u32,
+
+    #[max_len(0)]
+    pub token_salaries: Vec<u32>,
+
+    pub weighting: PickWeighting,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PickWeighting {
+    Credits,
+    Equal,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct TeamConfig {
+    pub max_team_size: u8,
+
+    pub scoring: TeamScoring,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum TeamScoring {
+    Average,
+    Best,
 }
 
 impl TokenDraftContest {
+    // Per token and per winner vectors are excluded from INIT_SPACE so that the account is only as
+    // large as the draft pool and payout table it is created with
+    pub fn space(num_tokens: usize, num_winners: usize, num_checkpoints: usize) -> usize {
+        let token_space = size_of::<Pubkey>() + 2 * size_of::<f64>() + 2 * size_of::<u32>();
+        let winner_space = size_of::<u32>() + size_of::<u8>();
+        let checkpoint_space = size_of::<u64>() + num_tokens * size_of::<f64>();
+        TokenDraftContest::INIT_SPACE
+            + num_tokens * token_space
+            + num_winners * winner_space
+            + num_checkpoints * checkpoint_space
+    }
+
     pub fn is_entry_active(&self) -> bool {
         let current_time = Clock::get().unwrap().unix_timestamp as u64;
         current_time < self.start_time
@@ -48,7 +174,257 @@ impl TokenDraftContest {
         current_time > self.end_time
     }
 
+    // Start prices can only be posted shortly after the start and never once the contest has ended
+    pub fn start_price_deadline(&self) -> u64 {
+        (self.start_time + PRICE_POSTING_WINDOW).min(self.end_time)
+    }
+
+    // Likewise each checkpoint must be posted shortly after its rebalance time
+    pub fn checkpoint_deadline(&self, checkpoint: usize) -> u64 {
+        (self.rebalance_times[checkpoint] + PRICE_POSTING_WINDOW).min(self.end_time)
+    }
+
+    // A contest missing a price snapshot past its deadline can never be resolved
+    pub fn has_missed_price_posting(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        if self.token_start_prices.is_empty() {
+            return current_time > self.start_price_deadline();
+        }
+        let checkpoint = self.num_checkpoints_posted();
+        checkpoint < self.rebalance_times.len()
+            && current_time > self.checkpoint_deadline(checkpoint)
+    }
+
     pub fn pool_amount(&self) -> u64 {
         self.entry_fee * self.num_entries as u64
     }
+
+    // Share of the contest fee the protocol keeps once both keeper bounties are taken out
+    pub fn protocol_fee_amount(&self, fee_percent: u8) -> u64 {
+        calc_fee_amount(self.pool_amount(), fee_percent) - 2 * self.keeper_bounty_amount
+    }
+
+    pub fn is_claim_expired(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        self.claim_deadline
+            .is_some_and(|claim_deadline| current_time > claim_deadline)
+    }
+
+    pub fn validate_credit_allocation(&self, credit_allocation: &[i8]) -> Result<()> {
+        require!(
+            self.token_feed_ids.len() == credit_allocation.len(),
+            ContestError::InvalidDraftTokenDistribution
+        );
+
+        // Negative credits short a token and are only allowed if the contest opts in
+        require!(
+            self.allow_short || credit_allocation.iter().all(|&v| v >= 0),
+            ContestError::InvalidDraftTokenDistribution
+        );
+
+        let sum_credits: u32 = credit_allocation
+            .iter()
+            .map(|&v| v.unsigned_abs() as u32)
+            .sum();
+        let Some(salary_cap) = &self.salary_cap else {
+            require!(
+                sum_credits == TOTAL_CREDIT_PER_CONTEST as u32,
+                ContestError::InvalidDraftTokenDistribution
+            );
+            return Ok(());
+        };
+
+        // Picks are either weighted by credits or weighted equally, in which case every pick is
+        // marked with a single credit
+        let is_distribution_good = match salary_cap.weighting {
+            PickWeighting::Credits => sum_credits == TOTAL_CREDIT_PER_CONTEST as u32,
+            PickWeighting::Equal => {
+                sum_credits > 0 && credit_allocation.iter().all(|&v| v.unsigned_abs() <= 1)
+            }
+        };
+        require!(
+            is_distribution_good,
+            ContestError::InvalidDraftTokenDistribution
+        );
+
+        // Check that the salaries of the picked tokens fit under the cap
+        let total_salary: u64 = credit_allocation
+            .iter()
+            .zip(salary_cap.token_salaries.iter())
+            .filter(|(&credits, _)| credits != 0)
+            .map(|(_, &salary)| salary as u64)
+            .sum();
+        require!(
+            total_salary <= salary_cap.cap as u64,
+            ContestError::SalaryCapExceeded
+        );
+
+        Ok(())
+    }
+
+    pub fn validate_captain(&self, credit_allocation: &[i8], captain: Option<u8>) -> Result<()> {
+        // Captain contests require a captain among the drafted tokens, other contests forbid one
+        let is_captain_good = match (self.captain_multiplier_bps, captain) {
+            (None, None) => true,
+            (Some(_), Some(captain)) => credit_allocation
+                .get(captain as usize)
+                .is_some_and(|&credits| credits != 0),
+            _ => false,
+        };
+        require!(is_captain_good, ContestError::InvalidCaptain);
+
+        Ok(())
+    }
+
+    // Each row in the credits account holds the credit allocation of every segment, followed by the
+    // captain index for captain contests
+    pub fn credit_row_len(&self) -> usize {
+        self.token_feed_ids.len() * self.num_segments()
+            + self.captain_multiplier_bps.is_some() as usize
+    }
+
+    // Rebalance checkpoints split the contest into segments that are scored separately
+    pub fn num_segments(&self) -> usize {
+        self.rebalance_times.len() + 1
+    }
+
+    pub fn num_checkpoints_posted(&self) -> usize {
+        self.checkpoint_prices.len() / self.token_feed_ids.len()
+    }
+
+    // Index of the first segment that a rebalance submitted now would apply to
+    pub fn next_rebalance_segment(&self) -> Option<usize> {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        self.rebalance_times
+            .iter()
+            .position(|&time| current_time < time)
+            .map(|i| i + 1)
+    }
+
+    // Each segment runs between consecutive price snapshots, from the start prices through every
+    // checkpoint to the end prices
+    pub fn segment_token_rois(&self, token_end_prices: &[f64]) -> Vec<Vec<f64>> {
+        let mut snapshots: Vec<&[f64]> = vec![&self.token_start_prices];
+        snapshots.extend(self.checkpoint_prices.chunks(self.token_feed_ids.len()));
+        snapshots.push(token_end_prices);
+        snapshots
+            .windows(2)
+            .map(|v| calc_token_rois(v[0], v[1]))
+            .collect()
+    }
+
+    // Team contests also record the team of each entry in the credits account
+    pub fn credits_entry_space(&self) -> usize {
+        self.credit_row_len() + self.team_config.is_some() as usize * size_of::<u32>()
+    }
+
+    // Team contests rank teams by the average or best ROI of their entries, other contests rank
+    // each entry on its own
+    pub fn rank_scores(
+        &self,
+        entry_rois: Vec<(usize, f64)>,
+        entry_teams: &[u32],
+    ) -> Vec<(usize, f64)> {
+        let Some(team_config) = &self.team_config else {
+            return entry_rois;
+        };
+
+        let mut team_rois: Vec<Vec<f64>> = vec![Vec::new(); self.num_teams as usize];
+        for (i, roi) in entry_rois {
+            team_rois[entry_teams[i] as usize].push(roi);
+        }
+
+        team_rois
+            .iter()
+            .enumerate()
+            .filter(|(_, rois)| !rois.is_empty())
+            .map(|(team_id, rois)| {
+                let score = match team_config.scoring {
+                    TeamScoring::Average => rois.iter().sum::<f64>() / rois.len() as f64,
+                    TeamScoring::Best => rois.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                };
+                (team_id, score)
+            })
+            .collect()
+    }
+
+    // Winner ids are team ids in team contests and entry ids otherwise
+    pub fn winner_position(&self, contest_entry: &TokenDraftContestEntry) -> Option<usize> {
+        let winner_id = match self.team_config {
+            Some(_) => contest_entry.team_id?,
+            None => contest_entry.id,
+        };
+        self.winner_ids.iter().position(|&id| id == winner_id)
+    }
+
+    // Reward owed to a winning entry, team prizes are split among the members who entered by the
+    // weights set when the team formed
+    pub fn entry_reward_amount(
+        &self,
+        contest_key: Pubkey,
+        contest_entry: &TokenDraftContestEntry,
+        fee_percent: u8,
+        team: Option<&Team>,
+    ) -> Result<u64> {
+        let pos = self
+            .winner_position(contest_entry)
+            .ok_or(ContestError::NotWinner)?;
+        let alloc = self.winner_reward_allocation[pos];
+
+        // Calculate the user reward amount based on the credit allocation
+        let total_pool_amount = self.pool_amount();
+        let fee_amount = calc_fee_amount(total_pool_amount, fee_percent);
+        let total_reward_amount = total_pool_amount - fee_amount;
+        let user_reward_amount = calc_reward_share(total_reward_amount, alloc);
+
+        let Some(team_id) = contest_entry.team_id else {
+            return Ok(user_reward_amount);
+        };
+        let team = team.ok_or(ContestError::NotTeamMember)?;
+        require!(
+            team.contest_key == contest_key && team.id == team_id,
+            ContestError::NotTeamMember
+        );
+        let weight = team
+            .member_weight(&contest_entry.user)
+            .ok_or(ContestError::NotTeamMember)?;
+
+        Ok(calc_weighted_share(
+            user_reward_amount,
+            weight,
+            team.entered_weight,
+        ))
+    }
+
+    pub fn captain_multiplier(&self, captain: &[i8]) -> Option<(usize, f64)> {
+        let captain_multiplier_bps = self.captain_multiplier_bps?;
+        let &captain = captain.first()?;
+        let multiplier = captain_multiplier_bps as f64 / CAPTAIN_MULTIPLIER_BPS_BASE as f64;
+        Some((captain as usize, multiplier))
+    }
+
+    // For allowlist contests the proof holds the sibling hashes from the user's leaf up to the
+    // root, invite code contests need the invite keypair to have signed the entry.
+    pub fn can_enter(
+        &self,
+        user: &Pubkey,
+        access_proof: &[[u8; 32]],
+        invite_signer: Option<Pubkey>,
+    ) -> bool {
+        match self.access {
+            ContestAccess::Public => true,
+            ContestAccess::Allowlist { merkle_root } => {
+                let mut node = hashv(&[user.as_ref()]).to_bytes();
+                for sibling in access_proof {
+                    node = if node <= *sibling {
+                        hashv(&[&node, sibling]).to_bytes()
+                    } else {
+                        hashv(&[sibling, &node]).to_bytes()
+                    };
+                }
+                node == merkle_root
+            }
+            ContestAccess::InviteCode { invite_key } => invite_signer == Some(invite_key),
+        }
+    }
 }
@@ -6,5 +6,8 @@ pub struct TokenDraftContestCredits {
     pub contest_key: Pubkey,
 
     #[max_len(0)]
-    pub credit_allocations: Vec<u8>,
+    pub credit_allocations: Vec<i8>,
+
+    #[max_len(0)]
+    pub entry_teams: Vec<u32>,
 }
\ No newline at end of file
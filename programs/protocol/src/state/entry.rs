@@ -1,5 +1,5 @@
-use anchor_lang::prelude::*;
 use crate::state::contest::MAX_TOKEN_PER_DRAFT;
+use anchor_lang::prelude::*;
 
 pub const TOTAL_CREDIT_PER_CONTEST: u8 = 100;
 
@@ -13,7 +13,13 @@ pub struct TokenDraftContestEntry {
     pub contest_key: Pubkey,
 
     #[max_len(MAX_TOKEN_PER_DRAFT)]
-    pub credit_allocation: Vec<u8>,
+    pub credit_allocation: Vec<i8>,
 
     pub has_claimed: bool,
-}
\ No newline at end of file
+
+    pub captain: Option<u8>,
+
+    pub has_season_points: bool,
+
+    pub team_id: Option<u32>,
+}
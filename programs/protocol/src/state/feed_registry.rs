@@ -0,0 +1,55 @@
+use crate::errors::ContestError;
+use anchor_lang::prelude::*;
+
+pub const MAX_FEED_SYMBOL_LEN: usize = 16;
+
+#[account]
+#[derive(InitSpace)]
+pub struct FeedRegistry {
+    pub curator: Pubkey,
+
+    #[max_len(0)]
+    pub feeds: Vec<RegisteredFeed>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct RegisteredFeed {
+    pub feed_id: Pubkey,
+
+    #[max_len(MAX_FEED_SYMBOL_LEN)]
+    pub symbol: String,
+
+    // Minimum price to confidence interval ratio a price update must have to be used, 0 disables
+    // the check
+    pub min_confidence_ratio: u32,
+
+    pub is_enabled: bool,
+}
+
+impl FeedRegistry {
+    pub fn get_feed(&self, feed_id: &Pubkey) -> Option<&RegisteredFeed> {
+        self.feeds.iter().find(|feed| feed.feed_id == *feed_id)
+    }
+
+    pub fn get_feed_ids(&self, feed_indices: &[u8]) -> Result<Vec<Pubkey>> {
+        feed_indices
+            .iter()
+            .map(|&i| match self.feeds.get(i as usize) {
+                Some(feed) => Ok(feed.feed_id),
+                None => err!(ContestError::FeedNotAllowed),
+            })
+            .collect()
+    }
+
+    // Only enabled feeds can be drafted, their confidence requirements are snapshotted into the
+    // contest so that later registry updates do not change how it settles
+    pub fn get_min_confidence_ratios(&self, token_feed_ids: &[Pubkey]) -> Result<Vec<u32>> {
+        token_feed_ids
+            .iter()
+            .map(|feed_id| match self.get_feed(feed_id) {
+                Some(feed) if feed.is_enabled => Ok(feed.min_confidence_ratio),
+                _ => err!(ContestError::FeedNotAllowed),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,105 @@
+use crate::errors::ContestError;
+use crate::state::entry::TOTAL_CREDIT_PER_CONTEST;
+use crate::utils::price::PRICE_POSTING_WINDOW;
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+#[account]
+#[derive(InitSpace)]
+pub struct HeadToHeadContest {
+    pub id: u64,
+
+    pub challenger: Pubkey,
+
+    pub opponent: Option<Pubkey>,
+
+    pub start_time: u64,
+
+    pub end_time: u64,
+
+    pub stake: u64,
+
+    #[max_len(0)]
+    pub token_feed_ids: Vec<Pubkey>,
+
+    #[max_len(0)]
+    pub token_min_confidence_ratios: Vec<u32>,
+
+    #[max_len(0)]
+    pub token_start_prices: Vec<f64>,
+
+    #[max_len(0)]
+    pub token_rois: Vec<f64>,
+
+    #[max_len(0)]
+    pub challenger_allocation: Vec<i8>,
+
+    #[max_len(0)]
+    pub opponent_allocation: Vec<i8>,
+
+    pub is_resolved: bool,
+
+    // None after resolution means the duel ended in a tie and both players get half of the pot
+    pub winner: Option<Pubkey>,
+
+    pub has_challenger_claimed: bool,
+
+    pub has_opponent_claimed: bool,
+}
+
+impl HeadToHeadContest {
+    pub fn space(num_tokens: usize) -> usize {
+        let token_space =
+            size_of::<Pubkey>() + size_of::<u32>() + 2 * size_of::<f64>() + 2 * size_of::<i8>();
+        HeadToHeadContest::INIT_SPACE + num_tokens * token_space
+    }
+
+    pub fn is_entry_active(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        current_time < self.start_time
+    }
+
+    pub fn has_ended(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        current_time > self.end_time
+    }
+
+    // Start prices can only be posted shortly after the start and never once the duel has ended
+    pub fn start_price_deadline(&self) -> u64 {
+        (self.start_time + PRICE_POSTING_WINDOW).min(self.end_time)
+    }
+
+    // An accepted duel whose start prices were never posted can only be refunded
+    pub fn has_missed_price_posting(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        self.token_start_prices.is_empty() && current_time > self.start_price_deadline()
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        self.opponent.is_some()
+    }
+
+    pub fn pool_amount(&self) -> u64 {
+        if self.is_accepted() {
+            2 * self.stake
+        } else {
+            self.stake
+        }
+    }
+
+    pub fn validate_credit_allocation(&self, credit_allocation: &[i8]) -> Result<()> {
+        let sum_credits: u32 = credit_allocation
+            .iter()
+            .map(|&v| v.unsigned_abs() as u32)
+            .sum();
+        let is_distribution_good = self.token_feed_ids.len() == credit_allocation.len()
+            && credit_allocation.iter().all(|&v| v >= 0)
+            && sum_credits == TOTAL_CREDIT_PER_CONTEST as u32;
+        require!(
+            is_distribution_good,
+            ContestError::InvalidDraftTokenDistribution
+        );
+
+        Ok(())
+    }
+}
@@ -6,4 +6,11 @@ pub struct ContestMetadata {
     pub token_draft_contest_count: u64,
     pub token_draft_contest_fee_percent: u8,
     pub token_draft_contest_fee_amount: u64,
+    pub head_to_head_contest_count: u64,
+    pub over_under_contest_count: u64,
+    pub parimutuel_contest_count: u64,
+    pub range_contest_count: u64,
+    pub survivor_contest_count: u64,
+    pub season_count: u64,
+    pub snake_draft_contest_count: u64,
 }
@@ -2,4 +2,13 @@ pub mod config;
 pub mod metadata;
 pub mod contest;
 pub mod entry;
-pub mod credit;
\ No newline at end of file
+pub mod credit;
+pub mod feed_registry;
+pub mod head_to_head;
+pub mod over_under;
+pub mod parimutuel;
+pub mod range;
+pub mod season;
+pub mod snake_draft;
+pub mod survivor;
+pub mod team;
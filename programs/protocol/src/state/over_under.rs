@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct OverUnderContest {
+    pub id: u64,
+
+    pub creator: Pubkey,
+
+    pub start_time: u64,
+
+    pub end_time: u64,
+
+    pub feed_id: Pubkey,
+
+    pub min_confidence_ratio: u32,
+
+    pub strike_price: f64,
+
+    pub over_amount: u64,
+
+    pub under_amount: u64,
+
+    pub end_price: f64,
+
+    pub is_resolved: bool,
+
+    // None after resolution means the market is void, either the price settled on the strike or
+    // nobody backed the winning side, and every stake is refunded without a fee
+    pub outcome: Option<OverUnderSide>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct OverUnderEntry {
+    pub user: Pubkey,
+
+    pub contest_key: Pubkey,
+
+    pub side: OverUnderSide,
+
+    pub amount: u64,
+
+    pub has_claimed: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum OverUnderSide {
+    Over,
+    Under,
+}
+
+impl OverUnderContest {
+    pub fn is_entry_active(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        current_time < self.start_time
+    }
+
+    pub fn has_ended(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        current_time > self.end_time
+    }
+
+    pub fn pool_amount(&self) -> u64 {
+        self.over_amount + self.under_amount
+    }
+
+    pub fn side_amount(&self, side: OverUnderSide) -> u64 {
+        match side {
+            OverUnderSide::Over => self.over_amount,
+            OverUnderSide::Under => self.under_amount,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::utils::price::PRICE_POSTING_WINDOW;
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ParimutuelContest {
+    pub id: u64,
+
+    pub creator: Pubkey,
+
+    pub start_time: u64,
+
+    pub end_time: u64,
+
+    #[max_len(0)]
+    pub token_feed_ids: Vec<Pubkey>,
+
+    #[max_len(0)]
+    pub token_min_confidence_ratios: Vec<u32>,
+
+    #[max_len(0)]
+    pub token_start_prices: Vec<f64>,
+
+    #[max_len(0)]
+    pub token_rois: Vec<f64>,
+
+    #[max_len(0)]
+    pub token_stake_amounts: Vec<u64>,
+
+    pub is_resolved: bool,
+
+    // None after resolution means the market is void, either several tokens shared the best ROI
+    // or nobody backed the best token, and every stake is refunded without a fee
+    pub winning_token: Option<u8>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ParimutuelEntry {
+    pub user: Pubkey,
+
+    pub contest_key: Pubkey,
+
+    pub token_index: u8,
+
+    pub amount: u64,
+
+    pub has_claimed: bool,
+}
+
+impl ParimutuelContest {
+    pub fn space(num_tokens: usize) -> usize {
+        let token_space =
+            size_of::<Pubkey>() + size_of::<u32>() + 2 * size_of::<f64>() + size_of::<u64>();
+        ParimutuelContest::INIT_SPACE + num_tokens * token_space
+    }
+
+    pub fn is_entry_active(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        current_time < self.start_time
+    }
+
+    pub fn has_ended(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        current_time > self.end_time
+    }
+
+    // Start prices can only be posted shortly after the start and never once the contest has ended
+    pub fn start_price_deadline(&self) -> u64 {
+        (self.start_time + PRICE_POSTING_WINDOW).min(self.end_time)
+    }
+
+    pub fn pool_amount(&self) -> u64 {
+        self.token_stake_amounts.iter().sum()
+    }
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+#[account]
+#[derive(InitSpace)]
+pub struct RangeContest {
+    pub id: u64,
+
+    pub creator: Pubkey,
+
+    pub start_time: u64,
+
+    pub end_time: u64,
+
+    pub entry_fee: u64,
+
+    pub max_entries: u32,
+
+    pub num_entries: u32,
+
+    pub feed_id: Pubkey,
+
+    pub min_confidence_ratio: u32,
+
+    pub target: RangeTarget,
+
+    pub start_price: Option<f64>,
+
+    pub end_price: f64,
+
+    pub is_resolved: bool,
+
+    #[max_len(0)]
+    pub winner_ids: Vec<u32>,
+
+    #[max_len(0)]
+    pub winner_reward_allocation: Vec<u8>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum RangeTarget {
+    Price,
+    PercentChange,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RangeContestEntry {
+    pub user: Pubkey,
+
+    pub id: u32,
+
+    pub contest_key: Pubkey,
+
+    pub prediction: f64,
+
+    pub has_claimed: bool,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RangeContestPredictions {
+    pub contest_key: Pubkey,
+
+    #[max_len(0)]
+    pub predictions: Vec<f64>,
+}
+
+impl RangeContest {
+    pub fn space(num_winners: usize) -> usize {
+        let winner_space = size_of::<u32>() + size_of::<u8>();
+        RangeContest::INIT_SPACE + num_winners * winner_space
+    }
+
+    pub fn is_entry_active(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        current_time < self.start_time
+    }
+
+    pub fn has_ended(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        current_time > self.end_time
+    }
+
+    pub fn pool_amount(&self) -> u64 {
+        self.entry_fee * self.num_entries as u64
+    }
+
+    // Predictions are scored against the end price, or against the percentage change from the
+    // start price
+    pub fn settled_value(&self, end_price: f64) -> Option<f64> {
+        match self.target {
+            RangeTarget::Price => Some(end_price),
+            RangeTarget::PercentChange => self
+                .start_price
+                .map(|start_price| (end_price - start_price) / start_price * 100.0),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Season {
+    pub id: u64,
+
+    pub start_time: u64,
+
+    pub end_time: u64,
+
+    // Points awarded for each finishing position in a linked contest
+    #[max_len(0)]
+    pub points_table: Vec<u32>,
+
+    // Percentage of the fee from each linked contest that funds the prize pool
+    pub fee_share_percent: u8,
+
+    pub prize_pool_amount: u64,
+
+    #[max_len(0)]
+    pub prize_allocation: Vec<u8>,
+
+    // Top standings ordered by points, kept up to date as points are awarded
+    #[max_len(0)]
+    pub leaders: Vec<SeasonLeader>,
+
+    pub num_contests: u32,
+
+    // The season can only be finalized once every linked contest is settled
+    pub num_settled_contests: u32,
+
+    pub is_finalized: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct SeasonLeader {
+    pub user: Pubkey,
+
+    pub points: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct SeasonStanding {
+    pub user: Pubkey,
+
+    pub season_key: Pubkey,
+
+    pub points: u64,
+
+    pub num_contests: u32,
+
+    pub has_claimed: bool,
+}
+
+impl Season {
+    pub fn space(num_positions: usize, num_winners: usize) -> usize {
+        let winner_space = size_of::<u8>() + SeasonLeader::INIT_SPACE;
+        Season::INIT_SPACE + num_positions * size_of::<u32>() + num_winners * winner_space
+    }
+
+    pub fn has_ended(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        current_time > self.end_time
+    }
+
+    pub fn update_leaders(&mut self, user: Pubkey, points: u64) {
+        match self.leaders.iter_mut().find(|leader| leader.user == user) {
+            Some(leader) => leader.points = points,
+            None => self.leaders.push(SeasonLeader { user, points }),
+        }
+
+        // Earlier leaders keep their place on equal points
+        self.leaders.sort_by_key(|leader| std::cmp::Reverse(leader.points));
+        self.leaders.truncate(self.prize_allocation.len());
+    }
+}
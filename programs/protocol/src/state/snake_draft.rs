@@ -0,0 +1,176 @@
+use crate::utils::price::PRICE_POSTING_WINDOW;
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+pub const MIN_SNAKE_DRAFT_PLAYERS: u8 = 2;
+pub const MAX_SNAKE_DRAFT_PLAYERS: u8 = 8;
+pub const MAX_SNAKE_DRAFT_POOL: usize = 32;
+
+#[account]
+#[derive(InitSpace)]
+pub struct SnakeDraftContest {
+    pub id: u64,
+
+    pub creator: Pubkey,
+
+    pub entry_fee: u64,
+
+    pub max_players: u8,
+
+    pub picks_per_player: u8,
+
+    pub pick_duration: u64,
+
+    pub contest_duration: u64,
+
+    // Players can only join until the lobby fills or this deadline passes
+    pub fill_deadline: u64,
+
+    #[max_len(0)]
+    pub players: Vec<Pubkey>,
+
+    #[max_len(0)]
+    pub has_claimed: Vec<bool>,
+
+    #[max_len(0)]
+    pub token_feed_ids: Vec<Pubkey>,
+
+    #[max_len(0)]
+    pub token_min_confidence_ratios: Vec<u32>,
+
+    // Index of the player owning each token in the pool, a token can only be owned once
+    #[max_len(0)]
+    pub token_owners: Vec<Option<u8>>,
+
+    // Token indices in the order they were picked
+    #[max_len(0)]
+    pub picks: Vec<u8>,
+
+    pub pick_deadline: u64,
+
+    // Contest period starts once the last pick is made
+    pub start_time: u64,
+
+    pub end_time: u64,
+
+    // Start prices of the picked tokens in pick order
+    #[max_len(0)]
+    pub token_start_prices: Vec<f64>,
+
+    #[max_len(0)]
+    pub winner_ids: Vec<u32>,
+
+    #[max_len(0)]
+    pub winner_reward_allocation: Vec<u8>,
+
+    pub is_resolved: bool,
+}
+
+impl SnakeDraftContest {
+    pub fn space(
+        max_players: usize,
+        num_tokens: usize,
+        num_picks: usize,
+        num_winners: usize,
+    ) -> usize {
+        let player_space = size_of::<Pubkey>() + size_of::<bool>();
+        let token_space = size_of::<Pubkey>() + size_of::<u32>() + 2 * size_of::<u8>();
+        let pick_space = size_of::<u8>() + size_of::<f64>();
+        let winner_space = size_of::<u32>() + size_of::<u8>();
+        SnakeDraftContest::INIT_SPACE
+            + max_players * player_space
+            + num_tokens * token_space
+            + num_picks * pick_space
+            + num_winners * winner_space
+    }
+
+    pub fn total_picks(&self) -> usize {
+        self.max_players as usize * self.picks_per_player as usize
+    }
+
+    pub fn is_lobby_full(&self) -> bool {
+        self.players.len() == self.max_players as usize
+    }
+
+    pub fn is_draft_active(&self) -> bool {
+        self.is_lobby_full() && self.picks.len() < self.total_picks()
+    }
+
+    pub fn is_draft_complete(&self) -> bool {
+        self.picks.len() == self.total_picks()
+    }
+
+    pub fn has_ended(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        self.is_draft_complete() && current_time > self.end_time
+    }
+
+    // Start prices can only be posted shortly after the draft completes
+    pub fn start_price_deadline(&self) -> u64 {
+        (self.start_time + PRICE_POSTING_WINDOW).min(self.end_time)
+    }
+
+    // A lobby that never filled or a draft whose start prices were never posted can only be
+    // refunded
+    pub fn is_refundable(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        if !self.is_lobby_full() {
+            return current_time > self.fill_deadline;
+        }
+        self.is_draft_complete()
+            && self.token_start_prices.is_empty()
+            && current_time > self.start_price_deadline()
+    }
+
+    pub fn pool_amount(&self) -> u64 {
+        self.entry_fee * self.players.len() as u64
+    }
+
+    // Turn order snakes, reversing direction every round
+    pub fn current_picker(&self) -> usize {
+        let num_players = self.players.len();
+        let round = self.picks.len() / num_players;
+        let pos = self.picks.len() % num_players;
+        if round % 2 == 1 {
+            num_players - 1 - pos
+        } else {
+            pos
+        }
+    }
+
+    pub fn make_pick(&mut self, token_index: usize) {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+
+        self.token_owners[token_index] = Some(self.current_picker() as u8);
+        self.picks.push(token_index as u8);
+
+        if self.is_draft_complete() {
+            self.start_time = current_time;
+            self.end_time = current_time + self.contest_duration;
+        } else {
+            self.pick_deadline = current_time + self.pick_duration;
+        }
+    }
+
+    pub fn picked_feed_ids(&self) -> Vec<Pubkey> {
+        self.picks
+            .iter()
+            .map(|&i| self.token_feed_ids[i as usize])
+            .collect()
+    }
+
+    pub fn picked_min_confidence_ratios(&self) -> Vec<u32> {
+        self.picks
+            .iter()
+            .map(|&i| self.token_min_confidence_ratios[i as usize])
+            .collect()
+    }
+
+    // Each player holds their picked tokens with equal weight
+    pub fn player_allocation(&self, player: usize) -> Vec<i8> {
+        self.picks
+            .iter()
+            .map(|&i| (self.token_owners[i as usize] == Some(player as u8)) as i8)
+            .collect()
+    }
+}
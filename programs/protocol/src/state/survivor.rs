@@ -0,0 +1,157 @@
+use crate::errors::ContestError;
+use crate::state::entry::TOTAL_CREDIT_PER_CONTEST;
+use crate::utils::price::PRICE_POSTING_WINDOW;
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+#[account]
+#[derive(InitSpace)]
+pub struct SurvivorContest {
+    pub id: u64,
+
+    pub creator: Pubkey,
+
+    pub entry_fee: u64,
+
+    pub max_entries: u32,
+
+    pub num_entries: u32,
+
+    pub num_rounds: u8,
+
+    pub current_round: u8,
+
+    pub elimination_percent: u8,
+
+    pub draft_duration: u64,
+
+    pub round_duration: u64,
+
+    pub round_start_time: u64,
+
+    pub round_end_time: u64,
+
+    #[max_len(0)]
+    pub token_feed_ids: Vec<Pubkey>,
+
+    #[max_len(0)]
+    pub token_min_confidence_ratios: Vec<u32>,
+
+    #[max_len(0)]
+    pub token_start_prices: Vec<f64>,
+
+    pub num_survivors: u32,
+
+    pub is_resolved: bool,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct SurvivorContestEntry {
+    pub user: Pubkey,
+
+    pub id: u32,
+
+    pub contest_key: Pubkey,
+
+    pub has_claimed: bool,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct SurvivorContestCredits {
+    pub contest_key: Pubkey,
+
+    #[max_len(0)]
+    pub is_alive: Vec<bool>,
+
+    // One row per entry holding the allocation it entered with
+    #[max_len(0)]
+    pub credit_allocations: Vec<i8>,
+
+    // Every re-draft appends a row tagged with its entry and the round it applies from, so the
+    // allocation held in each round stays on record
+    #[max_len(0)]
+    pub redraft_entry_ids: Vec<u32>,
+
+    #[max_len(0)]
+    pub redraft_rounds: Vec<u8>,
+
+    #[max_len(0)]
+    pub redraft_allocations: Vec<i8>,
+}
+
+impl SurvivorContest {
+    pub fn space(num_tokens: usize) -> usize {
+        let token_space = size_of::<Pubkey>() + size_of::<u32>() + size_of::<f64>();
+        SurvivorContest::INIT_SPACE + num_tokens * token_space
+    }
+
+    pub fn is_draft_active(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        current_time < self.round_start_time
+    }
+
+    pub fn has_round_ended(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        current_time > self.round_end_time
+    }
+
+    // Round start prices can only be posted shortly after the round starts
+    pub fn start_price_deadline(&self) -> u64 {
+        (self.round_start_time + PRICE_POSTING_WINDOW).min(self.round_end_time)
+    }
+
+    // A round whose start prices were never posted can never be resolved, every entry fee is
+    // refunded instead
+    pub fn has_missed_price_posting(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp as u64;
+        !self.is_resolved
+            && self.token_start_prices.is_empty()
+            && current_time > self.start_price_deadline()
+    }
+
+    pub fn pool_amount(&self) -> u64 {
+        self.entry_fee * self.num_entries as u64
+    }
+
+    pub fn validate_credit_allocation(&self, credit_allocation: &[i8]) -> Result<()> {
+        let sum_credits: u32 = credit_allocation
+            .iter()
+            .map(|&v| v.unsigned_abs() as u32)
+            .sum();
+        let is_distribution_good = self.token_feed_ids.len() == credit_allocation.len()
+            && credit_allocation.iter().all(|&v| v >= 0)
+            && sum_credits == TOTAL_CREDIT_PER_CONTEST as u32;
+        require!(
+            is_distribution_good,
+            ContestError::InvalidDraftTokenDistribution
+        );
+
+        Ok(())
+    }
+
+    // Bottom fraction of the survivors knocked out this round, at least one entry always survives
+    pub fn num_eliminated(&self) -> usize {
+        let num_eliminated = self.num_survivors as usize * self.elimination_percent as usize / 100;
+        num_eliminated.min(self.num_survivors.saturating_sub(1) as usize)
+    }
+}
+
+impl SurvivorContestCredits {
+    pub fn redraft_space(num_tokens: usize) -> usize {
+        size_of::<u32>() + size_of::<u8>() + num_tokens
+    }
+
+    // Allocation an entry holds in a round, its latest re-draft up to that round or else the
+    // allocation it entered with
+    pub fn round_allocation(&self, entry_id: usize, round: u8, num_tokens: usize) -> &[i8] {
+        let redraft = (0..self.redraft_entry_ids.len()).rev().find(|&i| {
+            self.redraft_entry_ids[i] == entry_id as u32 && self.redraft_rounds[i] <= round
+        });
+        match redraft {
+            Some(i) => &self.redraft_allocations[i * num_tokens..(i + 1) * num_tokens],
+            None => &self.credit_allocations[entry_id * num_tokens..(entry_id + 1) * num_tokens],
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Team {
+    pub id: u32,
+
+    pub contest_key: Pubkey,
+
+    pub creator: Pubkey,
+
+    #[max_len(0)]
+    pub members: Vec<Pubkey>,
+
+    // Percentage of the team prize paid to each member
+    #[max_len(0)]
+    pub member_weights: Vec<u8>,
+
+    // Sum of the weights of the members who entered, the team prize is split over them alone
+    pub entered_weight: u8,
+}
+
+impl Team {
+    pub fn space(num_members: usize) -> usize {
+        let member_space = size_of::<Pubkey>() + size_of::<u8>();
+        Team::INIT_SPACE + num_members * member_space
+    }
+
+    pub fn member_weight(&self, user: &Pubkey) -> Option<u8> {
+        let i = self.members.iter().position(|member| member == user)?;
+        Some(self.member_weights[i])
+    }
+}
@@ -0,0 +1,3 @@
+pub mod price;
+pub mod roi;
+pub mod settlement;
@@ -0,0 +1,85 @@
+use crate::errors::ContestError;
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+// Snapshot prices must be posted within this long after the snapshot time
+pub const PRICE_POSTING_WINDOW: u64 = 15 * 60;
+// And published at most this long after it, so a poster cannot pick a favourable print
+pub const MAX_PRICE_PUBLISH_DELAY: u64 = 5;
+
+// Price update accounts are passed through the remaining accounts, one per drafted token and in
+// the same order as the contest's token feed ids
+pub fn load_price_feeds(
+    feed_accounts: &[AccountInfo<'_>],
+    num_feeds: usize,
+) -> Result<Vec<PriceUpdateV2>> {
+    require!(feed_accounts.len() >= num_feeds, ContestError::InvalidFeeds);
+
+    feed_accounts[..num_feeds]
+        .iter()
+        .map(|feed_account| {
+            require_keys_eq!(
+                *feed_account.owner,
+                PriceUpdateV2::owner(),
+                ContestError::InvalidFeeds
+            );
+            let data = feed_account.try_borrow_data()?;
+            PriceUpdateV2::try_deserialize(&mut &data[..])
+        })
+        .collect()
+}
+
+// Price of a token along with the publish time of the update it was read from
+pub fn get_token_price_update(
+    feed_id: &Pubkey,
+    feed: &PriceUpdateV2,
+    min_confidence_ratio: u32,
+) -> Result<(f64, i64)> {
+    let price_data = feed.get_price_unchecked(&feed_id.to_bytes())?;
+    require!(
+        price_data.conf as u128 * min_confidence_ratio as u128 <= price_data.price.max(0) as u128,
+        ContestError::PriceConfidenceTooLow
+    );
+    let exp = (-price_data.exponent) as u32;
+    let price = (price_data.price as u64 as f64) / (10u64.pow(exp) as f64);
+    Ok((price, price_data.publish_time))
+}
+
+// Current prices of every token along with the publish time of each price
+pub fn get_token_price_updates(
+    feed_accounts: &[AccountInfo<'_>],
+    token_feed_ids: &[Pubkey],
+    min_confidence_ratios: &[u32],
+) -> Result<(Vec<f64>, Vec<i64>)> {
+    let feeds = load_price_feeds(feed_accounts, token_feed_ids.len())?;
+
+    let mut token_prices: Vec<f64> = Vec::with_capacity(feeds.len());
+    let mut publish_times: Vec<i64> = Vec::with_capacity(feeds.len());
+    for (i, feed_id) in token_feed_ids.iter().enumerate() {
+        let min_confidence_ratio = min_confidence_ratios.get(i).copied().unwrap_or(0);
+        let (price, publish_time) =
+            get_token_price_update(feed_id, &feeds[i], min_confidence_ratio)?;
+        token_prices.push(price);
+        publish_times.push(publish_time);
+    }
+
+    Ok((token_prices, publish_times))
+}
+
+// How long after the snapshot time the latest price was published, failing if any price predates
+// the snapshot or was published too long after it
+pub fn calc_publish_delay(publish_times: &[i64], snapshot_time: u64) -> Result<u64> {
+    let mut publish_delay = 0;
+    for &publish_time in publish_times {
+        require!(
+            publish_time >= snapshot_time as i64,
+            ContestError::InvalidPublishTime
+        );
+        publish_delay = publish_delay.max(publish_time as u64 - snapshot_time);
+    }
+    require!(
+        publish_delay <= MAX_PRICE_PUBLISH_DELAY,
+        ContestError::InvalidPublishTime
+    );
+    Ok(publish_delay)
+}
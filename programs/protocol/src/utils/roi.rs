@@ -0,0 +1,18 @@
+use crate::utils::price::{calc_publish_delay, get_token_price_updates};
+use anchor_lang::prelude::*;
+use protocol_scoring::roi::calc_token_rois;
+
+pub fn get_token_rois(
+    feed_accounts: &[AccountInfo<'_>],
+    token_feed_ids: &[Pubkey],
+    token_start_prices: &[f64],
+    min_confidence_ratios: &[u32],
+    end_time: u64,
+) -> Result<Vec<f64>> {
+    // End prices must be published right at the end time so the resolver cannot pick them
+    let (token_prices, publish_times) =
+        get_token_price_updates(feed_accounts, token_feed_ids, min_confidence_ratios)?;
+    calc_publish_delay(&publish_times, end_time)?;
+
+    Ok(calc_token_rois(token_start_prices, &token_prices))
+}
@@ -0,0 +1,142 @@
+use crate::errors::ContestError;
+use crate::events::{ContestResolved, PriceSnapshot, PricesPosted};
+use crate::state::contest::{TokenDraftContest, KEEPER_BOUNTY_BPS};
+use crate::state::credit::TokenDraftContestCredits;
+use crate::state::metadata::ContestMetadata;
+use crate::utils::price::{calc_publish_delay, get_token_price_updates};
+use anchor_lang::prelude::*;
+use protocol_scoring::payout::{calc_bps_share, calc_fee_amount};
+use protocol_scoring::rank::find_top_n;
+use protocol_scoring::roi::{calc_avg_roi, calc_chained_roi, calc_token_rois};
+
+// Settles a token draft contest from its end prices, both the base layer and the ephemeral rollup
+// resolve instructions go through here so they can never disagree on the outcome. The keeper is
+// whoever resolves the contest and earns the resolve bounty.
+pub fn settle_token_draft_contest(
+    keeper: Pubkey,
+    contest: &mut Account<'_, TokenDraftContest>,
+    contest_credits: &TokenDraftContestCredits,
+    contest_metadata: &mut ContestMetadata,
+    feed_accounts: &[AccountInfo<'_>],
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    require!(
+        contest.token_start_prices.len() > 0,
+        ContestError::ContestPriceNotSet
+    );
+
+    // Check that end time has passed
+    require!(
+        current_time > contest.end_time,
+        ContestError::ContestNotEnded
+    );
+
+    require!(!contest.is_resolved, ContestError::AlreadyResolved);
+
+    // Check that every rebalance checkpoint has been snapshotted
+    require!(
+        contest.num_checkpoints_posted() == contest.rebalance_times.len(),
+        ContestError::ContestPriceNotSet
+    );
+
+    let (token_end_prices, publish_times) = get_token_price_updates(
+        feed_accounts,
+        &contest.token_feed_ids,
+        &contest.token_min_confidence_ratios,
+    )?;
+
+    // End prices are bound to the end time like start and checkpoint prices, so a resolver cannot
+    // pick a later price
+    calc_publish_delay(&publish_times, contest.end_time)?;
+
+    let segment_token_rois = contest.segment_token_rois(&token_end_prices);
+    contest.token_rois = calc_token_rois(&contest.token_start_prices, &token_end_prices);
+
+    // Calculate the average ROI for each user, chaining the returns of every segment
+    let num_entries = contest.num_entries as usize;
+    let num_tokens = contest.token_feed_ids.len();
+    let num_segments = contest.num_segments();
+    let row_len = contest.credit_row_len();
+    let credit_allocations = &contest_credits.credit_allocations;
+    let mut user_avg_rois: Vec<(usize, f64)> = Vec::with_capacity(num_entries);
+    for i in 0..num_entries {
+        let row = &credit_allocations[(i * row_len)..(i * row_len + row_len)];
+        let (allocs, captain) = row.split_at(num_tokens * num_segments);
+        let captain = contest.captain_multiplier(captain);
+        let segment_rois: Vec<f64> = allocs
+            .chunks(num_tokens)
+            .zip(segment_token_rois.iter())
+            .map(|(alloc, token_rois)| calc_avg_roi(alloc, token_rois, captain))
+            .collect();
+        user_avg_rois.push((i, calc_chained_roi(&segment_rois)))
+    }
+
+    let scores = contest.rank_scores(user_avg_rois, &contest_credits.entry_teams);
+
+    // Find the top N users
+    let num_top_users = contest.winner_reward_allocation.len();
+    let winners = find_top_n(&scores, num_top_users);
+
+    // Store the top N users
+    contest.winner_ids = winners.iter().map(|v| v.0 as u32).collect();
+    contest.is_resolved = true;
+    contest.claim_deadline = contest
+        .claim_window
+        .map(|claim_window| current_time + claim_window);
+
+    // Every winning entry holds a prize until it is paid, team prizes are paid to each member
+    let count_entries = |winner_ids: &[u32]| match contest.team_config {
+        Some(_) => contest_credits
+            .entry_teams
+            .iter()
+            .filter(|team_id| winner_ids.contains(team_id))
+            .count() as u32,
+        None => winner_ids.len() as u32,
+    };
+    let num_pending_claims = count_entries(&contest.winner_ids);
+
+    // Linked contests also wait on the season points of every entry within the points table
+    let num_scoring_winners = contest
+        .winner_ids
+        .len()
+        .min(contest.season_scoring_positions as usize);
+    let num_pending_season_points = count_entries(&contest.winner_ids[..num_scoring_winners]);
+
+    contest.num_pending_claims = num_pending_claims;
+    contest.num_pending_season_points = num_pending_season_points;
+
+    // Set aside the price posting and resolve bounties, the protocol keeps the rest of the fee
+    let fee_percent = contest_metadata.token_draft_contest_fee_percent;
+    contest.keeper_bounty_amount = calc_bps_share(
+        calc_fee_amount(contest.pool_amount(), fee_percent),
+        KEEPER_BOUNTY_BPS,
+    );
+    contest.resolver = Some(keeper);
+
+    // Hold back the season's cut before the fee becomes withdrawable, keeper bounties are not
+    // shared
+    let protocol_fee_amount = contest.protocol_fee_amount(fee_percent);
+    contest.season_prize_amount =
+        protocol_fee_amount * contest.season_fee_share_percent as u64 / 100;
+
+    // Accumulate the fee amount from this contest
+    let fee_amount = protocol_fee_amount - contest.season_prize_amount;
+    contest_metadata.token_draft_contest_fee_amount += fee_amount;
+
+    emit!(PricesPosted {
+        contest: contest.key(),
+        snapshot: PriceSnapshot::End,
+        token_prices: token_end_prices,
+        publish_times,
+    });
+    emit!(ContestResolved {
+        contest: contest.key(),
+        token_rois: contest.token_rois.clone(),
+        winner_ids: contest.winner_ids.clone(),
+        winner_rois: winners.iter().map(|v| v.1).collect(),
+        fee_amount,
+    });
+
+    Ok(())
+}
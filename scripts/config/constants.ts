@@ -16,6 +16,11 @@ export const [contestMetadataPda] = PublicKey.findProgramAddressSync(
   programId
 );
 
+export const [feedRegistryPda] = PublicKey.findProgramAddressSync(
+  [Buffer.from("feed_registry")],
+  programId
+);
+
 export const [programTokenAccountPda] = PublicKey.findProgramAddressSync(
   [Buffer.from("token_account"), mint.toBuffer()],
   programId
@@ -4,13 +4,14 @@ import {
   connection,
   contestMetadataPda,
   env,
+  feedRegistryPda,
   program,
   pythPriceFeedIds,
   pythSolanaReceiver,
   unitsPerUsdc,
   wallet,
 } from "./config";
-import { hexToBase58, logEnvInfo } from "./utils";
+import { getFeedAccountMetas, hexToBase58, logEnvInfo } from "./utils";
 
 const { PublicKey } = web3;
 
@@ -60,11 +61,7 @@ export const main = async () => {
     contestMetadata: contestMetadataPda,
     contest: contestPda,
     contestCredits: contestCreditsPda,
-    feed0: feedAccounts[0],
-    feed1: feedAccounts[1] || null,
-    feed2: feedAccounts[2] || null,
-    feed3: feedAccounts[3] || null,
-    feed4: feedAccounts[4] || null,
+    feedRegistry: feedRegistryPda,
   };
 
   const recentBlockhash = await connection.getLatestBlockhash();
@@ -75,9 +72,17 @@ export const main = async () => {
       entryFee,
       maxEntries,
       tokenFeedIds,
-      Buffer.from(winnerRewardAllocation)
+      Buffer.from(winnerRewardAllocation),
+      { public: {} },
+      null,
+      false,
+      null,
+      null,
+      [],
+      null
     )
     .accounts(accounts)
+    .remainingAccounts(getFeedAccountMetas(feedAccounts))
     .instruction();
   const txMessage = new web3.TransactionMessage({
     payerKey: signer.publicKey,
@@ -3,13 +3,17 @@ import {
   configPda,
   connection,
   contestMetadataPda,
+  feedRegistryPda,
   programTokenAccountPda,
   mint,
   program,
+  pythPriceFeedIds,
   wallet,
 } from "./config";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
-import { logEnvInfo } from "./utils";
+import { hexToBase58, logEnvInfo } from "./utils";
+
+const { PublicKey } = web3;
 
 const tokenDraftContestFeePercent = 10;
 
@@ -56,6 +60,46 @@ export const main = async () => {
     signature: sig,
   });
   console.log("Tx signature:", sig);
+
+  // Initialize the feed registry with the wallet as curator and register the known feeds
+  const registryIxs = [
+    await program.methods
+      .initFeedRegistry(signer.publicKey)
+      .accounts({
+        signer: signer.publicKey,
+        config: configPda,
+        feedRegistry: feedRegistryPda,
+      })
+      .instruction(),
+  ];
+  for (const [symbol, feedId] of Object.entries(pythPriceFeedIds)) {
+    const ix = await program.methods
+      .addRegistryFeed(
+        new PublicKey(hexToBase58(feedId)),
+        symbol.toUpperCase(),
+        0
+      )
+      .accounts({ signer: signer.publicKey, feedRegistry: feedRegistryPda })
+      .instruction();
+    registryIxs.push(ix);
+  }
+  const registryBlockhash = await connection.getLatestBlockhash();
+  const registryTxMessage = new web3.TransactionMessage({
+    payerKey: signer.publicKey,
+    instructions: registryIxs,
+    recentBlockhash: registryBlockhash.blockhash,
+  }).compileToV0Message();
+  const registryTx = new web3.VersionedTransaction(registryTxMessage);
+  registryTx.sign([signer]);
+  const registrySig = await connection.sendTransaction(registryTx, {
+    skipPreflight: false,
+  });
+  await connection.confirmTransaction({
+    blockhash: registryBlockhash.blockhash,
+    lastValidBlockHeight: registryBlockhash.lastValidBlockHeight,
+    signature: registrySig,
+  });
+  console.log("Tx signature:", registrySig);
 };
 
 main()
@@ -21,3 +21,10 @@ export const logEnvInfo = () => {
   console.log("Signer:", wallet.publicKey.toBase58());
   console.log("");
 };
+
+export const getFeedAccountMetas = (feedAccounts: web3.PublicKey[]) =>
+  feedAccounts.map((pubkey) => ({
+    pubkey,
+    isSigner: false,
+    isWritable: false,
+  }));
@@ -8,7 +8,12 @@ import {
 } from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Protocol } from "../target/types/protocol";
-import { hexToBase58, pythPriceFeedIds, UNITS_PER_USDC } from "./helpers";
+import {
+  getFeedAccountMetas,
+  hexToBase58,
+  pythPriceFeedIds,
+  UNITS_PER_USDC,
+} from "./helpers";
 import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
 import { fixtureBase } from "./fixtures";
 
@@ -52,6 +57,10 @@ describe.skip("create", () => {
       [Buffer.from("token_draft_contest_credits"), contestPda.toBuffer()],
       programId
     );
+    const [feedRegistryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("feed_registry")],
+      programId
+    );
 
     const currentTime = Math.floor(Date.now() / 1000);
     const startTime = new BN(currentTime + 60 * 60); // 1 hour from now
@@ -76,11 +85,7 @@ describe.skip("create", () => {
       contestMetadata: contestMetadataPda,
       contest: contestPda,
       contestCredits: contestCreditsPda,
-      feed0: feedAccounts[0],
-      feed1: feedAccounts[1] || null,
-      feed2: feedAccounts[2] || null,
-      feed3: feedAccounts[3] || null,
-      feed4: feedAccounts[4] || null,
+      feedRegistry: feedRegistryPda,
     };
 
     const sig = await pg.methods
@@ -90,9 +95,17 @@ describe.skip("create", () => {
         entryFee,
         maxEntries,
         tokenFeedIds,
-        Buffer.from(winnerRewardAllocation)
+        Buffer.from(winnerRewardAllocation),
+        { public: {} },
+        null,
+        false,
+        null,
+        null,
+        [],
+        null
       )
       .accounts(accounts)
+      .remainingAccounts(getFeedAccountMetas(feedAccounts))
       .signers([signer])
       .rpc();
     console.log("Tx signature:", sig);
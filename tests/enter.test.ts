@@ -94,9 +94,8 @@ describe.skip("enter", () => {
     };
 
     const creditAllocation = [35, 65];
-    const creditAllocationInput = Buffer.from(creditAllocation);
     const txSignature = await pg.methods
-      .enterTokenDraftContest(creditAllocationInput)
+      .enterTokenDraftContest(creditAllocation, [], null)
       .accounts(accounts)
       .signers([signer])
       .rpc();
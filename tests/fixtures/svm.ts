@@ -10,7 +10,12 @@ import {
 } from "@coral-xyz/anchor";
 import { LAMPORTS_PER_SOL } from "@solana/web3.js";
 import {
+  ContestAccess,
+  SalaryCap,
+  TeamConfig,
   getCreateContestTx,
+  hexToBase58,
+  pythPriceFeedIds,
   sendSvmTransaction,
   USDC_DECIMALS,
 } from "../helpers";
@@ -28,6 +33,7 @@ import { HermesClient } from "@pythnetwork/hermes-client";
 import {
   SEED_CONFIG,
   SEED_CONTEST_METADATA,
+  SEED_FEED_REGISTRY,
   SEED_PROGRAM_TOKEN_ACCOUNT,
 } from "../helpers/constants";
 
@@ -178,11 +184,45 @@ export const fixtureInitialization = async (args: { numSigners?: number }) => {
 
   sendSvmTransaction(svm, signer, tx);
 
+  // Register all the fixture price feeds
+  const [feedRegistryPda] = PublicKey.findProgramAddressSync(
+    [SEED_FEED_REGISTRY],
+    programId
+  );
+  const registryIxs = [
+    await pg.methods
+      .initFeedRegistry(signer.publicKey)
+      .accounts({
+        signer: signer.publicKey,
+        config: configPda,
+        feedRegistry: feedRegistryPda,
+      })
+      .instruction(),
+  ];
+  for (const [symbol, feedId] of Object.entries(pythPriceFeedIds)) {
+    const ix = await pg.methods
+      .addRegistryFeed(
+        new PublicKey(hexToBase58(feedId)),
+        symbol.toUpperCase(),
+        0
+      )
+      .accounts({ signer: signer.publicKey, feedRegistry: feedRegistryPda })
+      .instruction();
+    registryIxs.push(ix);
+  }
+  const registryMsg = new web3.TransactionMessage({
+    payerKey: signer.publicKey,
+    instructions: registryIxs,
+    recentBlockhash: svm.latestBlockhash(),
+  }).compileToV0Message();
+  sendSvmTransaction(svm, signer, new web3.VersionedTransaction(registryMsg));
+
   return {
     ...res,
     configPda,
     contestMetadataPda,
     programTokenAccountPda,
+    feedRegistryPda,
   };
 };
 
@@ -194,6 +234,13 @@ export const fixtureWithContest = async (args: {
     maxEntries: number;
     priceFeedIds: string[];
     rewardAllocation: number[];
+    access?: ContestAccess;
+    salaryCap?: SalaryCap;
+    allowShort?: boolean;
+    captainMultiplierBps?: number;
+    teamConfig?: TeamConfig;
+    rebalanceTimes?: number[];
+    claimWindow?: number;
   };
   numSigners?: number;
 }) => {
@@ -211,6 +258,13 @@ export const fixtureWithContest = async (args: {
     maxEntries: contestParams.maxEntries,
     priceFeedIds: contestParams.priceFeedIds,
     rewardAllocation: contestParams.rewardAllocation,
+    access: contestParams.access,
+    salaryCap: contestParams.salaryCap,
+    allowShort: contestParams.allowShort,
+    captainMultiplierBps: contestParams.captainMultiplierBps,
+    teamConfig: contestParams.teamConfig,
+    rebalanceTimes: contestParams.rebalanceTimes,
+    claimWindow: contestParams.claimWindow,
   };
 
   // Create a contest
@@ -8,6 +8,30 @@ export const SEED_TOKEN_DRAFT_CONTEST_CREDITS = Buffer.from(
 export const SEED_TOKEN_DRAFT_CONTEST_ENTRY = Buffer.from(
   "token_draft_contest_entry"
 );
+export const SEED_FEED_REGISTRY = Buffer.from("feed_registry");
+export const SEED_HEAD_TO_HEAD_CONTEST = Buffer.from("head_to_head_contest");
+export const SEED_OVER_UNDER_CONTEST = Buffer.from("over_under_contest");
+export const SEED_OVER_UNDER_ENTRY = Buffer.from("over_under_entry");
+export const SEED_PARIMUTUEL_CONTEST = Buffer.from("parimutuel_contest");
+export const SEED_PARIMUTUEL_ENTRY = Buffer.from("parimutuel_entry");
+export const SEED_RANGE_CONTEST = Buffer.from("range_contest");
+export const SEED_RANGE_CONTEST_ENTRY = Buffer.from("range_contest_entry");
+export const SEED_RANGE_CONTEST_PREDICTIONS = Buffer.from(
+  "range_contest_predictions"
+);
+export const SEED_SURVIVOR_CONTEST = Buffer.from("survivor_contest");
+export const SEED_SURVIVOR_CONTEST_ENTRY = Buffer.from(
+  "survivor_contest_entry"
+);
+export const SEED_SURVIVOR_CONTEST_CREDITS = Buffer.from(
+  "survivor_contest_credits"
+);
+export const SEED_SEASON = Buffer.from("season");
+export const SEED_SEASON_STANDING = Buffer.from("season_standing");
+export const SEED_TEAM = Buffer.from("team");
+export const SEED_SNAKE_DRAFT_CONTEST = Buffer.from("snake_draft_contest");
 
 export const ONE_HOUR = 60 * 60;
 export const ONE_DAY = 24 * 60 * 60;
+
+export const KEEPER_BOUNTY_BPS = 1_000;
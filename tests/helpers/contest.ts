@@ -4,11 +4,18 @@ import {
   PythSolanaReceiver,
 } from "@pythnetwork/pyth-solana-receiver";
 import { Protocol } from "../../target/types/protocol";
-import { hexToBase58, now } from "../helpers";
+import {
+  ContestAccess,
+  SalaryCap,
+  getFeedAccountMetas,
+  hexToBase58,
+  now,
+} from "../helpers";
 import { Account, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { HermesClient } from "@pythnetwork/hermes-client";
 import { LiteSVM } from "litesvm";
 import {
+  SEED_FEED_REGISTRY,
   SEED_TOKEN_DRAFT_CONTEST,
   SEED_TOKEN_DRAFT_CONTEST_CREDITS,
   SEED_TOKEN_DRAFT_CONTEST_ENTRY,
@@ -29,6 +36,10 @@ export const getCreateContestTx = async (args: {
     maxEntries: number;
     priceFeedIds: string[];
     rewardAllocation: number[];
+    access?: ContestAccess;
+    salaryCap?: SalaryCap;
+    allowShort?: boolean;
+    captainMultiplierBps?: number;
   };
 }) => {
   const {
@@ -66,6 +77,10 @@ export const getCreateContestTx = async (args: {
     [SEED_TOKEN_DRAFT_CONTEST_CREDITS, contestPda.toBuffer()],
     programId
   );
+  const [feedRegistryPda] = PublicKey.findProgramAddressSync(
+    [SEED_FEED_REGISTRY],
+    programId
+  );
 
   const {
     startTime,
@@ -74,6 +89,13 @@ export const getCreateContestTx = async (args: {
     maxEntries,
     rewardAllocation,
     priceFeedIds,
+    access,
+    salaryCap,
+    allowShort,
+    captainMultiplierBps,
+    teamConfig,
+    rebalanceTimes,
+    claimWindow,
   } = contestParams;
   const tokenFeedIds = priceFeedIds.map((v) => new PublicKey(hexToBase58(v)));
   const feedAccounts = priceFeedIds.map((v) =>
@@ -84,11 +106,7 @@ export const getCreateContestTx = async (args: {
     contestMetadata: contestMetadataPda,
     contest: contestPda,
     contestCredits: contestCreditsPda,
-    feed0: feedAccounts[0],
-    feed1: feedAccounts[1] || null,
-    feed2: feedAccounts[2] || null,
-    feed3: feedAccounts[3] || null,
-    feed4: feedAccounts[4] || null,
+    feedRegistry: feedRegistryPda,
   };
   const ixs = await pg.methods
     .createTokenDraftContest(
@@ -97,9 +115,17 @@ export const getCreateContestTx = async (args: {
       new BN(entryFee.toString()),
       maxEntries,
       tokenFeedIds,
-      Buffer.from(rewardAllocation)
+      Buffer.from(rewardAllocation),
+      access ?? { public: {} },
+      salaryCap ?? null,
+      allowShort ?? false,
+      captainMultiplierBps ?? null,
+      teamConfig ?? null,
+      (rebalanceTimes ?? []).map((v) => new BN(v)),
+      claimWindow !== undefined ? new BN(claimWindow) : null
     )
     .accounts(accounts)
+    .remainingAccounts(getFeedAccountMetas(feedAccounts))
     .instruction();
 
   let recentBlockhash: string;
@@ -129,6 +155,7 @@ export const getPostPricesTxs = async (args: {
   contestPda: web3.PublicKey;
   pythSolanaReceiver: PythSolanaReceiver;
   hermesClient: HermesClient;
+  timestamp?: number;
 }) => {
   const {
     svm,
@@ -137,6 +164,7 @@ export const getPostPricesTxs = async (args: {
     contestPda,
     pythSolanaReceiver,
     hermesClient,
+    timestamp,
   } = args;
 
   let contest: any;
@@ -149,7 +177,7 @@ export const getPostPricesTxs = async (args: {
   } else {
     contest = await pg.account.tokenDraftContest.fetch(contestPda);
   }
-  const startTimestamp = contest.startTime.toNumber();
+  const startTimestamp = timestamp ?? contest.startTime.toNumber();
 
   const priceFeedIds = contest.tokenFeedIds.map(
     (v) => "0x" + v.toBuffer().toString("hex").toLowerCase()
@@ -174,17 +202,13 @@ export const getPostPricesTxs = async (args: {
       const accounts = {
         signer: signer.publicKey,
         contest: contestPda,
-        feed0: priceUpdateAccounts[0],
-        feed1: priceUpdateAccounts[1] || null,
-        feed2: priceUpdateAccounts[2] || null,
-        feed3: priceUpdateAccounts[3] || null,
-        feed4: priceUpdateAccounts[4] || null,
         tokenProgram: utils.token.TOKEN_PROGRAM_ID,
       };
 
       const txInstruction = await pg.methods
         .postTokenDraftContestPrices()
         .accounts(accounts)
+        .remainingAccounts(getFeedAccountMetas(priceUpdateAccounts))
         .instruction();
 
       const instruction: InstructionWithEphemeralSigners = {
@@ -229,6 +253,10 @@ export const getEnterContestTx = async (args: {
   programTokenAccountPda: web3.PublicKey;
   signerTokenAccount: Account;
   creditAllocation: number[];
+  accessProof?: number[][];
+  captain?: number;
+  teamPda?: web3.PublicKey;
+  inviteSigner?: web3.Keypair;
 }) => {
   const {
     svm,
@@ -240,6 +268,10 @@ export const getEnterContestTx = async (args: {
     programTokenAccountPda,
     signerTokenAccount,
     creditAllocation,
+    accessProof,
+    captain,
+    teamPda,
+    inviteSigner,
   } = args;
   const programId = pg.programId;
   const [contestEntryPda] = PublicKey.findProgramAddressSync(
@@ -261,12 +293,13 @@ export const getEnterContestTx = async (args: {
     contest: contestPda,
     contestEntry: contestEntryPda,
     contestCredits: contestCreditsPda,
+    team: teamPda ?? null,
+    inviteSigner: inviteSigner?.publicKey ?? null,
     mint,
     programTokenAccount: programTokenAccountPda,
     signerTokenAccount: signerTokenAccount.address,
     tokenProgram: utils.token.TOKEN_PROGRAM_ID,
   };
-  const creditAllocationInput = Buffer.from(creditAllocation);
 
   let recentBlockhash: string;
   if (svm) {
@@ -278,7 +311,11 @@ export const getEnterContestTx = async (args: {
   }
 
   const ixs = await pg.methods
-    .enterTokenDraftContest(creditAllocationInput)
+    .enterTokenDraftContest(
+      creditAllocation,
+      accessProof ?? [],
+      captain ?? null
+    )
     .accounts(accounts)
     .instruction();
   const msg = new web3.TransactionMessage({
@@ -287,7 +324,7 @@ export const getEnterContestTx = async (args: {
     recentBlockhash,
   }).compileToV0Message();
   const tx = new web3.VersionedTransaction(msg);
-  tx.sign([signer]);
+  tx.sign(inviteSigner ? [signer, inviteSigner] : [signer]);
 
   return { tx, contestEntryPda, contestCreditsPda };
 };
@@ -303,6 +340,8 @@ export const getResolveContestTx = async (args: {
   programTokenAccountPda: web3.PublicKey;
   hermesClient: HermesClient;
   pythSolanaReceiver: PythSolanaReceiver;
+  er?: boolean;
+  timestamp?: number;
 }) => {
   const {
     svm,
@@ -315,6 +354,8 @@ export const getResolveContestTx = async (args: {
     hermesClient,
     pythSolanaReceiver,
     programTokenAccountPda,
+    er,
+    timestamp,
   } = args;
 
   let contest: any;
@@ -332,7 +373,7 @@ export const getResolveContestTx = async (args: {
     (v) => "0x" + v.toBuffer().toString("hex").toLowerCase()
   );
 
-  const endTimestamp = contest.endTime.toNumber();
+  const endTimestamp = timestamp ?? contest.endTime.toNumber();
   const priceUpdates = await hermesClient.getPriceUpdatesAtTimestamp(
     endTimestamp,
     priceFeedIds,
@@ -356,17 +397,15 @@ export const getResolveContestTx = async (args: {
         contestMetadata: contestMetadataPda,
         mint,
         programTokenAccount: programTokenAccountPda,
-        feed0: priceUpdateAccounts[0],
-        feed1: priceUpdateAccounts[1] || null,
-        feed2: priceUpdateAccounts[2] || null,
-        feed3: priceUpdateAccounts[3] || null,
-        feed4: priceUpdateAccounts[4] || null,
         tokenProgram: TOKEN_PROGRAM_ID,
       };
 
-      const txInstruction = await program.methods
-        .resolveTokenDraftContest()
+      const method = er
+        ? program.methods.resolveTokenDraftContestEr()
+        : program.methods.resolveTokenDraftContest();
+      const txInstruction = await method
         .accounts(accounts)
+        .remainingAccounts(getFeedAccountMetas(priceUpdateAccounts))
         .instruction();
 
       const instruction: InstructionWithEphemeralSigners = {
@@ -401,6 +440,48 @@ export const getResolveContestTx = async (args: {
   return { txs: vtxs };
 };
 
+export const getPayKeeperBountiesTx = async (args: {
+  svm: LiteSVM;
+  program: Program<Protocol>;
+  signer: web3.Keypair;
+  mint: web3.PublicKey;
+  contestPda: web3.PublicKey;
+  programTokenAccountPda: web3.PublicKey;
+  pricePosterTokenAccount: web3.PublicKey;
+  resolverTokenAccount: web3.PublicKey;
+}) => {
+  const {
+    svm,
+    program,
+    signer,
+    mint,
+    contestPda,
+    programTokenAccountPda,
+    pricePosterTokenAccount,
+    resolverTokenAccount,
+  } = args;
+
+  const ix = await program.methods
+    .payTokenDraftContestKeeperBounties()
+    .accounts({
+      signer: signer.publicKey,
+      contest: contestPda,
+      mint,
+      programTokenAccount: programTokenAccountPda,
+      pricePosterTokenAccount,
+      resolverTokenAccount,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+
+  const msg = new web3.TransactionMessage({
+    payerKey: signer.publicKey,
+    instructions: [ix],
+    recentBlockhash: svm.latestBlockhash(),
+  }).compileToV0Message();
+  return { tx: new web3.VersionedTransaction(msg) };
+};
+
 export const createContest = async (args: {
   provider: AnchorProvider;
   program: Program<Protocol>;
@@ -490,9 +571,8 @@ export const enterContest = async (args: {
     tokenProgram: utils.token.TOKEN_PROGRAM_ID,
   };
 
-  const creditAllocationInput = Buffer.from(creditAllocation);
   const txSignature = await program.methods
-    .enterTokenDraftContest(creditAllocationInput)
+    .enterTokenDraftContest(creditAllocation, [], null)
     .accounts(accounts)
     .signers([signer])
     .rpc();
@@ -540,17 +620,13 @@ export const postContestPrices = async (args: {
       const accounts = {
         signer: signer.publicKey,
         contest: contestPda,
-        feed0: priceUpdateAccounts[0],
-        feed1: priceUpdateAccounts[1] || null,
-        feed2: priceUpdateAccounts[2] || null,
-        feed3: priceUpdateAccounts[3] || null,
-        feed4: priceUpdateAccounts[4] || null,
         tokenProgram: utils.token.TOKEN_PROGRAM_ID,
       };
 
       const txInstruction = await pg.methods
         .postTokenDraftContestPrices()
         .accounts(accounts)
+        .remainingAccounts(getFeedAccountMetas(priceUpdateAccounts))
         .instruction();
 
       const instruction: InstructionWithEphemeralSigners = {
@@ -629,17 +705,13 @@ export const resolveContest = async (args: {
         mint,
         escrowTokenAccount: escrowTokenAccountPda,
         feeTokenAccount: feeTokenAccountPda,
-        feed0: priceUpdateAccounts[0],
-        feed1: priceUpdateAccounts[1] || null,
-        feed2: priceUpdateAccounts[2] || null,
-        feed3: priceUpdateAccounts[3] || null,
-        feed4: priceUpdateAccounts[4] || null,
         tokenProgram: utils.token.TOKEN_PROGRAM_ID,
       };
 
       const txInstruction = await program.methods
         .resolveTokenDraftContest()
         .accounts(accounts)
+        .remainingAccounts(getFeedAccountMetas(priceUpdateAccounts))
         .instruction();
 
       const instruction: InstructionWithEphemeralSigners = {
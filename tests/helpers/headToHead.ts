@@ -0,0 +1,164 @@
+import { BN, Program, utils, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account } from "@solana/spl-token";
+import { LiteSVM } from "litesvm";
+import { Protocol } from "../../target/types/protocol";
+import { getFeedAccountMetas, hexToBase58 } from "../helpers";
+import { SEED_FEED_REGISTRY, SEED_HEAD_TO_HEAD_CONTEST } from "./constants";
+
+const { PublicKey } = web3;
+
+const getRecentBlockhash = async (pg: Program<Protocol>, svm?: LiteSVM) => {
+  if (svm) {
+    return svm.latestBlockhash();
+  }
+  return await pg.provider.connection
+    .getLatestBlockhash()
+    .then((x) => x.blockhash);
+};
+
+export const getCreateHeadToHeadTx = async (args: {
+  svm?: LiteSVM;
+  program: Program<Protocol>;
+  signer: web3.Keypair;
+  configPda: web3.PublicKey;
+  contestMetadataPda: web3.PublicKey;
+  mint: web3.PublicKey;
+  programTokenAccountPda: web3.PublicKey;
+  signerTokenAccount: Account;
+  pythSolanaReceiver: PythSolanaReceiver;
+  duelParams: {
+    startTime: number;
+    endTime: number;
+    stake: bigint;
+    priceFeedIds: string[];
+    creditAllocation: number[];
+  };
+}) => {
+  const {
+    svm,
+    program: pg,
+    signer,
+    configPda,
+    contestMetadataPda,
+    mint,
+    programTokenAccountPda,
+    signerTokenAccount,
+    pythSolanaReceiver,
+    duelParams,
+  } = args;
+
+  const programId = pg.programId;
+  let contestMetadata;
+  if (svm) {
+    const contestMetadataAccInfo = svm.getAccount(contestMetadataPda);
+    contestMetadata = pg.coder.accounts.decode(
+      "contestMetadata",
+      Buffer.from(contestMetadataAccInfo.data)
+    );
+  } else {
+    contestMetadata = await pg.account.contestMetadata.fetch(
+      contestMetadataPda
+    );
+  }
+
+  const [contestPda] = PublicKey.findProgramAddressSync(
+    [
+      SEED_HEAD_TO_HEAD_CONTEST,
+      contestMetadata.headToHeadContestCount.toArrayLike(Buffer, "le", 8),
+      signer.publicKey.toBuffer(),
+    ],
+    programId
+  );
+  const [feedRegistryPda] = PublicKey.findProgramAddressSync(
+    [SEED_FEED_REGISTRY],
+    programId
+  );
+
+  const { startTime, endTime, stake, priceFeedIds, creditAllocation } =
+    duelParams;
+  const tokenFeedIds = priceFeedIds.map((v) => new PublicKey(hexToBase58(v)));
+  const feedAccounts = priceFeedIds.map((v) =>
+    pythSolanaReceiver.getPriceFeedAccountAddress(0, v)
+  );
+  const accounts = {
+    signer: signer.publicKey,
+    config: configPda,
+    contestMetadata: contestMetadataPda,
+    contest: contestPda,
+    feedRegistry: feedRegistryPda,
+    mint,
+    programTokenAccount: programTokenAccountPda,
+    signerTokenAccount: signerTokenAccount.address,
+    tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+  };
+  const ixs = await pg.methods
+    .createHeadToHeadContest(
+      new BN(startTime),
+      new BN(endTime),
+      new BN(stake.toString()),
+      tokenFeedIds,
+      creditAllocation
+    )
+    .accounts(accounts)
+    .remainingAccounts(getFeedAccountMetas(feedAccounts))
+    .instruction();
+
+  const msg = new web3.TransactionMessage({
+    payerKey: signer.publicKey,
+    instructions: [ixs],
+    recentBlockhash: await getRecentBlockhash(pg, svm),
+  }).compileToV0Message();
+  const tx = new web3.VersionedTransaction(msg);
+  tx.sign([signer]);
+
+  return { tx, contestPda };
+};
+
+export const getAcceptHeadToHeadTx = async (args: {
+  svm?: LiteSVM;
+  program: Program<Protocol>;
+  signer: web3.Keypair;
+  configPda: web3.PublicKey;
+  contestPda: web3.PublicKey;
+  mint: web3.PublicKey;
+  programTokenAccountPda: web3.PublicKey;
+  signerTokenAccount: Account;
+  creditAllocation: number[];
+}) => {
+  const {
+    svm,
+    program: pg,
+    signer,
+    configPda,
+    contestPda,
+    mint,
+    programTokenAccountPda,
+    signerTokenAccount,
+    creditAllocation,
+  } = args;
+
+  const accounts = {
+    signer: signer.publicKey,
+    config: configPda,
+    contest: contestPda,
+    mint,
+    programTokenAccount: programTokenAccountPda,
+    signerTokenAccount: signerTokenAccount.address,
+    tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+  };
+  const ixs = await pg.methods
+    .acceptHeadToHeadContest(creditAllocation)
+    .accounts(accounts)
+    .instruction();
+
+  const msg = new web3.TransactionMessage({
+    payerKey: signer.publicKey,
+    instructions: [ixs],
+    recentBlockhash: await getRecentBlockhash(pg, svm),
+  }).compileToV0Message();
+  const tx = new web3.VersionedTransaction(msg);
+  tx.sign([signer]);
+
+  return { tx };
+};
@@ -11,6 +11,29 @@ export type ContestParams = {
   maxEntries: number;
   priceFeedIds: string[];
   rewardAllocation: number[];
+  access?: ContestAccess;
+  salaryCap?: SalaryCap;
+  allowShort?: boolean;
+  captainMultiplierBps?: number;
+  teamConfig?: TeamConfig;
+  rebalanceTimes?: number[];
+  claimWindow?: number;
+};
+
+export type ContestAccess =
+  | { public: {} }
+  | { allowlist: { merkleRoot: number[] } }
+  | { inviteCode: { inviteKey: web3.PublicKey } };
+
+export type SalaryCap = {
+  cap: number;
+  tokenSalaries: number[];
+  weighting: { credits: {} } | { equal: {} };
+};
+
+export type TeamConfig = {
+  maxTeamSize: number;
+  scoring: { average: {} } | { best: {} };
 };
 
 export const createMint = async (args: {
@@ -120,5 +143,11 @@ export const UNITS_PER_USDC = 10 ** USDC_DECIMALS;
 
 export * from "./pyth";
 export * from "./contest";
+export * from "./headToHead";
+export * from "./overUnder";
+export * from "./parimutuel";
+export * from "./range";
+export * from "./snakeDraft";
+export * from "./survivor";
 export * from "./tx";
 export * from "./constants";
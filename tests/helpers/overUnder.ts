@@ -0,0 +1,149 @@
+import { BN, Program, utils, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account } from "@solana/spl-token";
+import { LiteSVM } from "litesvm";
+import { Protocol } from "../../target/types/protocol";
+import { getFeedAccountMetas, hexToBase58 } from "../helpers";
+import {
+  SEED_FEED_REGISTRY,
+  SEED_OVER_UNDER_CONTEST,
+  SEED_OVER_UNDER_ENTRY,
+} from "./constants";
+
+const { PublicKey } = web3;
+
+export type OverUnderSide = { over: {} } | { under: {} };
+
+export const getCreateOverUnderTx = async (args: {
+  svm: LiteSVM;
+  program: Program<Protocol>;
+  signer: web3.Keypair;
+  contestMetadataPda: web3.PublicKey;
+  pythSolanaReceiver: PythSolanaReceiver;
+  contestParams: {
+    startTime: number;
+    endTime: number;
+    priceFeedId: string;
+    strikePrice: number;
+  };
+}) => {
+  const {
+    svm,
+    program: pg,
+    signer,
+    contestMetadataPda,
+    pythSolanaReceiver,
+    contestParams,
+  } = args;
+
+  const programId = pg.programId;
+  const contestMetadataAccInfo = svm.getAccount(contestMetadataPda);
+  const contestMetadata = pg.coder.accounts.decode(
+    "contestMetadata",
+    Buffer.from(contestMetadataAccInfo.data)
+  );
+
+  const [contestPda] = PublicKey.findProgramAddressSync(
+    [
+      SEED_OVER_UNDER_CONTEST,
+      contestMetadata.overUnderContestCount.toArrayLike(Buffer, "le", 8),
+      signer.publicKey.toBuffer(),
+    ],
+    programId
+  );
+  const [feedRegistryPda] = PublicKey.findProgramAddressSync(
+    [SEED_FEED_REGISTRY],
+    programId
+  );
+
+  const { startTime, endTime, priceFeedId, strikePrice } = contestParams;
+  const feedAccount = pythSolanaReceiver.getPriceFeedAccountAddress(
+    0,
+    priceFeedId
+  );
+  const accounts = {
+    signer: signer.publicKey,
+    contestMetadata: contestMetadataPda,
+    contest: contestPda,
+    feedRegistry: feedRegistryPda,
+  };
+  const ixs = await pg.methods
+    .createOverUnderContest(
+      new BN(startTime),
+      new BN(endTime),
+      new PublicKey(hexToBase58(priceFeedId)),
+      strikePrice
+    )
+    .accounts(accounts)
+    .remainingAccounts(getFeedAccountMetas([feedAccount]))
+    .instruction();
+
+  const msg = new web3.TransactionMessage({
+    payerKey: signer.publicKey,
+    instructions: [ixs],
+    recentBlockhash: svm.latestBlockhash(),
+  }).compileToV0Message();
+  const tx = new web3.VersionedTransaction(msg);
+  tx.sign([signer]);
+
+  return { tx, contestPda };
+};
+
+export const getEnterOverUnderTx = async (args: {
+  svm: LiteSVM;
+  program: Program<Protocol>;
+  signer: web3.Keypair;
+  configPda: web3.PublicKey;
+  contestPda: web3.PublicKey;
+  mint: web3.PublicKey;
+  programTokenAccountPda: web3.PublicKey;
+  signerTokenAccount: Account;
+  side: OverUnderSide;
+  amount: bigint;
+}) => {
+  const {
+    svm,
+    program: pg,
+    signer,
+    configPda,
+    contestPda,
+    mint,
+    programTokenAccountPda,
+    signerTokenAccount,
+    side,
+    amount,
+  } = args;
+
+  const [contestEntryPda] = PublicKey.findProgramAddressSync(
+    [
+      SEED_OVER_UNDER_ENTRY,
+      contestPda.toBuffer(),
+      signer.publicKey.toBuffer(),
+    ],
+    pg.programId
+  );
+  const accounts = {
+    signer: signer.publicKey,
+    config: configPda,
+    contest: contestPda,
+    contestEntry: contestEntryPda,
+    mint,
+    programTokenAccount: programTokenAccountPda,
+    signerTokenAccount: signerTokenAccount.address,
+    tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+  };
+  const ixs = await pg.methods
+    .enterOverUnderContest(side, new BN(amount.toString()))
+    .accounts(accounts)
+    .instruction();
+
+  const msg = new web3.TransactionMessage({
+    payerKey: signer.publicKey,
+    instructions: [ixs],
+    recentBlockhash: svm.latestBlockhash(),
+  }).compileToV0Message();
+  const tx = new web3.VersionedTransaction(msg);
+  tx.sign([signer]);
+
+  return { tx, contestEntryPda };
+};
@@ -0,0 +1,141 @@
+import { BN, Program, utils, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account } from "@solana/spl-token";
+import { LiteSVM } from "litesvm";
+import { Protocol } from "../../target/types/protocol";
+import { getFeedAccountMetas, hexToBase58 } from "../helpers";
+import {
+  SEED_FEED_REGISTRY,
+  SEED_PARIMUTUEL_CONTEST,
+  SEED_PARIMUTUEL_ENTRY,
+} from "./constants";
+
+const { PublicKey } = web3;
+
+export const getCreateParimutuelTx = async (args: {
+  svm: LiteSVM;
+  program: Program<Protocol>;
+  signer: web3.Keypair;
+  contestMetadataPda: web3.PublicKey;
+  pythSolanaReceiver: PythSolanaReceiver;
+  contestParams: {
+    startTime: number;
+    endTime: number;
+    priceFeedIds: string[];
+  };
+}) => {
+  const {
+    svm,
+    program: pg,
+    signer,
+    contestMetadataPda,
+    pythSolanaReceiver,
+    contestParams,
+  } = args;
+
+  const programId = pg.programId;
+  const contestMetadataAccInfo = svm.getAccount(contestMetadataPda);
+  const contestMetadata = pg.coder.accounts.decode(
+    "contestMetadata",
+    Buffer.from(contestMetadataAccInfo.data)
+  );
+
+  const [contestPda] = PublicKey.findProgramAddressSync(
+    [
+      SEED_PARIMUTUEL_CONTEST,
+      contestMetadata.parimutuelContestCount.toArrayLike(Buffer, "le", 8),
+      signer.publicKey.toBuffer(),
+    ],
+    programId
+  );
+  const [feedRegistryPda] = PublicKey.findProgramAddressSync(
+    [SEED_FEED_REGISTRY],
+    programId
+  );
+
+  const { startTime, endTime, priceFeedIds } = contestParams;
+  const tokenFeedIds = priceFeedIds.map((v) => new PublicKey(hexToBase58(v)));
+  const feedAccounts = priceFeedIds.map((v) =>
+    pythSolanaReceiver.getPriceFeedAccountAddress(0, v)
+  );
+  const accounts = {
+    signer: signer.publicKey,
+    contestMetadata: contestMetadataPda,
+    contest: contestPda,
+    feedRegistry: feedRegistryPda,
+  };
+  const ixs = await pg.methods
+    .createParimutuelContest(new BN(startTime), new BN(endTime), tokenFeedIds)
+    .accounts(accounts)
+    .remainingAccounts(getFeedAccountMetas(feedAccounts))
+    .instruction();
+
+  const msg = new web3.TransactionMessage({
+    payerKey: signer.publicKey,
+    instructions: [ixs],
+    recentBlockhash: svm.latestBlockhash(),
+  }).compileToV0Message();
+  const tx = new web3.VersionedTransaction(msg);
+  tx.sign([signer]);
+
+  return { tx, contestPda };
+};
+
+export const getEnterParimutuelTx = async (args: {
+  svm: LiteSVM;
+  program: Program<Protocol>;
+  signer: web3.Keypair;
+  configPda: web3.PublicKey;
+  contestPda: web3.PublicKey;
+  mint: web3.PublicKey;
+  programTokenAccountPda: web3.PublicKey;
+  signerTokenAccount: Account;
+  tokenIndex: number;
+  amount: bigint;
+}) => {
+  const {
+    svm,
+    program: pg,
+    signer,
+    configPda,
+    contestPda,
+    mint,
+    programTokenAccountPda,
+    signerTokenAccount,
+    tokenIndex,
+    amount,
+  } = args;
+
+  const [contestEntryPda] = PublicKey.findProgramAddressSync(
+    [
+      SEED_PARIMUTUEL_ENTRY,
+      contestPda.toBuffer(),
+      signer.publicKey.toBuffer(),
+    ],
+    pg.programId
+  );
+  const accounts = {
+    signer: signer.publicKey,
+    config: configPda,
+    contest: contestPda,
+    contestEntry: contestEntryPda,
+    mint,
+    programTokenAccount: programTokenAccountPda,
+    signerTokenAccount: signerTokenAccount.address,
+    tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+  };
+  const ixs = await pg.methods
+    .enterParimutuelContest(tokenIndex, new BN(amount.toString()))
+    .accounts(accounts)
+    .instruction();
+
+  const msg = new web3.TransactionMessage({
+    payerKey: signer.publicKey,
+    instructions: [ixs],
+    recentBlockhash: svm.latestBlockhash(),
+  }).compileToV0Message();
+  const tx = new web3.VersionedTransaction(msg);
+  tx.sign([signer]);
+
+  return { tx, contestEntryPda };
+};
@@ -1,3 +1,5 @@
+import { web3 } from "@coral-xyz/anchor";
+
 export const pythPriceFeedIds = {
   bonk: "0x72b021217ca3fe68922a19aaf990109cb9d84e9ad004b4d2025ad6f529314419",
   popcat: "0xb9312a7ee50e189ef045aa3c7842e099b061bd9bdc99ac645956c3b660dc8cce",
@@ -6,3 +8,10 @@ export const pythPriceFeedIds = {
   fartcoin:
     "0x58cd29ef0e714c5affc44f269b2c1899a52da4169d7acc147b9da692e6953608",
 };
+
+export const getFeedAccountMetas = (feedAccounts: web3.PublicKey[]) =>
+  feedAccounts.map((pubkey) => ({
+    pubkey,
+    isSigner: false,
+    isWritable: false,
+  }));
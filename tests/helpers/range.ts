@@ -0,0 +1,172 @@
+import { BN, Program, utils, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account } from "@solana/spl-token";
+import { LiteSVM } from "litesvm";
+import { Protocol } from "../../target/types/protocol";
+import { getFeedAccountMetas, hexToBase58 } from "../helpers";
+import {
+  SEED_FEED_REGISTRY,
+  SEED_RANGE_CONTEST,
+  SEED_RANGE_CONTEST_ENTRY,
+  SEED_RANGE_CONTEST_PREDICTIONS,
+} from "./constants";
+
+const { PublicKey } = web3;
+
+export type RangeTarget = { price: {} } | { percentChange: {} };
+
+export const getCreateRangeContestTx = async (args: {
+  svm: LiteSVM;
+  program: Program<Protocol>;
+  signer: web3.Keypair;
+  contestMetadataPda: web3.PublicKey;
+  pythSolanaReceiver: PythSolanaReceiver;
+  contestParams: {
+    startTime: number;
+    endTime: number;
+    entryFee: bigint;
+    maxEntries: number;
+    priceFeedId: string;
+    target: RangeTarget;
+    rewardAllocation: number[];
+  };
+}) => {
+  const {
+    svm,
+    program: pg,
+    signer,
+    contestMetadataPda,
+    pythSolanaReceiver,
+    contestParams,
+  } = args;
+
+  const programId = pg.programId;
+  const contestMetadataAccInfo = svm.getAccount(contestMetadataPda);
+  const contestMetadata = pg.coder.accounts.decode(
+    "contestMetadata",
+    Buffer.from(contestMetadataAccInfo.data)
+  );
+
+  const [contestPda] = PublicKey.findProgramAddressSync(
+    [
+      SEED_RANGE_CONTEST,
+      contestMetadata.rangeContestCount.toArrayLike(Buffer, "le", 8),
+      signer.publicKey.toBuffer(),
+    ],
+    programId
+  );
+  const [contestPredictionsPda] = PublicKey.findProgramAddressSync(
+    [SEED_RANGE_CONTEST_PREDICTIONS, contestPda.toBuffer()],
+    programId
+  );
+  const [feedRegistryPda] = PublicKey.findProgramAddressSync(
+    [SEED_FEED_REGISTRY],
+    programId
+  );
+
+  const {
+    startTime,
+    endTime,
+    entryFee,
+    maxEntries,
+    priceFeedId,
+    target,
+    rewardAllocation,
+  } = contestParams;
+  const feedAccount = pythSolanaReceiver.getPriceFeedAccountAddress(
+    0,
+    priceFeedId
+  );
+  const accounts = {
+    signer: signer.publicKey,
+    contestMetadata: contestMetadataPda,
+    contest: contestPda,
+    contestPredictions: contestPredictionsPda,
+    feedRegistry: feedRegistryPda,
+  };
+  const ixs = await pg.methods
+    .createRangeContest(
+      new BN(startTime),
+      new BN(endTime),
+      new BN(entryFee.toString()),
+      maxEntries,
+      new PublicKey(hexToBase58(priceFeedId)),
+      target,
+      Buffer.from(rewardAllocation)
+    )
+    .accounts(accounts)
+    .remainingAccounts(getFeedAccountMetas([feedAccount]))
+    .instruction();
+
+  const msg = new web3.TransactionMessage({
+    payerKey: signer.publicKey,
+    instructions: [ixs],
+    recentBlockhash: svm.latestBlockhash(),
+  }).compileToV0Message();
+  const tx = new web3.VersionedTransaction(msg);
+  tx.sign([signer]);
+
+  return { tx, contestPda, contestPredictionsPda };
+};
+
+export const getEnterRangeContestTx = async (args: {
+  svm: LiteSVM;
+  program: Program<Protocol>;
+  signer: web3.Keypair;
+  configPda: web3.PublicKey;
+  contestPda: web3.PublicKey;
+  mint: web3.PublicKey;
+  programTokenAccountPda: web3.PublicKey;
+  signerTokenAccount: Account;
+  prediction: number;
+}) => {
+  const {
+    svm,
+    program: pg,
+    signer,
+    configPda,
+    contestPda,
+    mint,
+    programTokenAccountPda,
+    signerTokenAccount,
+    prediction,
+  } = args;
+
+  const [contestEntryPda] = PublicKey.findProgramAddressSync(
+    [
+      SEED_RANGE_CONTEST_ENTRY,
+      contestPda.toBuffer(),
+      signer.publicKey.toBuffer(),
+    ],
+    pg.programId
+  );
+  const [contestPredictionsPda] = PublicKey.findProgramAddressSync(
+    [SEED_RANGE_CONTEST_PREDICTIONS, contestPda.toBuffer()],
+    pg.programId
+  );
+  const accounts = {
+    signer: signer.publicKey,
+    config: configPda,
+    contest: contestPda,
+    contestEntry: contestEntryPda,
+    contestPredictions: contestPredictionsPda,
+    mint,
+    programTokenAccount: programTokenAccountPda,
+    signerTokenAccount: signerTokenAccount.address,
+    tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+  };
+  const ixs = await pg.methods
+    .enterRangeContest(prediction)
+    .accounts(accounts)
+    .instruction();
+
+  const msg = new web3.TransactionMessage({
+    payerKey: signer.publicKey,
+    instructions: [ixs],
+    recentBlockhash: svm.latestBlockhash(),
+  }).compileToV0Message();
+  const tx = new web3.VersionedTransaction(msg);
+  tx.sign([signer]);
+
+  return { tx, contestEntryPda };
+};
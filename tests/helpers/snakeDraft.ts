@@ -0,0 +1,159 @@
+import { BN, Program, utils, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account } from "@solana/spl-token";
+import { LiteSVM } from "litesvm";
+import { Protocol } from "../../target/types/protocol";
+import { getFeedAccountMetas, hexToBase58 } from "../helpers";
+import { SEED_FEED_REGISTRY, SEED_SNAKE_DRAFT_CONTEST } from "./constants";
+
+const { PublicKey } = web3;
+
+export const getCreateSnakeDraftContestTx = async (args: {
+  svm: LiteSVM;
+  program: Program<Protocol>;
+  signer: web3.Keypair;
+  contestMetadataPda: web3.PublicKey;
+  pythSolanaReceiver: PythSolanaReceiver;
+  contestParams: {
+    entryFee: bigint;
+    maxPlayers: number;
+    picksPerPlayer: number;
+    pickDuration: number;
+    contestDuration: number;
+    priceFeedIds: string[];
+    rewardAllocation: number[];
+    fillDuration: number;
+  };
+}) => {
+  const {
+    svm,
+    program: pg,
+    signer,
+    contestMetadataPda,
+    pythSolanaReceiver,
+    contestParams,
+  } = args;
+
+  const programId = pg.programId;
+  const contestMetadataAccInfo = svm.getAccount(contestMetadataPda);
+  const contestMetadata = pg.coder.accounts.decode(
+    "contestMetadata",
+    Buffer.from(contestMetadataAccInfo.data)
+  );
+
+  const [contestPda] = PublicKey.findProgramAddressSync(
+    [
+      SEED_SNAKE_DRAFT_CONTEST,
+      contestMetadata.snakeDraftContestCount.toArrayLike(Buffer, "le", 8),
+      signer.publicKey.toBuffer(),
+    ],
+    programId
+  );
+  const [feedRegistryPda] = PublicKey.findProgramAddressSync(
+    [SEED_FEED_REGISTRY],
+    programId
+  );
+
+  const {
+    entryFee,
+    maxPlayers,
+    picksPerPlayer,
+    pickDuration,
+    contestDuration,
+    priceFeedIds,
+    rewardAllocation,
+    fillDuration,
+  } = contestParams;
+
+  // The token pool is passed as indices into the feed registry
+  const feedRegistryAccInfo = svm.getAccount(feedRegistryPda);
+  const feedRegistry = pg.coder.accounts.decode(
+    "feedRegistry",
+    Buffer.from(feedRegistryAccInfo.data)
+  );
+  const feedIndices = priceFeedIds.map((feedId) =>
+    feedRegistry.feeds.findIndex((feed) =>
+      feed.feedId.equals(new PublicKey(hexToBase58(feedId)))
+    )
+  );
+
+  const feedAccounts = priceFeedIds.map((feedId) =>
+    pythSolanaReceiver.getPriceFeedAccountAddress(0, feedId)
+  );
+  const accounts = {
+    signer: signer.publicKey,
+    contestMetadata: contestMetadataPda,
+    contest: contestPda,
+    feedRegistry: feedRegistryPda,
+  };
+  const ixs = await pg.methods
+    .createSnakeDraftContest(
+      new BN(entryFee.toString()),
+      maxPlayers,
+      picksPerPlayer,
+      new BN(pickDuration),
+      new BN(contestDuration),
+      Buffer.from(feedIndices),
+      Buffer.from(rewardAllocation),
+      new BN(fillDuration)
+    )
+    .accounts(accounts)
+    .remainingAccounts(getFeedAccountMetas(feedAccounts))
+    .instruction();
+
+  const msg = new web3.TransactionMessage({
+    payerKey: signer.publicKey,
+    instructions: [ixs],
+    recentBlockhash: svm.latestBlockhash(),
+  }).compileToV0Message();
+  const tx = new web3.VersionedTransaction(msg);
+  tx.sign([signer]);
+
+  return { tx, contestPda };
+};
+
+export const getJoinSnakeDraftContestTx = async (args: {
+  svm: LiteSVM;
+  program: Program<Protocol>;
+  signer: web3.Keypair;
+  configPda: web3.PublicKey;
+  contestPda: web3.PublicKey;
+  mint: web3.PublicKey;
+  programTokenAccountPda: web3.PublicKey;
+  signerTokenAccount: Account;
+}) => {
+  const {
+    svm,
+    program: pg,
+    signer,
+    configPda,
+    contestPda,
+    mint,
+    programTokenAccountPda,
+    signerTokenAccount,
+  } = args;
+
+  const accounts = {
+    signer: signer.publicKey,
+    config: configPda,
+    contest: contestPda,
+    mint,
+    programTokenAccount: programTokenAccountPda,
+    signerTokenAccount: signerTokenAccount.address,
+    tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+  };
+  const ixs = await pg.methods
+    .joinSnakeDraftContest()
+    .accounts(accounts)
+    .instruction();
+
+  const msg = new web3.TransactionMessage({
+    payerKey: signer.publicKey,
+    instructions: [ixs],
+    recentBlockhash: svm.latestBlockhash(),
+  }).compileToV0Message();
+  const tx = new web3.VersionedTransaction(msg);
+  tx.sign([signer]);
+
+  return { tx };
+};
@@ -0,0 +1,172 @@
+import { BN, Program, utils, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account } from "@solana/spl-token";
+import { LiteSVM } from "litesvm";
+import { Protocol } from "../../target/types/protocol";
+import { getFeedAccountMetas, hexToBase58 } from "../helpers";
+import {
+  SEED_FEED_REGISTRY,
+  SEED_SURVIVOR_CONTEST,
+  SEED_SURVIVOR_CONTEST_CREDITS,
+  SEED_SURVIVOR_CONTEST_ENTRY,
+} from "./constants";
+
+const { PublicKey } = web3;
+
+export const getCreateSurvivorContestTx = async (args: {
+  svm: LiteSVM;
+  program: Program<Protocol>;
+  signer: web3.Keypair;
+  contestMetadataPda: web3.PublicKey;
+  pythSolanaReceiver: PythSolanaReceiver;
+  contestParams: {
+    startTime: number;
+    draftDuration: number;
+    roundDuration: number;
+    numRounds: number;
+    eliminationPercent: number;
+    entryFee: bigint;
+    maxEntries: number;
+    priceFeedIds: string[];
+  };
+}) => {
+  const {
+    svm,
+    program: pg,
+    signer,
+    contestMetadataPda,
+    pythSolanaReceiver,
+    contestParams,
+  } = args;
+
+  const programId = pg.programId;
+  const contestMetadataAccInfo = svm.getAccount(contestMetadataPda);
+  const contestMetadata = pg.coder.accounts.decode(
+    "contestMetadata",
+    Buffer.from(contestMetadataAccInfo.data)
+  );
+
+  const [contestPda] = PublicKey.findProgramAddressSync(
+    [
+      SEED_SURVIVOR_CONTEST,
+      contestMetadata.survivorContestCount.toArrayLike(Buffer, "le", 8),
+      signer.publicKey.toBuffer(),
+    ],
+    programId
+  );
+  const [contestCreditsPda] = PublicKey.findProgramAddressSync(
+    [SEED_SURVIVOR_CONTEST_CREDITS, contestPda.toBuffer()],
+    programId
+  );
+  const [feedRegistryPda] = PublicKey.findProgramAddressSync(
+    [SEED_FEED_REGISTRY],
+    programId
+  );
+
+  const {
+    startTime,
+    draftDuration,
+    roundDuration,
+    numRounds,
+    eliminationPercent,
+    entryFee,
+    maxEntries,
+    priceFeedIds,
+  } = contestParams;
+  const feedAccounts = priceFeedIds.map((feedId) =>
+    pythSolanaReceiver.getPriceFeedAccountAddress(0, feedId)
+  );
+  const accounts = {
+    signer: signer.publicKey,
+    contestMetadata: contestMetadataPda,
+    contest: contestPda,
+    contestCredits: contestCreditsPda,
+    feedRegistry: feedRegistryPda,
+  };
+  const ixs = await pg.methods
+    .createSurvivorContest(
+      new BN(startTime),
+      new BN(draftDuration),
+      new BN(roundDuration),
+      numRounds,
+      eliminationPercent,
+      new BN(entryFee.toString()),
+      maxEntries,
+      priceFeedIds.map((feedId) => new PublicKey(hexToBase58(feedId)))
+    )
+    .accounts(accounts)
+    .remainingAccounts(getFeedAccountMetas(feedAccounts))
+    .instruction();
+
+  const msg = new web3.TransactionMessage({
+    payerKey: signer.publicKey,
+    instructions: [ixs],
+    recentBlockhash: svm.latestBlockhash(),
+  }).compileToV0Message();
+  const tx = new web3.VersionedTransaction(msg);
+  tx.sign([signer]);
+
+  return { tx, contestPda, contestCreditsPda };
+};
+
+export const getEnterSurvivorContestTx = async (args: {
+  svm: LiteSVM;
+  program: Program<Protocol>;
+  signer: web3.Keypair;
+  configPda: web3.PublicKey;
+  contestPda: web3.PublicKey;
+  mint: web3.PublicKey;
+  programTokenAccountPda: web3.PublicKey;
+  signerTokenAccount: Account;
+  creditAllocation: number[];
+}) => {
+  const {
+    svm,
+    program: pg,
+    signer,
+    configPda,
+    contestPda,
+    mint,
+    programTokenAccountPda,
+    signerTokenAccount,
+    creditAllocation,
+  } = args;
+
+  const [contestEntryPda] = PublicKey.findProgramAddressSync(
+    [
+      SEED_SURVIVOR_CONTEST_ENTRY,
+      contestPda.toBuffer(),
+      signer.publicKey.toBuffer(),
+    ],
+    pg.programId
+  );
+  const [contestCreditsPda] = PublicKey.findProgramAddressSync(
+    [SEED_SURVIVOR_CONTEST_CREDITS, contestPda.toBuffer()],
+    pg.programId
+  );
+  const accounts = {
+    signer: signer.publicKey,
+    config: configPda,
+    contest: contestPda,
+    contestEntry: contestEntryPda,
+    contestCredits: contestCreditsPda,
+    mint,
+    programTokenAccount: programTokenAccountPda,
+    signerTokenAccount: signerTokenAccount.address,
+    tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+  };
+  const ixs = await pg.methods
+    .enterSurvivorContest(creditAllocation)
+    .accounts(accounts)
+    .instruction();
+
+  const msg = new web3.TransactionMessage({
+    payerKey: signer.publicKey,
+    instructions: [ixs],
+    recentBlockhash: svm.latestBlockhash(),
+  }).compileToV0Message();
+  const tx = new web3.VersionedTransaction(msg);
+  tx.sign([signer]);
+
+  return { tx, contestEntryPda };
+};
@@ -1,9 +1,10 @@
-import { web3 } from "@coral-xyz/anchor";
+import { EventParser, Program, web3 } from "@coral-xyz/anchor";
 import {
   LiteSVM,
   TransactionMetadata,
   FailedTransactionMetadata,
 } from "litesvm";
+import { Protocol } from "../../target/types/protocol";
 
 export const sendSvmTransaction = (
   svm: LiteSVM,
@@ -25,3 +26,11 @@ export const sendSvmTransaction = (
   }
   return info as TransactionMetadata;
 };
+
+export const parseEvents = (
+  program: Program<Protocol>,
+  info: TransactionMetadata
+) => {
+  const parser = new EventParser(program.programId, program.coder);
+  return [...parser.parseLogs(info.logs())];
+};
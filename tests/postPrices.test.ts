@@ -15,6 +15,7 @@ import { Account } from "@solana/spl-token";
 import { Protocol } from "../target/types/protocol";
 import {
   ContestParams,
+  getFeedAccountMetas,
   now,
   pythPriceFeedIds,
   UNITS_PER_USDC,
@@ -101,17 +102,13 @@ describe.skip("postPrices", () => {
         const accounts = {
           signer: signer.publicKey,
           contest: contestPda,
-          feed0: priceUpdateAccounts[0],
-          feed1: priceUpdateAccounts[1] || null,
-          feed2: priceUpdateAccounts[2] || null,
-          feed3: priceUpdateAccounts[3] || null,
-          feed4: priceUpdateAccounts[4] || null,
           tokenProgram: utils.token.TOKEN_PROGRAM_ID,
         };
 
         const txInstruction = await pg.methods
           .postTokenDraftContestPrices()
           .accounts(accounts)
+          .remainingAccounts(getFeedAccountMetas(priceUpdateAccounts))
           .instruction();
 
         const instruction: InstructionWithEphemeralSigners = {
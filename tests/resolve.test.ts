@@ -17,6 +17,7 @@ import { Protocol } from "../target/types/protocol";
 import {
   ContestParams,
   enterContest,
+  getFeedAccountMetas,
   postContestPrices,
   pythPriceFeedIds,
   UNITS_PER_USDC,
@@ -143,17 +144,13 @@ describe.skip("resolve", () => {
           mint,
           escrowTokenAccount: escrowTokenAccountPda,
           feeTokenAccount: feeTokenAccountPda,
-          feed0: priceUpdateAccounts[0],
-          feed1: priceUpdateAccounts[1] || null,
-          feed2: priceUpdateAccounts[2] || null,
-          feed3: priceUpdateAccounts[3] || null,
-          feed4: priceUpdateAccounts[4] || null,
           tokenProgram: utils.token.TOKEN_PROGRAM_ID,
         };
 
         const txInstruction = await pg.methods
           .resolveTokenDraftContest()
           .accounts(accounts)
+          .remainingAccounts(getFeedAccountMetas(priceUpdateAccounts))
           .instruction();
 
         const instruction: InstructionWithEphemeralSigners = {
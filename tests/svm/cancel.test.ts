@@ -0,0 +1,249 @@
+import { BN, Program, utils, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account, unpackAccount } from "@solana/spl-token";
+import { HermesClient } from "@pythnetwork/hermes-client";
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { Protocol } from "../../target/types/protocol";
+import { fixtureWithContest } from "../fixtures/svm";
+import {
+  getEnterContestTx,
+  getPostPricesTxs,
+  ONE_DAY,
+  ONE_HOUR,
+  parseEvents,
+  pythPriceFeedIds,
+  SEED_TOKEN_DRAFT_CONTEST_ENTRY,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { setSvmTimeTo } from "../helpers/time";
+
+const START_PRICE_POSTING_WINDOW = 15 * 60;
+
+describe("cancel", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let contestCreditsPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[] = [];
+
+  let pythSolanaReceiver: PythSolanaReceiver;
+  let priceServiceConnection: HermesClient;
+  let startTime: number;
+  const entryFee = BigInt(10 * UNITS_PER_USDC);
+  const numEntries = 3;
+
+  const getEntryPda = (signer: web3.Keypair) =>
+    web3.PublicKey.findProgramAddressSync(
+      [
+        SEED_TOKEN_DRAFT_CONTEST_ENTRY,
+        contestPda.toBuffer(),
+        signer.publicKey.toBuffer(),
+      ],
+      pg.programId
+    )[0];
+
+  const getBalance = (tokenAccount: Account) => {
+    const accInfo = svm.getAccount(tokenAccount.address);
+    return new BN(
+      unpackAccount(tokenAccount.address, accInfo as any).amount.toString()
+    );
+  };
+
+  const decodeContest = () =>
+    pg.coder.accounts.decode(
+      "tokenDraftContest",
+      Buffer.from(svm.getAccount(contestPda).data)
+    );
+
+  const toTx = (signer: web3.Keypair, ix: web3.TransactionInstruction) => {
+    const msg = new web3.TransactionMessage({
+      payerKey: signer.publicKey,
+      instructions: [ix],
+      recentBlockhash: svm.latestBlockhash(),
+    }).compileToV0Message();
+    return new web3.VersionedTransaction(msg);
+  };
+
+  const postPrices = async (signerId: number, timestamp?: number) => {
+    const { txs } = await getPostPricesTxs({
+      svm,
+      program: pg,
+      signer: signers[signerId],
+      contestPda,
+      pythSolanaReceiver,
+      hermesClient: priceServiceConnection,
+      timestamp,
+    });
+    let failed = false;
+    for (const tx of txs) {
+      try {
+        sendSvmTransaction(svm, signers[signerId], tx);
+      } catch {
+        failed = true;
+      }
+    }
+    return { failed };
+  };
+
+  const getCancelTx = async (signerId: number) => {
+    const signer = signers[signerId];
+    const ix = await pg.methods
+      .cancelTokenDraftContest()
+      .accounts({ signer: signer.publicKey, contest: contestPda })
+      .instruction();
+    return toTx(signer, ix);
+  };
+
+  const getRefundTx = async (signerId: number) => {
+    const signer = signers[signerId];
+    const ix = await pg.methods
+      .refundTokenDraftContestEntry()
+      .accounts({
+        signer: signer.publicKey,
+        config: configPda,
+        contest: contestPda,
+        contestEntry: getEntryPda(signer),
+        mint,
+        programTokenAccount: programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[signerId].address,
+        tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+    return toTx(signer, ix);
+  };
+
+  before(async () => {
+    const currentTime = Math.floor(Date.now() / 1000);
+    startTime = currentTime - ONE_DAY; // 1 day ago
+    const endTime = startTime + ONE_HOUR; // 1 hour from start
+    const contestParams = {
+      startTime,
+      endTime,
+      entryFee,
+      maxEntries: 100,
+      priceFeedIds: [pythPriceFeedIds.bonk, pythPriceFeedIds.popcat],
+      rewardAllocation: [75, 25],
+    };
+
+    const res = await fixtureWithContest({ contestParams });
+    svm = res.svm;
+    pg = res.program;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestCreditsPda = res.contestCreditsPda;
+    contestPda = res.contestPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signers = res.signers;
+    signerTokenAccounts = res.signerTokenAccounts;
+    pythSolanaReceiver = res.pythSolanaReceiver;
+    priceServiceConnection = res.priceServiceConnection;
+
+    const creditAllocations = [
+      [25, 75],
+      [50, 50],
+      [40, 60],
+    ];
+    for (let i = 0; i < numEntries; i++) {
+      const { tx } = await getEnterContestTx({
+        svm,
+        signer: signers[i],
+        program: pg,
+        configPda,
+        contestPda,
+        mint,
+        programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[i],
+        creditAllocation: creditAllocations[i],
+      });
+      sendSvmTransaction(svm, signers[i], tx);
+    }
+
+    setSvmTimeTo(svm, startTime + 1);
+  });
+
+  it("reject start prices published long after the start", async () => {
+    const { failed } = await postPrices(9, startTime + 5 * 60);
+    expect(failed).equal(true);
+    expect(decodeContest().tokenStartPrices.length).equal(0);
+  });
+
+  it("reject cancelling while start prices can still be posted", async () => {
+    const tx = await getCancelTx(9);
+    expect(() => sendSvmTransaction(svm, signers[9], tx)).to.throw();
+  });
+
+  it("reject posting start prices after the posting window", async () => {
+    setSvmTimeTo(svm, startTime + START_PRICE_POSTING_WINDOW + 1);
+    svm.expireBlockhash();
+    const { failed } = await postPrices(9);
+    expect(failed).equal(true);
+    expect(decodeContest().tokenStartPrices.length).equal(0);
+  });
+
+  it("cancel the contest once the posting window is missed", async () => {
+    const tx = await getCancelTx(9);
+    const info = sendSvmTransaction(svm, signers[9], tx);
+
+    const contest = decodeContest();
+    expect(contest.isCancelled).equal(true);
+    expect(contest.numPendingClaims).equal(numEntries);
+
+    const events = parseEvents(pg, info);
+    expect(events.map((v) => v.name)).deep.equal(["contestCancelled"]);
+
+    svm.expireBlockhash();
+    const replayTx = await getCancelTx(9);
+    expect(() => sendSvmTransaction(svm, signers[9], replayTx)).to.throw();
+  });
+
+  it("refund every entry fee once", async () => {
+    for (let i = 0; i < numEntries; i++) {
+      const balance = getBalance(signerTokenAccounts[i]);
+      const tx = await getRefundTx(i);
+      sendSvmTransaction(svm, signers[i], tx);
+
+      expect(getBalance(signerTokenAccounts[i]).sub(balance).toString()).equal(
+        entryFee.toString()
+      );
+    }
+    expect(decodeContest().numPendingClaims).equal(0);
+
+    svm.expireBlockhash();
+    const replayTx = await getRefundTx(0);
+    expect(() => sendSvmTransaction(svm, signers[0], replayTx)).to.throw();
+  });
+
+  it("close the refunded entries and the contest", async () => {
+    for (let i = 0; i < numEntries; i++) {
+      const ix = await pg.methods
+        .closeTokenDraftContestEntry()
+        .accounts({
+          signer: signers[i].publicKey,
+          user: signers[i].publicKey,
+          contest: contestPda,
+          contestCredits: contestCreditsPda,
+          contestEntry: getEntryPda(signers[i]),
+        })
+        .instruction();
+      sendSvmTransaction(svm, signers[i], toTx(signers[i], ix));
+      expect(svm.getAccount(getEntryPda(signers[i]))).equal(null);
+    }
+
+    const ix = await pg.methods
+      .closeTokenDraftContest()
+      .accounts({
+        signer: signers[0].publicKey,
+        contest: contestPda,
+        contestCredits: contestCreditsPda,
+      })
+      .instruction();
+    sendSvmTransaction(svm, signers[0], toTx(signers[0], ix));
+    expect(svm.getAccount(contestPda)).equal(null);
+  });
+});
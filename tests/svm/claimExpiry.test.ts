@@ -0,0 +1,348 @@
+import { BN, Program, utils, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account, unpackAccount } from "@solana/spl-token";
+import { HermesClient } from "@pythnetwork/hermes-client";
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { Protocol } from "../../target/types/protocol";
+import { fixtureWithContest } from "../fixtures/svm";
+import {
+  getEnterContestTx,
+  getPayKeeperBountiesTx,
+  getPostPricesTxs,
+  getResolveContestTx,
+  ONE_DAY,
+  ONE_HOUR,
+  parseEvents,
+  pythPriceFeedIds,
+  SEED_TOKEN_DRAFT_CONTEST_ENTRY,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { setSvmTimeTo } from "../helpers/time";
+
+describe("claimExpiry", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let contestMetadataPda: web3.PublicKey;
+  let contestCreditsPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[] = [];
+
+  let pythSolanaReceiver: PythSolanaReceiver;
+  let priceServiceConnection: HermesClient;
+  let winnerIds: number[];
+  let rewardAmounts: BN[];
+  let claimDeadline: number;
+  const rewardAllocation = [75, 25];
+  const claimWindow = ONE_DAY;
+  const numEntries = 4;
+
+  const getEntryPda = (signer: web3.Keypair) =>
+    web3.PublicKey.findProgramAddressSync(
+      [
+        SEED_TOKEN_DRAFT_CONTEST_ENTRY,
+        contestPda.toBuffer(),
+        signer.publicKey.toBuffer(),
+      ],
+      pg.programId
+    )[0];
+
+  const getBalance = (tokenAccount: Account) => {
+    const accInfo = svm.getAccount(tokenAccount.address);
+    return new BN(
+      unpackAccount(tokenAccount.address, accInfo as any).amount.toString()
+    );
+  };
+
+  const decodeContest = () =>
+    pg.coder.accounts.decode(
+      "tokenDraftContest",
+      Buffer.from(svm.getAccount(contestPda).data)
+    );
+
+  const decodeContestMetadata = () =>
+    pg.coder.accounts.decode(
+      "contestMetadata",
+      Buffer.from(svm.getAccount(contestMetadataPda).data)
+    );
+
+  const toTx = (signer: web3.Keypair, ix: web3.TransactionInstruction) => {
+    const msg = new web3.TransactionMessage({
+      payerKey: signer.publicKey,
+      instructions: [ix],
+      recentBlockhash: svm.latestBlockhash(),
+    }).compileToV0Message();
+    return new web3.VersionedTransaction(msg);
+  };
+
+  const getClaimTx = async (winnerId: number) => {
+    const signer = signers[winnerId];
+    const ix = await pg.methods
+      .claimTokenDraftContest()
+      .accounts({
+        signer: signer.publicKey,
+        config: configPda,
+        contest: contestPda,
+        contestMetadata: contestMetadataPda,
+        contestEntry: getEntryPda(signer),
+        mint,
+        programTokenAccount: programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[winnerId].address,
+        tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+    return toTx(signer, ix);
+  };
+
+  const getDistributeTx = async (keeperId: number, winnerId: number) => {
+    const keeper = signers[keeperId];
+    const ix = await pg.methods
+      .distributeTokenDraftContestRewards()
+      .accounts({
+        signer: keeper.publicKey,
+        contest: contestPda,
+        contestMetadata: contestMetadataPda,
+        mint,
+        programTokenAccount: programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[keeperId].address,
+        tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts([
+        {
+          pubkey: getEntryPda(signers[winnerId]),
+          isSigner: false,
+          isWritable: true,
+        },
+        {
+          pubkey: signerTokenAccounts[winnerId].address,
+          isSigner: false,
+          isWritable: true,
+        },
+      ])
+      .instruction();
+    return toTx(keeper, ix);
+  };
+
+  const getSweepTx = async (signerId: number) => {
+    const signer = signers[signerId];
+    const ix = await pg.methods
+      .sweepTokenDraftContest()
+      .accounts({
+        signer: signer.publicKey,
+        contest: contestPda,
+        contestMetadata: contestMetadataPda,
+        season: null,
+      })
+      .instruction();
+    return toTx(signer, ix);
+  };
+
+  before(async () => {
+    const currentTime = Math.floor(Date.now() / 1000);
+    const startTime = currentTime - ONE_DAY; // 1 day ago
+    const endTime = startTime + ONE_HOUR; // 1 hour from start
+    const contestParams = {
+      startTime,
+      endTime,
+      entryFee: BigInt(10 * UNITS_PER_USDC),
+      maxEntries: 100,
+      priceFeedIds: [pythPriceFeedIds.bonk, pythPriceFeedIds.popcat],
+      rewardAllocation,
+      claimWindow,
+    };
+
+    const res = await fixtureWithContest({ contestParams });
+    svm = res.svm;
+    pg = res.program;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestMetadataPda = res.contestMetadataPda;
+    contestCreditsPda = res.contestCreditsPda;
+    contestPda = res.contestPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signers = res.signers;
+    signerTokenAccounts = res.signerTokenAccounts;
+    pythSolanaReceiver = res.pythSolanaReceiver;
+    priceServiceConnection = res.priceServiceConnection;
+
+    const creditAllocations = [
+      [25, 75],
+      [50, 50],
+      [40, 60],
+      [75, 25],
+    ];
+    for (let i = 0; i < numEntries; i++) {
+      const { tx } = await getEnterContestTx({
+        svm,
+        signer: signers[i],
+        program: pg,
+        configPda,
+        contestPda,
+        mint,
+        programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[i],
+        creditAllocation: creditAllocations[i],
+      });
+      sendSvmTransaction(svm, signers[i], tx);
+    }
+
+    setSvmTimeTo(svm, contestParams.startTime + 1);
+
+    const { txs: txsPostPrices } = await getPostPricesTxs({
+      svm,
+      program: pg,
+      signer: signers[0],
+      contestPda,
+      pythSolanaReceiver,
+      hermesClient: priceServiceConnection,
+    });
+    for (const tx of txsPostPrices) {
+      sendSvmTransaction(svm, signers[0], tx);
+    }
+
+    setSvmTimeTo(svm, contestParams.endTime + 1);
+
+    const { txs: txsResolve } = await getResolveContestTx({
+      svm,
+      program: pg,
+      signer: signers[0],
+      mint,
+      contestPda,
+      contestCreditsPda,
+      contestMetadataPda,
+      programTokenAccountPda,
+      hermesClient: priceServiceConnection,
+      pythSolanaReceiver,
+    });
+    for (const tx of txsResolve) {
+      sendSvmTransaction(svm, signers[0], tx);
+    }
+
+    const contest = decodeContest();
+    winnerIds = contest.winnerIds;
+    claimDeadline = contest.claimDeadline.toNumber();
+    expect(claimDeadline).equal(contestParams.endTime + 1 + claimWindow);
+
+    const totalPoolAmount = new BN(contestParams.entryFee.toString()).mul(
+      new BN(numEntries)
+    );
+    const feeAmount = totalPoolAmount
+      .mul(new BN(decodeContestMetadata().tokenDraftContestFeePercent))
+      .div(new BN(100));
+    rewardAmounts = rewardAllocation.map((alloc) =>
+      totalPoolAmount.sub(feeAmount).mul(new BN(alloc)).div(new BN(100))
+    );
+  });
+
+  it("reject sweeping while the claim window is open", async () => {
+    const tx = await getSweepTx(9);
+    expect(() => sendSvmTransaction(svm, signers[9], tx)).to.throw();
+  });
+
+  it("claim a reward before the deadline", async () => {
+    setSvmTimeTo(svm, claimDeadline);
+    const winnerId = winnerIds[0];
+    const balance = getBalance(signerTokenAccounts[winnerId]);
+    sendSvmTransaction(svm, signers[winnerId], await getClaimTx(winnerId));
+
+    expect(
+      getBalance(signerTokenAccounts[winnerId]).sub(balance).toString()
+    ).equal(rewardAmounts[0].toString());
+    expect(decodeContest().paidAmount.toString()).equal(
+      rewardAmounts[0].toString()
+    );
+  });
+
+  it("reject claiming and distributing after the deadline", async () => {
+    setSvmTimeTo(svm, claimDeadline + 1);
+    const winnerId = winnerIds[1];
+
+    const claimTx = await getClaimTx(winnerId);
+    expect(() =>
+      sendSvmTransaction(svm, signers[winnerId], claimTx)
+    ).to.throw();
+
+    const distributeTx = await getDistributeTx(9, winnerId);
+    expect(() => sendSvmTransaction(svm, signers[9], distributeTx)).to.throw();
+  });
+
+  it("sweep unclaimed prizes into the treasury", async () => {
+    const feeAmount = new BN(
+      decodeContestMetadata().tokenDraftContestFeeAmount
+    );
+
+    const tx = await getSweepTx(9);
+    const info = sendSvmTransaction(svm, signers[9], tx);
+
+    expect(
+      new BN(decodeContestMetadata().tokenDraftContestFeeAmount)
+        .sub(feeAmount)
+        .toString()
+    ).equal(rewardAmounts[1].toString());
+
+    const contest = decodeContest();
+    expect(contest.numPendingClaims).equal(0);
+    expect(contest.paidAmount.toString()).equal(
+      rewardAmounts[0].add(rewardAmounts[1]).toString()
+    );
+
+    const events = parseEvents(pg, info);
+    expect(events.map((v) => v.name)).deep.equal(["prizesSwept"]);
+    expect(events[0].data.amount.toString()).equal(
+      rewardAmounts[1].toString()
+    );
+    expect(events[0].data.season).equal(null);
+  });
+
+  it("reject sweeping twice", async () => {
+    svm.expireBlockhash();
+    const tx = await getSweepTx(9);
+    expect(() => sendSvmTransaction(svm, signers[9], tx)).to.throw();
+  });
+
+  it("close the entries and the contest after the deadline", async () => {
+    // The expired winner's entry can be closed without a claim
+    for (let i = 0; i < numEntries; i++) {
+      const entryIx = await pg.methods
+        .closeTokenDraftContestEntry()
+        .accounts({
+          signer: signers[0].publicKey,
+          user: signers[i].publicKey,
+          contest: contestPda,
+          contestCredits: contestCreditsPda,
+          contestEntry: getEntryPda(signers[i]),
+        })
+        .instruction();
+      sendSvmTransaction(svm, signers[0], toTx(signers[0], entryIx));
+      expect(svm.getAccount(getEntryPda(signers[i]))).equal(null);
+    }
+
+    const { tx: bountiesTx } = await getPayKeeperBountiesTx({
+      svm,
+      program: pg,
+      signer: signers[0],
+      mint,
+      contestPda,
+      programTokenAccountPda,
+      pricePosterTokenAccount: signerTokenAccounts[0].address,
+      resolverTokenAccount: signerTokenAccounts[0].address,
+    });
+    sendSvmTransaction(svm, signers[0], bountiesTx);
+
+    const contestIx = await pg.methods
+      .closeTokenDraftContest()
+      .accounts({
+        signer: signers[0].publicKey,
+        contest: contestPda,
+        contestCredits: contestCreditsPda,
+      })
+      .instruction();
+    sendSvmTransaction(svm, signers[0], toTx(signers[0], contestIx));
+    expect(svm.getAccount(contestPda)).equal(null);
+  });
+});
@@ -0,0 +1,307 @@
+import { BN, Program, utils, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account, unpackAccount } from "@solana/spl-token";
+import { HermesClient } from "@pythnetwork/hermes-client";
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { Protocol } from "../../target/types/protocol";
+import { fixtureWithContest } from "../fixtures/svm";
+import {
+  getCreateContestTx,
+  getEnterContestTx,
+  getPostPricesTxs,
+  getResolveContestTx,
+  ONE_DAY,
+  ONE_HOUR,
+  parseEvents,
+  pythPriceFeedIds,
+  SEED_TOKEN_DRAFT_CONTEST_ENTRY,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { setSvmTimeTo } from "../helpers/time";
+
+describe("claimMany", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let contestMetadataPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[] = [];
+
+  let pythSolanaReceiver: PythSolanaReceiver;
+  let priceServiceConnection: HermesClient;
+
+  // The last contest is left unresolved
+  let contests: {
+    contestPda: web3.PublicKey;
+    contestCreditsPda: web3.PublicKey;
+  }[] = [];
+  const numEntries = 4;
+  const creditAllocations = [
+    [
+      [25, 75],
+      [50, 50],
+      [40, 60],
+      [75, 25],
+    ],
+    [
+      [75, 25],
+      [40, 60],
+      [50, 50],
+      [25, 75],
+    ],
+    [
+      [50, 50],
+      [25, 75],
+      [75, 25],
+      [40, 60],
+    ],
+  ];
+  const rewardAllocation = [75, 25];
+
+  const getEntryPda = (contestPda: web3.PublicKey, signer: web3.Keypair) =>
+    web3.PublicKey.findProgramAddressSync(
+      [
+        SEED_TOKEN_DRAFT_CONTEST_ENTRY,
+        contestPda.toBuffer(),
+        signer.publicKey.toBuffer(),
+      ],
+      pg.programId
+    )[0];
+
+  const getBalance = (tokenAccount: Account) => {
+    const accInfo = svm.getAccount(tokenAccount.address);
+    return new BN(
+      unpackAccount(tokenAccount.address, accInfo as any).amount.toString()
+    );
+  };
+
+  const getClaimManyTx = async (signerId: number) => {
+    const signer = signers[signerId];
+    const remainingAccounts = contests.flatMap(({ contestPda }) => [
+      { pubkey: contestPda, isSigner: false, isWritable: true },
+      {
+        pubkey: getEntryPda(contestPda, signer),
+        isSigner: false,
+        isWritable: true,
+      },
+    ]);
+
+    const accounts = {
+      signer: signer.publicKey,
+      config: configPda,
+      contestMetadata: contestMetadataPda,
+      mint,
+      programTokenAccount: programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[signerId].address,
+      tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+    };
+    const ix = await pg.methods
+      .claimManyTokenDraftContests()
+      .accounts(accounts)
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+
+    const msg = new web3.TransactionMessage({
+      payerKey: signer.publicKey,
+      instructions: [ix],
+      recentBlockhash: svm.latestBlockhash(),
+    }).compileToV0Message();
+    return new web3.VersionedTransaction(msg);
+  };
+
+  before(async () => {
+    const currentTime = Math.floor(Date.now() / 1000);
+    const startTime = currentTime - ONE_DAY; // 1 day ago
+    const endTime = startTime + ONE_HOUR; // 1 hour from start
+    const contestParams = {
+      startTime,
+      endTime,
+      entryFee: BigInt(10 * UNITS_PER_USDC),
+      maxEntries: 100,
+      priceFeedIds: [pythPriceFeedIds.bonk, pythPriceFeedIds.popcat],
+      rewardAllocation,
+    };
+
+    const res = await fixtureWithContest({ contestParams });
+    svm = res.svm;
+    pg = res.program;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestMetadataPda = res.contestMetadataPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signers = res.signers;
+    signerTokenAccounts = res.signerTokenAccounts;
+    pythSolanaReceiver = res.pythSolanaReceiver;
+    priceServiceConnection = res.priceServiceConnection;
+    contests.push({
+      contestPda: res.contestPda,
+      contestCreditsPda: res.contestCreditsPda,
+    });
+
+    for (let i = 0; i < creditAllocations.length; i++) {
+      const { tx, contestPda, contestCreditsPda } = await getCreateContestTx({
+        svm,
+        program: pg,
+        signer: signers[0],
+        contestMetadataPda,
+        contestParams,
+        pythSolanaReceiver,
+      });
+      sendSvmTransaction(svm, signers[0], tx);
+      contests.push({ contestPda, contestCreditsPda });
+    }
+
+    for (let c = 0; c < contests.length; c++) {
+      for (let i = 0; i < numEntries; i++) {
+        const { tx } = await getEnterContestTx({
+          svm,
+          signer: signers[i],
+          program: pg,
+          configPda,
+          contestPda: contests[c].contestPda,
+          mint,
+          programTokenAccountPda,
+          signerTokenAccount: signerTokenAccounts[i],
+          creditAllocation:
+            creditAllocations[c % creditAllocations.length][i],
+        });
+        sendSvmTransaction(svm, signers[i], tx);
+      }
+    }
+
+    for (const { contestPda, contestCreditsPda } of contests.slice(0, -1)) {
+      setSvmTimeTo(svm, contestParams.startTime + 1);
+      const { txs: txsPostPrices } = await getPostPricesTxs({
+        svm,
+        program: pg,
+        signer: signers[0],
+        contestPda,
+        pythSolanaReceiver,
+        hermesClient: priceServiceConnection,
+      });
+      for (const tx of txsPostPrices) {
+        sendSvmTransaction(svm, signers[0], tx);
+      }
+
+      setSvmTimeTo(svm, contestParams.endTime + 1);
+      const { txs: txsResolve } = await getResolveContestTx({
+        svm,
+        program: pg,
+        signer: signers[0],
+        mint,
+        contestPda,
+        contestCreditsPda,
+        contestMetadataPda,
+        programTokenAccountPda,
+        hermesClient: priceServiceConnection,
+        pythSolanaReceiver,
+      });
+      for (const tx of txsResolve) {
+        sendSvmTransaction(svm, signers[0], tx);
+      }
+    }
+  });
+
+  it("claim every winning entry in a single transfer", async () => {
+    const contestMetadata = pg.coder.accounts.decode(
+      "contestMetadata",
+      Buffer.from(svm.getAccount(contestMetadataPda).data)
+    );
+
+    for (let signerId = 0; signerId < numEntries; signerId++) {
+      let expectedAmount = new BN(0);
+      let numWins = 0;
+      for (const { contestPda } of contests) {
+        const contest = pg.coder.accounts.decode(
+          "tokenDraftContest",
+          Buffer.from(svm.getAccount(contestPda).data)
+        );
+        const pos = contest.winnerIds.indexOf(signerId);
+        if (!contest.isResolved || pos < 0) {
+          continue;
+        }
+
+        const totalPoolAmount = contest.entryFee.mul(
+          new BN(contest.numEntries)
+        );
+        const feeAmount = totalPoolAmount
+          .mul(new BN(contestMetadata.tokenDraftContestFeePercent))
+          .div(new BN(100));
+        expectedAmount = expectedAmount.add(
+          totalPoolAmount
+            .sub(feeAmount)
+            .mul(new BN(rewardAllocation[pos]))
+            .div(new BN(100))
+        );
+        numWins += 1;
+      }
+
+      const balance = getBalance(signerTokenAccounts[signerId]);
+      const tx = await getClaimManyTx(signerId);
+      const info = sendSvmTransaction(svm, signers[signerId], tx);
+
+      expect(
+        getBalance(signerTokenAccounts[signerId]).sub(balance).toString()
+      ).equal(expectedAmount.toString());
+
+      const events = parseEvents(pg, info);
+      expect(events.length).equal(numWins);
+
+      // Exactly one token transfer no matter how many contests paid out
+      const numTransfers = info
+        .logs()
+        .filter((v) => v.includes("Instruction: TransferChecked")).length;
+      expect(numTransfers).equal(numWins > 0 ? 1 : 0);
+    }
+  });
+
+  it("skip entries that already claimed", async () => {
+    svm.expireBlockhash();
+    const balance = getBalance(signerTokenAccounts[0]);
+    const tx = await getClaimManyTx(0);
+    const info = sendSvmTransaction(svm, signers[0], tx);
+
+    expect(getBalance(signerTokenAccounts[0]).toString()).equal(
+      balance.toString()
+    );
+    expect(parseEvents(pg, info).length).equal(0);
+  });
+
+  it("reject an entry that belongs to another wallet", async () => {
+    const signer = signers[0];
+    const { contestPda } = contests[0];
+    const accounts = {
+      signer: signer.publicKey,
+      config: configPda,
+      contestMetadata: contestMetadataPda,
+      mint,
+      programTokenAccount: programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[0].address,
+      tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+    };
+    const ix = await pg.methods
+      .claimManyTokenDraftContests()
+      .accounts(accounts)
+      .remainingAccounts([
+        { pubkey: contestPda, isSigner: false, isWritable: true },
+        {
+          pubkey: getEntryPda(contestPda, signers[1]),
+          isSigner: false,
+          isWritable: true,
+        },
+      ])
+      .instruction();
+
+    const msg = new web3.TransactionMessage({
+      payerKey: signer.publicKey,
+      instructions: [ix],
+      recentBlockhash: svm.latestBlockhash(),
+    }).compileToV0Message();
+    const tx = new web3.VersionedTransaction(msg);
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+});
@@ -0,0 +1,307 @@
+import { Program, utils, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account } from "@solana/spl-token";
+import { HermesClient } from "@pythnetwork/hermes-client";
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { Protocol } from "../../target/types/protocol";
+import { fixtureWithContest } from "../fixtures/svm";
+import {
+  getEnterContestTx,
+  getPayKeeperBountiesTx,
+  getPostPricesTxs,
+  getResolveContestTx,
+  ONE_DAY,
+  ONE_HOUR,
+  parseEvents,
+  pythPriceFeedIds,
+  SEED_TOKEN_DRAFT_CONTEST_ENTRY,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { setSvmTimeTo } from "../helpers/time";
+
+describe("close", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let contestMetadataPda: web3.PublicKey;
+  let contestCreditsPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[] = [];
+
+  let pythSolanaReceiver: PythSolanaReceiver;
+  let priceServiceConnection: HermesClient;
+  let winnerIds: number[];
+  let loserIds: number[];
+  const numEntries = 4;
+
+  const getEntryPda = (signer: web3.Keypair) =>
+    web3.PublicKey.findProgramAddressSync(
+      [
+        SEED_TOKEN_DRAFT_CONTEST_ENTRY,
+        contestPda.toBuffer(),
+        signer.publicKey.toBuffer(),
+      ],
+      pg.programId
+    )[0];
+
+  const toTx = (signer: web3.Keypair, ix: web3.TransactionInstruction) => {
+    const msg = new web3.TransactionMessage({
+      payerKey: signer.publicKey,
+      instructions: [ix],
+      recentBlockhash: svm.latestBlockhash(),
+    }).compileToV0Message();
+    return new web3.VersionedTransaction(msg);
+  };
+
+  const getCloseEntryTx = async (signerId: number, userId = signerId) => {
+    const signer = signers[signerId];
+    const user = signers[userId];
+    const ix = await pg.methods
+      .closeTokenDraftContestEntry()
+      .accounts({
+        signer: signer.publicKey,
+        user: user.publicKey,
+        contest: contestPda,
+        contestCredits: contestCreditsPda,
+        contestEntry: getEntryPda(user),
+      })
+      .instruction();
+    return toTx(signer, ix);
+  };
+
+  const getCloseContestTx = async (signerId: number) => {
+    const signer = signers[signerId];
+    const ix = await pg.methods
+      .closeTokenDraftContest()
+      .accounts({
+        signer: signer.publicKey,
+        contest: contestPda,
+        contestCredits: contestCreditsPda,
+      })
+      .instruction();
+    return toTx(signer, ix);
+  };
+
+  const getClaimTx = async (winnerId: number) => {
+    const signer = signers[winnerId];
+    const ix = await pg.methods
+      .claimTokenDraftContest()
+      .accounts({
+        signer: signer.publicKey,
+        config: configPda,
+        contest: contestPda,
+        contestMetadata: contestMetadataPda,
+        contestEntry: getEntryPda(signer),
+        mint,
+        programTokenAccount: programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[winnerId].address,
+        tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+    return toTx(signer, ix);
+  };
+
+  before(async () => {
+    const currentTime = Math.floor(Date.now() / 1000);
+    const startTime = currentTime - ONE_DAY; // 1 day ago
+    const endTime = startTime + ONE_HOUR; // 1 hour from start
+    const contestParams = {
+      startTime,
+      endTime,
+      entryFee: BigInt(10 * UNITS_PER_USDC),
+      maxEntries: 100,
+      priceFeedIds: [pythPriceFeedIds.bonk, pythPriceFeedIds.popcat],
+      rewardAllocation: [75, 25],
+    };
+
+    const res = await fixtureWithContest({ contestParams });
+    svm = res.svm;
+    pg = res.program;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestMetadataPda = res.contestMetadataPda;
+    contestCreditsPda = res.contestCreditsPda;
+    contestPda = res.contestPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signers = res.signers;
+    signerTokenAccounts = res.signerTokenAccounts;
+    pythSolanaReceiver = res.pythSolanaReceiver;
+    priceServiceConnection = res.priceServiceConnection;
+
+    const creditAllocations = [
+      [25, 75],
+      [50, 50],
+      [40, 60],
+      [75, 25],
+    ];
+    for (let i = 0; i < numEntries; i++) {
+      const { tx } = await getEnterContestTx({
+        svm,
+        signer: signers[i],
+        program: pg,
+        configPda,
+        contestPda,
+        mint,
+        programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[i],
+        creditAllocation: creditAllocations[i],
+      });
+      sendSvmTransaction(svm, signers[i], tx);
+    }
+
+    setSvmTimeTo(svm, contestParams.startTime + 1);
+
+    const { txs: txsPostPrices } = await getPostPricesTxs({
+      svm,
+      program: pg,
+      signer: signers[0],
+      contestPda,
+      pythSolanaReceiver,
+      hermesClient: priceServiceConnection,
+    });
+    for (const tx of txsPostPrices) {
+      sendSvmTransaction(svm, signers[0], tx);
+    }
+
+    setSvmTimeTo(svm, contestParams.endTime + 1);
+
+    const { txs: txsResolve } = await getResolveContestTx({
+      svm,
+      program: pg,
+      signer: signers[0],
+      mint,
+      contestPda,
+      contestCreditsPda,
+      contestMetadataPda,
+      programTokenAccountPda,
+      hermesClient: priceServiceConnection,
+      pythSolanaReceiver,
+    });
+    for (const tx of txsResolve) {
+      sendSvmTransaction(svm, signers[0], tx);
+    }
+
+    const contest = pg.coder.accounts.decode(
+      "tokenDraftContest",
+      Buffer.from(svm.getAccount(contestPda).data)
+    );
+    winnerIds = contest.winnerIds;
+    loserIds = [...Array(numEntries).keys()].filter(
+      (i) => !winnerIds.includes(i)
+    );
+    expect(contest.numPendingClaims).equal(winnerIds.length);
+  });
+
+  it("reject closing a winning entry before it is claimed", async () => {
+    const winnerId = winnerIds[0];
+    const tx = await getCloseEntryTx(winnerId);
+    expect(() => sendSvmTransaction(svm, signers[winnerId], tx)).to.throw();
+  });
+
+  it("reject closing the contest while prizes are unpaid", async () => {
+    const tx = await getCloseContestTx(0);
+    expect(() => sendSvmTransaction(svm, signers[0], tx)).to.throw();
+  });
+
+  it("close a losing entry and return its rent", async () => {
+    const loserId = loserIds[0];
+    const entryPda = getEntryPda(signers[loserId]);
+    const entryLamports = svm.getAccount(entryPda).lamports;
+    const credits = svm.getAccount(contestCreditsPda);
+    const balance = svm.getBalance(signers[loserId].publicKey);
+
+    const tx = await getCloseEntryTx(loserId);
+    const info = sendSvmTransaction(svm, signers[loserId], tx);
+
+    const events = parseEvents(pg, info);
+    expect(events.map((v) => v.name)).deep.equal(["entryClosed"]);
+    expect(events[0].data.entry.toBase58()).equal(entryPda.toBase58());
+
+    // The credit row paid for while entering is dropped and its rent
+    // refunded with the entry, leaving the credits account rent exempt
+    const closedCredits = svm.getAccount(contestCreditsPda);
+    const rowRent = credits.lamports - closedCredits.lamports;
+    expect(rowRent).greaterThan(0);
+    expect(closedCredits.data.length).lessThan(credits.data.length);
+    const creditsSpace = BigInt(closedCredits.data.length);
+    expect(BigInt(closedCredits.lamports)).equal(
+      svm.minimumBalanceForRentExemption(creditsSpace)
+    );
+
+    expect(svm.getAccount(entryPda)).equal(null);
+    expect(
+      Number(svm.getBalance(signers[loserId].publicKey) - balance)
+    ).greaterThan(entryLamports + rowRent - 10_000);
+  });
+
+  it("close a winning entry after it is claimed", async () => {
+    svm.expireBlockhash();
+    const winnerId = winnerIds[0];
+    sendSvmTransaction(svm, signers[winnerId], await getClaimTx(winnerId));
+
+    const tx = await getCloseEntryTx(winnerId);
+    sendSvmTransaction(svm, signers[winnerId], tx);
+    expect(svm.getAccount(getEntryPda(signers[winnerId]))).equal(null);
+
+    // The claim cannot be replayed once the entry is gone
+    svm.expireBlockhash();
+    const claimTx = await getClaimTx(winnerId);
+    expect(() =>
+      sendSvmTransaction(svm, signers[winnerId], claimTx)
+    ).to.throw();
+  });
+
+  it("close the contest once every prize is paid", async () => {
+    for (const winnerId of winnerIds.slice(1)) {
+      sendSvmTransaction(svm, signers[winnerId], await getClaimTx(winnerId));
+    }
+
+    // Keeper bounties are owed too
+    const unpaidTx = await getCloseContestTx(0);
+    expect(() => sendSvmTransaction(svm, signers[0], unpaidTx)).to.throw();
+
+    const { tx: bountiesTx } = await getPayKeeperBountiesTx({
+      svm,
+      program: pg,
+      signer: signers[1],
+      mint,
+      contestPda,
+      programTokenAccountPda,
+      pricePosterTokenAccount: signerTokenAccounts[0].address,
+      resolverTokenAccount: signerTokenAccounts[0].address,
+    });
+    sendSvmTransaction(svm, signers[1], bountiesTx);
+
+    // Entries still holding their credit row rent must be closed first
+    svm.expireBlockhash();
+    const openTx = await getCloseContestTx(0);
+    expect(() => sendSvmTransaction(svm, signers[0], openTx)).to.throw();
+
+    // The creator closes the remaining entries on behalf of their users
+    const remainingIds = [...winnerIds.slice(1), ...loserIds.slice(1)];
+    for (const i of remainingIds) {
+      const balance = svm.getBalance(signers[i].publicKey);
+      sendSvmTransaction(svm, signers[0], await getCloseEntryTx(0, i));
+      expect(svm.getAccount(getEntryPda(signers[i]))).equal(null);
+      expect(svm.getBalance(signers[i].publicKey) > balance).equal(true);
+    }
+
+    // Only the creator can close the contest
+    const otherTx = await getCloseContestTx(1);
+    expect(() => sendSvmTransaction(svm, signers[1], otherTx)).to.throw();
+
+    svm.expireBlockhash();
+    const tx = await getCloseContestTx(0);
+    const info = sendSvmTransaction(svm, signers[0], tx);
+    expect(parseEvents(pg, info).map((v) => v.name)).deep.equal([
+      "contestClosed",
+    ]);
+    expect(svm.getAccount(contestPda)).equal(null);
+    expect(svm.getAccount(contestCreditsPda)).equal(null);
+  });
+});
@@ -5,10 +5,12 @@ import { Program } from "@coral-xyz/anchor";
 import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
 import { fixtureInitialization } from "../fixtures/svm";
 import {
+  SEED_FEED_REGISTRY,
   SEED_TOKEN_DRAFT_CONTEST,
   SEED_TOKEN_DRAFT_CONTEST_CREDITS,
 } from "../helpers/constants";
 import {
+  getFeedAccountMetas,
   hexToBase58,
   pythPriceFeedIds,
   sendSvmTransaction,
@@ -65,6 +67,10 @@ describe("create", () => {
       [SEED_TOKEN_DRAFT_CONTEST_CREDITS, contestPda.toBuffer()],
       programId
     );
+    const [feedRegistryPda] = PublicKey.findProgramAddressSync(
+      [SEED_FEED_REGISTRY],
+      programId
+    );
 
     const currentTime = Math.floor(Date.now() / 1000);
     const startTime = new BN(currentTime + 60 * 60); // 1 hour from now
@@ -92,11 +98,7 @@ describe("create", () => {
       contestMetadata: contestMetadataPda,
       contest: contestPda,
       contestCredits: contestCreditsPda,
-      feed0: feedAccounts[0],
-      feed1: feedAccounts[1] || null,
-      feed2: feedAccounts[2] || null,
-      feed3: feedAccounts[3] || null,
-      feed4: feedAccounts[4] || null,
+      feedRegistry: feedRegistryPda,
     };
 
     const tx = await pg.methods
@@ -106,9 +108,17 @@ describe("create", () => {
         entryFee,
         maxEntries,
         tokenFeedIds,
-        Buffer.from(winnerRewardAllocation)
+        Buffer.from(winnerRewardAllocation),
+        { public: {} },
+        null,
+        false,
+        null,
+        null,
+        [],
+        null
       )
       .accounts(accounts)
+      .remainingAccounts(getFeedAccountMetas(feedAccounts))
       .transaction();
 
     sendSvmTransaction(svm, signer, tx);
@@ -0,0 +1,294 @@
+import { BN, Program, utils, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account, unpackAccount } from "@solana/spl-token";
+import { HermesClient } from "@pythnetwork/hermes-client";
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { Protocol } from "../../target/types/protocol";
+import { fixtureWithContest } from "../fixtures/svm";
+import {
+  getEnterContestTx,
+  getPostPricesTxs,
+  getResolveContestTx,
+  ONE_DAY,
+  ONE_HOUR,
+  parseEvents,
+  pythPriceFeedIds,
+  SEED_TOKEN_DRAFT_CONTEST_ENTRY,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { setSvmTimeTo } from "../helpers/time";
+
+const DISTRIBUTE_REWARDS_BOUNTY_BPS = 50;
+
+describe("distribute", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let contestMetadataPda: web3.PublicKey;
+  let contestCreditsPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[] = [];
+
+  let pythSolanaReceiver: PythSolanaReceiver;
+  let priceServiceConnection: HermesClient;
+  let winnerIds: number[];
+  let rewardAmounts: BN[];
+  const rewardAllocation = [75, 25];
+  const numEntries = 4;
+
+  const getEntryPda = (signer: web3.Keypair) =>
+    web3.PublicKey.findProgramAddressSync(
+      [
+        SEED_TOKEN_DRAFT_CONTEST_ENTRY,
+        contestPda.toBuffer(),
+        signer.publicKey.toBuffer(),
+      ],
+      pg.programId
+    )[0];
+
+  const getBalance = (tokenAccount: Account) => {
+    const accInfo = svm.getAccount(tokenAccount.address);
+    return new BN(
+      unpackAccount(tokenAccount.address, accInfo as any).amount.toString()
+    );
+  };
+
+  const getDistributeTx = async (keeperId: number, entryIds: number[]) => {
+    const keeper = signers[keeperId];
+    const remainingAccounts = entryIds.flatMap((i) => [
+      { pubkey: getEntryPda(signers[i]), isSigner: false, isWritable: true },
+      {
+        pubkey: signerTokenAccounts[i].address,
+        isSigner: false,
+        isWritable: true,
+      },
+    ]);
+
+    const accounts = {
+      signer: keeper.publicKey,
+      contest: contestPda,
+      contestMetadata: contestMetadataPda,
+      mint,
+      programTokenAccount: programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[keeperId].address,
+      tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+    };
+    const ix = await pg.methods
+      .distributeTokenDraftContestRewards()
+      .accounts(accounts)
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+
+    const msg = new web3.TransactionMessage({
+      payerKey: keeper.publicKey,
+      instructions: [ix],
+      recentBlockhash: svm.latestBlockhash(),
+    }).compileToV0Message();
+    return new web3.VersionedTransaction(msg);
+  };
+
+  const getClaimTx = async (winnerId: number) => {
+    const signer = signers[winnerId];
+    const accounts = {
+      signer: signer.publicKey,
+      config: configPda,
+      contest: contestPda,
+      contestMetadata: contestMetadataPda,
+      contestEntry: getEntryPda(signer),
+      mint,
+      programTokenAccount: programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[winnerId].address,
+      tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+    };
+    const ix = await pg.methods
+      .claimTokenDraftContest()
+      .accounts(accounts)
+      .instruction();
+
+    const msg = new web3.TransactionMessage({
+      payerKey: signer.publicKey,
+      instructions: [ix],
+      recentBlockhash: svm.latestBlockhash(),
+    }).compileToV0Message();
+    return new web3.VersionedTransaction(msg);
+  };
+
+  before(async () => {
+    const currentTime = Math.floor(Date.now() / 1000);
+    const startTime = currentTime - ONE_DAY; // 1 day ago
+    const endTime = startTime + ONE_HOUR; // 1 hour from start
+    const contestParams = {
+      startTime,
+      endTime,
+      entryFee: BigInt(10 * UNITS_PER_USDC),
+      maxEntries: 100,
+      priceFeedIds: [pythPriceFeedIds.bonk, pythPriceFeedIds.popcat],
+      rewardAllocation,
+    };
+
+    const res = await fixtureWithContest({ contestParams });
+    svm = res.svm;
+    pg = res.program;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestMetadataPda = res.contestMetadataPda;
+    contestCreditsPda = res.contestCreditsPda;
+    contestPda = res.contestPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signers = res.signers;
+    signerTokenAccounts = res.signerTokenAccounts;
+    pythSolanaReceiver = res.pythSolanaReceiver;
+    priceServiceConnection = res.priceServiceConnection;
+
+    const creditAllocations = [
+      [25, 75],
+      [50, 50],
+      [40, 60],
+      [75, 25],
+    ];
+    for (let i = 0; i < numEntries; i++) {
+      const { tx } = await getEnterContestTx({
+        svm,
+        signer: signers[i],
+        program: pg,
+        configPda,
+        contestPda,
+        mint,
+        programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[i],
+        creditAllocation: creditAllocations[i],
+      });
+      sendSvmTransaction(svm, signers[i], tx);
+    }
+
+    setSvmTimeTo(svm, contestParams.startTime + 1);
+
+    const { txs: txsPostPrices } = await getPostPricesTxs({
+      svm,
+      program: pg,
+      signer: signers[0],
+      contestPda,
+      pythSolanaReceiver,
+      hermesClient: priceServiceConnection,
+    });
+    for (const tx of txsPostPrices) {
+      sendSvmTransaction(svm, signers[0], tx);
+    }
+
+    setSvmTimeTo(svm, contestParams.endTime + 1);
+
+    const { txs: txsResolve } = await getResolveContestTx({
+      svm,
+      program: pg,
+      signer: signers[0],
+      mint,
+      contestPda,
+      contestCreditsPda,
+      contestMetadataPda,
+      programTokenAccountPda,
+      hermesClient: priceServiceConnection,
+      pythSolanaReceiver,
+    });
+    for (const tx of txsResolve) {
+      sendSvmTransaction(svm, signers[0], tx);
+    }
+
+    const contest = pg.coder.accounts.decode(
+      "tokenDraftContest",
+      Buffer.from(svm.getAccount(contestPda).data)
+    );
+    const contestMetadata = pg.coder.accounts.decode(
+      "contestMetadata",
+      Buffer.from(svm.getAccount(contestMetadataPda).data)
+    );
+    winnerIds = contest.winnerIds;
+
+    const totalPoolAmount = new BN(contestParams.entryFee.toString()).mul(
+      new BN(numEntries)
+    );
+    const feeAmount = totalPoolAmount
+      .mul(new BN(contestMetadata.tokenDraftContestFeePercent))
+      .div(new BN(100));
+    rewardAmounts = rewardAllocation.map((alloc) =>
+      totalPoolAmount.sub(feeAmount).mul(new BN(alloc)).div(new BN(100))
+    );
+  });
+
+  it("reject paying an entry that did not win", async () => {
+    const loserId = [...Array(numEntries).keys()].find(
+      (i) => !winnerIds.includes(i)
+    );
+    const tx = await getDistributeTx(9, [loserId]);
+    expect(() => sendSvmTransaction(svm, signers[9], tx)).to.throw();
+  });
+
+  it("pay a winner and send the bounty to the keeper", async () => {
+    const winnerId = winnerIds[0];
+    const winnerBalance = getBalance(signerTokenAccounts[winnerId]);
+    const keeperBalance = getBalance(signerTokenAccounts[9]);
+
+    const tx = await getDistributeTx(9, [winnerId]);
+    const info = sendSvmTransaction(svm, signers[9], tx);
+
+    const bounty = rewardAmounts[0]
+      .mul(new BN(DISTRIBUTE_REWARDS_BOUNTY_BPS))
+      .div(new BN(10_000));
+    expect(
+      getBalance(signerTokenAccounts[winnerId]).sub(winnerBalance).toString()
+    ).equal(rewardAmounts[0].sub(bounty).toString());
+    expect(
+      getBalance(signerTokenAccounts[9]).sub(keeperBalance).toString()
+    ).equal(bounty.toString());
+
+    const contestEntry = pg.coder.accounts.decode(
+      "tokenDraftContestEntry",
+      Buffer.from(svm.getAccount(getEntryPda(signers[winnerId])).data)
+    );
+    expect(contestEntry.hasClaimed).equal(true);
+
+    const events = parseEvents(pg, info);
+    expect(events.map((v) => v.name)).deep.equal([
+      "rewardClaimed",
+      "rewardsDistributed",
+    ]);
+    expect(events[1].data.numPaid).equal(1);
+    expect(events[1].data.bountyAmount.toString()).equal(bounty.toString());
+  });
+
+  it("reject claiming a reward that was already distributed", async () => {
+    const winnerId = winnerIds[0];
+    const tx = await getClaimTx(winnerId);
+    expect(() => sendSvmTransaction(svm, signers[winnerId], tx)).to.throw();
+  });
+
+  it("skip winners that already claimed", async () => {
+    const winnerId = winnerIds[1];
+    sendSvmTransaction(svm, signers[winnerId], await getClaimTx(winnerId));
+
+    const winnerBalances = winnerIds.map((i) =>
+      getBalance(signerTokenAccounts[i])
+    );
+    const keeperBalance = getBalance(signerTokenAccounts[9]);
+
+    const tx = await getDistributeTx(9, winnerIds);
+    const info = sendSvmTransaction(svm, signers[9], tx);
+
+    winnerIds.forEach((i, j) => {
+      expect(getBalance(signerTokenAccounts[i]).toString()).equal(
+        winnerBalances[j].toString()
+      );
+    });
+    expect(getBalance(signerTokenAccounts[9]).toString()).equal(
+      keeperBalance.toString()
+    );
+
+    const events = parseEvents(pg, info);
+    expect(events.map((v) => v.name)).deep.equal(["rewardsDistributed"]);
+    expect(events[0].data.numPaid).equal(0);
+  });
+});
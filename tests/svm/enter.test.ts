@@ -91,9 +91,8 @@ describe("enter", () => {
     };
 
     const creditAllocation = [35, 65];
-    const creditAllocationInput = Buffer.from(creditAllocation);
     const ixs = await pg.methods
-      .enterTokenDraftContest(creditAllocationInput)
+      .enterTokenDraftContest(creditAllocation, [], null)
       .accounts(accounts)
       .instruction();
     const msg = new web3.TransactionMessage({
@@ -0,0 +1,92 @@
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { web3 } from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Account } from "@solana/spl-token";
+import { fixtureWithContest } from "../fixtures/svm";
+import {
+  getEnterContestTx,
+  pythPriceFeedIds,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { Protocol } from "../../target/types/protocol";
+
+describe("enterShort", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[];
+
+  before(async () => {
+    const currentTime = Math.floor(Date.now() / 1000);
+    const startTime = currentTime + 60 * 60; // 1 hour from now
+    const endTime = startTime + 60 * 60 * 24; // 1 day from now
+    const contestParams = {
+      startTime,
+      endTime,
+      entryFee: BigInt(10 * UNITS_PER_USDC),
+      maxEntries: 100,
+      priceFeedIds: [pythPriceFeedIds.bonk, pythPriceFeedIds.popcat],
+      rewardAllocation: [50, 50],
+      allowShort: true,
+    };
+    const res = await fixtureWithContest({ contestParams, numSigners: 10 });
+
+    pg = res.program;
+    svm = res.svm;
+    signers = res.signers;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestPda = res.contestPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signerTokenAccounts = res.signerTokenAccounts;
+  });
+
+  it("reject signed credits not summing to the total", async () => {
+    const signer = signers[1];
+    const { tx } = await getEnterContestTx({
+      svm,
+      program: pg,
+      signer,
+      configPda,
+      contestPda,
+      mint,
+      programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[1],
+      creditAllocation: [-50, 60],
+    });
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+
+  it("enter a long/short contest with a shorted token", async () => {
+    const signer = signers[0];
+    const { tx, contestEntryPda } = await getEnterContestTx({
+      svm,
+      program: pg,
+      signer,
+      configPda,
+      contestPda,
+      mint,
+      programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[0],
+      creditAllocation: [-40, 60],
+    });
+
+    sendSvmTransaction(svm, signer, tx);
+
+    const contestEntryAccInfo = svm.getAccount(contestEntryPda);
+    const contestEntry = pg.coder.accounts.decode(
+      "tokenDraftContestEntry",
+      Buffer.from(contestEntryAccInfo.data)
+    );
+    expect(contestEntry.user.toBase58()).equal(signer.publicKey.toBase58());
+    expect([...contestEntry.creditAllocation]).deep.equal([-40, 60]);
+  });
+});
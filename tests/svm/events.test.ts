@@ -0,0 +1,99 @@
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { Program, web3 } from "@coral-xyz/anchor";
+import { Account } from "@solana/spl-token";
+import { fixtureWithContest } from "../fixtures/svm";
+import {
+  ContestParams,
+  getEnterContestTx,
+  hexToBase58,
+  parseEvents,
+  pythPriceFeedIds,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { Protocol } from "../../target/types/protocol";
+
+describe("events", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[];
+  let contestParams: ContestParams;
+  let createTxInfo: ReturnType<typeof sendSvmTransaction>;
+
+  before(async () => {
+    const currentTime = Math.floor(Date.now() / 1000);
+    const startTime = currentTime + 60 * 60; // 1 hour from now
+    const endTime = startTime + 60 * 60 * 24; // 1 day from now
+    contestParams = {
+      startTime,
+      endTime,
+      entryFee: BigInt(10 * UNITS_PER_USDC),
+      maxEntries: 100,
+      priceFeedIds: [pythPriceFeedIds.bonk, pythPriceFeedIds.popcat],
+      rewardAllocation: [50, 50],
+    };
+    const res = await fixtureWithContest({ contestParams, numSigners: 2 });
+
+    pg = res.program;
+    svm = res.svm;
+    signers = res.signers;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestPda = res.contestPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signerTokenAccounts = res.signerTokenAccounts;
+    createTxInfo = res.txInfo;
+  });
+
+  it("emit an event when a contest is created", async () => {
+    const events = parseEvents(pg, createTxInfo);
+    expect(events.map((v) => v.name)).deep.equal(["contestCreated"]);
+
+    const { data } = events[0];
+    expect(data.contest.toBase58()).equal(contestPda.toBase58());
+    expect(data.creator.toBase58()).equal(signers[0].publicKey.toBase58());
+    expect(data.startTime.toNumber()).equal(contestParams.startTime);
+    expect(data.endTime.toNumber()).equal(contestParams.endTime);
+    expect(data.tokenFeedIds.map((v) => v.toBase58())).deep.equal(
+      contestParams.priceFeedIds.map(hexToBase58)
+    );
+    expect([...data.rewardAllocation]).deep.equal(
+      contestParams.rewardAllocation
+    );
+  });
+
+  it("emit an event when an entry is submitted", async () => {
+    const signer = signers[1];
+    const creditAllocation = [35, 65];
+    const { tx, contestEntryPda } = await getEnterContestTx({
+      svm,
+      program: pg,
+      signer,
+      configPda,
+      contestPda,
+      mint,
+      programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[1],
+      creditAllocation,
+    });
+    const txInfo = sendSvmTransaction(svm, signer, tx);
+
+    const events = parseEvents(pg, txInfo);
+    expect(events.map((v) => v.name)).deep.equal(["entrySubmitted"]);
+
+    const { data } = events[0];
+    expect(data.contest.toBase58()).equal(contestPda.toBase58());
+    expect(data.entry.toBase58()).equal(contestEntryPda.toBase58());
+    expect(data.user.toBase58()).equal(signer.publicKey.toBase58());
+    expect(data.entryId).equal(0);
+    expect([...data.creditAllocation]).deep.equal(creditAllocation);
+    expect(data.captain).equal(null);
+  });
+});
@@ -0,0 +1,107 @@
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { Program, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { fixtureInitialization } from "../fixtures/svm";
+import {
+  getCreateContestTx,
+  hexToBase58,
+  parseEvents,
+  pythPriceFeedIds,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { Protocol } from "../../target/types/protocol";
+
+const { PublicKey } = web3;
+
+describe("feedRegistry", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+
+  let contestMetadataPda: web3.PublicKey;
+  let feedRegistryPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let pythSolanaReceiver: PythSolanaReceiver;
+
+  before(async () => {
+    const res = await fixtureInitialization({ numSigners: 10 });
+
+    pg = res.program;
+    svm = res.svm;
+    signers = res.signers;
+    contestMetadataPda = res.contestMetadataPda;
+    feedRegistryPda = res.feedRegistryPda;
+    pythSolanaReceiver = res.pythSolanaReceiver;
+  });
+
+  it("list registered feeds", async () => {
+    const feedRegistryAccInfo = svm.getAccount(feedRegistryPda);
+    const feedRegistry = pg.coder.accounts.decode(
+      "feedRegistry",
+      Buffer.from(feedRegistryAccInfo.data)
+    );
+
+    const feedIds = Object.values(pythPriceFeedIds);
+    expect(feedRegistry.curator.toBase58()).equal(
+      signers[0].publicKey.toBase58()
+    );
+    expect(feedRegistry.feeds.length).equal(feedIds.length);
+    for (let i = 0; i < feedIds.length; i++) {
+      expect(feedRegistry.feeds[i].feedId.toBase58()).equal(
+        hexToBase58(feedIds[i])
+      );
+      expect(feedRegistry.feeds[i].isEnabled).equal(true);
+    }
+  });
+
+  it("reject a contest with a disabled feed", async () => {
+    const signer = signers[0];
+
+    const ix = await pg.methods
+      .updateRegistryFeed(
+        new PublicKey(hexToBase58(pythPriceFeedIds.wif)),
+        0,
+        false
+      )
+      .accounts({ signer: signer.publicKey, feedRegistry: feedRegistryPda })
+      .instruction();
+    const msg = new web3.TransactionMessage({
+      payerKey: signer.publicKey,
+      instructions: [ix],
+      recentBlockhash: svm.latestBlockhash(),
+    }).compileToV0Message();
+    const info = sendSvmTransaction(
+      svm,
+      signer,
+      new web3.VersionedTransaction(msg)
+    );
+
+    const events = parseEvents(pg, info);
+    expect(events.map((v) => v.name)).deep.equal(["registryFeedUpdated"]);
+    expect(events[0].data.feedId.toBase58()).equal(
+      hexToBase58(pythPriceFeedIds.wif)
+    );
+    expect(events[0].data.isEnabled).equal(false);
+
+    const currentTime = Math.floor(Date.now() / 1000);
+    const startTime = currentTime + 60 * 60; // 1 hour from now
+    const { tx } = await getCreateContestTx({
+      svm,
+      program: pg,
+      signer,
+      contestMetadataPda,
+      pythSolanaReceiver,
+      contestParams: {
+        startTime,
+        endTime: startTime + 60 * 60,
+        entryFee: BigInt(10 * UNITS_PER_USDC),
+        maxEntries: 100,
+        priceFeedIds: [pythPriceFeedIds.bonk, pythPriceFeedIds.wif],
+        rewardAllocation: [100],
+      },
+    });
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+});
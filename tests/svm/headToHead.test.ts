@@ -0,0 +1,209 @@
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { BN, Program, utils, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account, unpackAccount } from "@solana/spl-token";
+import { fixtureInitialization } from "../fixtures/svm";
+import {
+  getAcceptHeadToHeadTx,
+  getCreateHeadToHeadTx,
+  parseEvents,
+  pythPriceFeedIds,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { setSvmTimeTo } from "../helpers/time";
+import { Protocol } from "../../target/types/protocol";
+
+describe("headToHead", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let contestMetadataPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[];
+  let pythSolanaReceiver: PythSolanaReceiver;
+
+  const stake = BigInt(10 * UNITS_PER_USDC);
+  const currentTime = Math.floor(Date.now() / 1000);
+  const startTime = currentTime + 60 * 60; // 1 hour from now
+  const endTime = startTime + 60 * 60 * 24; // 1 day from now
+  const pricePostingWindow = 15 * 60;
+
+  const getBalance = (tokenAccount: Account) => {
+    const accInfo = svm.getAccount(tokenAccount.address);
+    return new BN(
+      unpackAccount(tokenAccount.address, accInfo as any).amount.toString()
+    );
+  };
+
+  const getRefundTx = async (signerId: number) => {
+    const signer = signers[signerId];
+    const ix = await pg.methods
+      .refundHeadToHeadContest()
+      .accounts({
+        signer: signer.publicKey,
+        config: configPda,
+        contest: contestPda,
+        mint,
+        programTokenAccount: programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[signerId].address,
+        tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+    const msg = new web3.TransactionMessage({
+      payerKey: signer.publicKey,
+      instructions: [ix],
+      recentBlockhash: svm.latestBlockhash(),
+    }).compileToV0Message();
+    return new web3.VersionedTransaction(msg);
+  };
+
+  before(async () => {
+    const res = await fixtureInitialization({ numSigners: 10 });
+
+    pg = res.program;
+    svm = res.svm;
+    signers = res.signers;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestMetadataPda = res.contestMetadataPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signerTokenAccounts = res.signerTokenAccounts;
+    pythSolanaReceiver = res.pythSolanaReceiver;
+  });
+
+  it("create a head-to-head duel", async () => {
+    const signer = signers[0];
+
+    const res = await getCreateHeadToHeadTx({
+      svm,
+      program: pg,
+      signer,
+      configPda,
+      contestMetadataPda,
+      mint,
+      programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[0],
+      pythSolanaReceiver,
+      duelParams: {
+        startTime,
+        endTime,
+        stake,
+        priceFeedIds: [pythPriceFeedIds.bonk, pythPriceFeedIds.popcat],
+        creditAllocation: [30, 70],
+      },
+    });
+    contestPda = res.contestPda;
+
+    const info = sendSvmTransaction(svm, signer, res.tx);
+    const events = parseEvents(pg, info);
+    expect(events.map((v) => v.name)).deep.equal(["headToHeadContestCreated"]);
+    expect(events[0].data.stake.toString()).equal(stake.toString());
+
+    const contestAccInfo = svm.getAccount(contestPda);
+    const contest = pg.coder.accounts.decode(
+      "headToHeadContest",
+      Buffer.from(contestAccInfo.data)
+    );
+    expect(contest.challenger.toBase58()).equal(signer.publicKey.toBase58());
+    expect(contest.opponent).equal(null);
+    expect(contest.stake.toString()).equal(stake.toString());
+    expect([...contest.challengerAllocation]).deep.equal([30, 70]);
+  });
+
+  it("reject accepting your own duel", async () => {
+    const signer = signers[0];
+    const { tx } = await getAcceptHeadToHeadTx({
+      svm,
+      program: pg,
+      signer,
+      configPda,
+      contestPda,
+      mint,
+      programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[0],
+      creditAllocation: [50, 50],
+    });
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+
+  it("accept a head-to-head duel", async () => {
+    const signer = signers[1];
+    const { tx } = await getAcceptHeadToHeadTx({
+      svm,
+      program: pg,
+      signer,
+      configPda,
+      contestPda,
+      mint,
+      programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[1],
+      creditAllocation: [80, 20],
+    });
+
+    const info = sendSvmTransaction(svm, signer, tx);
+    expect(parseEvents(pg, info).map((v) => v.name)).deep.equal([
+      "headToHeadContestAccepted",
+    ]);
+
+    const contestAccInfo = svm.getAccount(contestPda);
+    const contest = pg.coder.accounts.decode(
+      "headToHeadContest",
+      Buffer.from(contestAccInfo.data)
+    );
+    expect(contest.opponent.toBase58()).equal(signer.publicKey.toBase58());
+    expect([...contest.opponentAllocation]).deep.equal([80, 20]);
+  });
+
+  it("reject cancelling an accepted duel", async () => {
+    const signer = signers[0];
+    const tx = await pg.methods
+      .cancelHeadToHeadContest()
+      .accounts({
+        signer: signer.publicKey,
+        config: configPda,
+        contest: contestPda,
+        mint,
+        programTokenAccount: programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[0].address,
+        tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+      })
+      .transaction();
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+
+  it("reject refunding while start prices can still be posted", async () => {
+    setSvmTimeTo(svm, startTime + 1);
+    const tx = await getRefundTx(0);
+    expect(() => sendSvmTransaction(svm, signers[0], tx)).to.throw();
+  });
+
+  it("refund both stakes once the posting window is missed", async () => {
+    setSvmTimeTo(svm, startTime + pricePostingWindow + 1);
+    svm.expireBlockhash();
+    for (const signerId of [0, 1]) {
+      const balance = getBalance(signerTokenAccounts[signerId]);
+      const tx = await getRefundTx(signerId);
+      const info = sendSvmTransaction(svm, signers[signerId], tx);
+
+      const events = parseEvents(pg, info);
+      expect(events.map((v) => v.name)).deep.equal(["playerRefunded"]);
+      expect(events[0].data.amount.toString()).equal(stake.toString());
+
+      expect(
+        getBalance(signerTokenAccounts[signerId]).sub(balance).toString()
+      ).equal(stake.toString());
+    }
+
+    svm.expireBlockhash();
+    const replayTx = await getRefundTx(0);
+    expect(() => sendSvmTransaction(svm, signers[0], replayTx)).to.throw();
+  });
+});
@@ -0,0 +1,273 @@
+import { BN, Program, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account, unpackAccount } from "@solana/spl-token";
+import { HermesClient } from "@pythnetwork/hermes-client";
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { Protocol } from "../../target/types/protocol";
+import { fixtureWithContest } from "../fixtures/svm";
+import {
+  getEnterContestTx,
+  getPayKeeperBountiesTx,
+  getPostPricesTxs,
+  getResolveContestTx,
+  KEEPER_BOUNTY_BPS,
+  ONE_DAY,
+  ONE_HOUR,
+  parseEvents,
+  pythPriceFeedIds,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { setSvmTimeTo } from "../helpers/time";
+
+describe("keeperBounty", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let contestMetadataPda: web3.PublicKey;
+  let contestCreditsPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[] = [];
+
+  let pythSolanaReceiver: PythSolanaReceiver;
+  let priceServiceConnection: HermesClient;
+  let endTime: number;
+  const numEntries = 4;
+  const posterId = 8;
+  const resolverId = 9;
+
+  const getBalance = (tokenAccount: Account) => {
+    const accInfo = svm.getAccount(tokenAccount.address);
+    return new BN(
+      unpackAccount(tokenAccount.address, accInfo as any).amount.toString()
+    );
+  };
+
+  const decodeContest = () =>
+    pg.coder.accounts.decode(
+      "tokenDraftContest",
+      Buffer.from(svm.getAccount(contestPda).data)
+    );
+
+  before(async () => {
+    const currentTime = Math.floor(Date.now() / 1000);
+    const startTime = currentTime - ONE_DAY; // 1 day ago
+    endTime = startTime + ONE_HOUR; // 1 hour from start
+    const contestParams = {
+      startTime,
+      endTime,
+      entryFee: BigInt(10 * UNITS_PER_USDC),
+      maxEntries: 100,
+      priceFeedIds: [pythPriceFeedIds.bonk, pythPriceFeedIds.popcat],
+      rewardAllocation: [75, 25],
+    };
+
+    const res = await fixtureWithContest({ contestParams });
+    svm = res.svm;
+    pg = res.program;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestMetadataPda = res.contestMetadataPda;
+    contestCreditsPda = res.contestCreditsPda;
+    contestPda = res.contestPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signers = res.signers;
+    signerTokenAccounts = res.signerTokenAccounts;
+    pythSolanaReceiver = res.pythSolanaReceiver;
+    priceServiceConnection = res.priceServiceConnection;
+
+    const creditAllocations = [
+      [25, 75],
+      [50, 50],
+      [40, 60],
+      [75, 25],
+    ];
+    for (let i = 0; i < numEntries; i++) {
+      const { tx } = await getEnterContestTx({
+        svm,
+        signer: signers[i],
+        program: pg,
+        configPda,
+        contestPda,
+        mint,
+        programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[i],
+        creditAllocation: creditAllocations[i],
+      });
+      sendSvmTransaction(svm, signers[i], tx);
+    }
+
+    setSvmTimeTo(svm, startTime + 1);
+  });
+
+  it("record the keeper that posts the start prices", async () => {
+    const { txs } = await getPostPricesTxs({
+      svm,
+      program: pg,
+      signer: signers[posterId],
+      contestPda,
+      pythSolanaReceiver,
+      hermesClient: priceServiceConnection,
+    });
+    for (const tx of txs) {
+      sendSvmTransaction(svm, signers[posterId], tx);
+    }
+
+    const contest = decodeContest();
+    expect(contest.pricePoster.toBase58()).equal(
+      signers[posterId].publicKey.toBase58()
+    );
+  });
+
+  it("reject overwriting posted start prices", async () => {
+    svm.expireBlockhash();
+    const { tokenStartPrices } = decodeContest();
+    const { txs } = await getPostPricesTxs({
+      svm,
+      program: pg,
+      signer: signers[resolverId],
+      contestPda,
+      pythSolanaReceiver,
+      hermesClient: priceServiceConnection,
+    });
+    let failed = false;
+    for (const tx of txs) {
+      try {
+        sendSvmTransaction(svm, signers[resolverId], tx);
+      } catch {
+        failed = true;
+      }
+    }
+    expect(failed).equal(true);
+
+    const contest = decodeContest();
+    expect(contest.tokenStartPrices).deep.equal(tokenStartPrices);
+    expect(contest.pricePoster.toBase58()).equal(
+      signers[posterId].publicKey.toBase58()
+    );
+  });
+
+  it("reject end prices published after the end", async () => {
+    setSvmTimeTo(svm, endTime + 120);
+    const { txs } = await getResolveContestTx({
+      svm,
+      program: pg,
+      signer: signers[resolverId],
+      mint,
+      contestPda,
+      contestCreditsPda,
+      contestMetadataPda,
+      programTokenAccountPda,
+      hermesClient: priceServiceConnection,
+      pythSolanaReceiver,
+      timestamp: endTime + 60,
+    });
+    let failed = false;
+    for (const tx of txs) {
+      try {
+        sendSvmTransaction(svm, signers[resolverId], tx);
+      } catch {
+        failed = true;
+      }
+    }
+    expect(failed).equal(true);
+    expect(decodeContest().isResolved).equal(false);
+  });
+
+  it("set aside both bounties when the contest resolves", async () => {
+    svm.expireBlockhash();
+    const { txs } = await getResolveContestTx({
+      svm,
+      program: pg,
+      signer: signers[resolverId],
+      mint,
+      contestPda,
+      contestCreditsPda,
+      contestMetadataPda,
+      programTokenAccountPda,
+      hermesClient: priceServiceConnection,
+      pythSolanaReceiver,
+    });
+    for (const tx of txs) {
+      sendSvmTransaction(svm, signers[resolverId], tx);
+    }
+
+    const contest = decodeContest();
+    const contestMetadata = pg.coder.accounts.decode(
+      "contestMetadata",
+      Buffer.from(svm.getAccount(contestMetadataPda).data)
+    );
+    const feeAmount = contest.entryFee
+      .mul(new BN(contest.numEntries))
+      .mul(new BN(contestMetadata.tokenDraftContestFeePercent))
+      .div(new BN(100));
+    const bountyAmount = feeAmount
+      .mul(new BN(KEEPER_BOUNTY_BPS))
+      .div(new BN(10_000));
+
+    expect(contest.resolver.toBase58()).equal(
+      signers[resolverId].publicKey.toBase58()
+    );
+    expect(contest.keeperBountyAmount.toString()).equal(
+      bountyAmount.toString()
+    );
+    expect(contestMetadata.tokenDraftContestFeeAmount.toString()).equal(
+      feeAmount.sub(bountyAmount.muln(2)).toString()
+    );
+  });
+
+  it("reject paying bounties to the wrong keeper", async () => {
+    const { tx } = await getPayKeeperBountiesTx({
+      svm,
+      program: pg,
+      signer: signers[0],
+      mint,
+      contestPda,
+      programTokenAccountPda,
+      pricePosterTokenAccount: signerTokenAccounts[resolverId].address,
+      resolverTokenAccount: signerTokenAccounts[resolverId].address,
+    });
+    expect(() => sendSvmTransaction(svm, signers[0], tx)).to.throw();
+  });
+
+  it("pay both keepers their bounty once", async () => {
+    const { keeperBountyAmount } = decodeContest();
+    const posterBalance = getBalance(signerTokenAccounts[posterId]);
+    const resolverBalance = getBalance(signerTokenAccounts[resolverId]);
+
+    const getTx = async () =>
+      getPayKeeperBountiesTx({
+        svm,
+        program: pg,
+        signer: signers[0],
+        mint,
+        contestPda,
+        programTokenAccountPda,
+        pricePosterTokenAccount: signerTokenAccounts[posterId].address,
+        resolverTokenAccount: signerTokenAccounts[resolverId].address,
+      });
+    const { tx } = await getTx();
+    const info = sendSvmTransaction(svm, signers[0], tx);
+
+    expect(
+      getBalance(signerTokenAccounts[posterId]).sub(posterBalance).toString()
+    ).equal(keeperBountyAmount.toString());
+    expect(
+      getBalance(signerTokenAccounts[resolverId])
+        .sub(resolverBalance)
+        .toString()
+    ).equal(keeperBountyAmount.toString());
+    expect(decodeContest().hasPaidKeeperBounties).equal(true);
+
+    const events = parseEvents(pg, info);
+    expect(events.map((v) => v.name)).deep.equal(["keeperBountiesPaid"]);
+
+    svm.expireBlockhash();
+    const { tx: replayTx } = await getTx();
+    expect(() => sendSvmTransaction(svm, signers[0], replayTx)).to.throw();
+  });
+});
@@ -0,0 +1,134 @@
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { Program, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account } from "@solana/spl-token";
+import { fixtureInitialization } from "../fixtures/svm";
+import {
+  getCreateOverUnderTx,
+  getEnterOverUnderTx,
+  parseEvents,
+  pythPriceFeedIds,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { Protocol } from "../../target/types/protocol";
+
+describe("overUnder", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let contestMetadataPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[];
+  let pythSolanaReceiver: PythSolanaReceiver;
+
+  before(async () => {
+    const res = await fixtureInitialization({ numSigners: 10 });
+
+    pg = res.program;
+    svm = res.svm;
+    signers = res.signers;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestMetadataPda = res.contestMetadataPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signerTokenAccounts = res.signerTokenAccounts;
+    pythSolanaReceiver = res.pythSolanaReceiver;
+  });
+
+  it("create an over/under contest", async () => {
+    const signer = signers[0];
+    const currentTime = Math.floor(Date.now() / 1000);
+    const startTime = currentTime + 60 * 60; // 1 hour from now
+    const endTime = startTime + 60 * 60 * 24; // 1 day from now
+
+    const res = await getCreateOverUnderTx({
+      svm,
+      program: pg,
+      signer,
+      contestMetadataPda,
+      pythSolanaReceiver,
+      contestParams: {
+        startTime,
+        endTime,
+        priceFeedId: pythPriceFeedIds.wif,
+        strikePrice: 1.5,
+      },
+    });
+    contestPda = res.contestPda;
+
+    const info = sendSvmTransaction(svm, signer, res.tx);
+    expect(parseEvents(pg, info).map((v) => v.name)).deep.equal([
+      "overUnderContestCreated",
+    ]);
+
+    const contestAccInfo = svm.getAccount(contestPda);
+    const contest = pg.coder.accounts.decode(
+      "overUnderContest",
+      Buffer.from(contestAccInfo.data)
+    );
+    expect(contest.creator.toBase58()).equal(signer.publicKey.toBase58());
+    expect(contest.strikePrice).equal(1.5);
+    expect(contest.overAmount.toNumber()).equal(0);
+    expect(contest.underAmount.toNumber()).equal(0);
+  });
+
+  it("stake on both sides of the strike", async () => {
+    const sides = [{ over: {} }, { under: {} }, { over: {} }];
+    const amounts = [10, 25, 5].map((v) => BigInt(v * UNITS_PER_USDC));
+
+    for (let i = 0; i < sides.length; i++) {
+      const { tx } = await getEnterOverUnderTx({
+        svm,
+        program: pg,
+        signer: signers[i],
+        configPda,
+        contestPda,
+        mint,
+        programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[i],
+        side: sides[i],
+        amount: amounts[i],
+      });
+      const info = sendSvmTransaction(svm, signers[i], tx);
+
+      const events = parseEvents(pg, info);
+      expect(events.map((v) => v.name)).deep.equal(["overUnderEntrySubmitted"]);
+      expect(events[0].data.side).deep.equal(sides[i]);
+      expect(events[0].data.amount.toString()).equal(amounts[i].toString());
+    }
+
+    const contestAccInfo = svm.getAccount(contestPda);
+    const contest = pg.coder.accounts.decode(
+      "overUnderContest",
+      Buffer.from(contestAccInfo.data)
+    );
+    expect(contest.overAmount.toString()).equal(
+      (amounts[0] + amounts[2]).toString()
+    );
+    expect(contest.underAmount.toString()).equal(amounts[1].toString());
+  });
+
+  it("reject an empty stake", async () => {
+    const signer = signers[3];
+    const { tx } = await getEnterOverUnderTx({
+      svm,
+      program: pg,
+      signer,
+      configPda,
+      contestPda,
+      mint,
+      programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[3],
+      side: { under: {} },
+      amount: BigInt(0),
+    });
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+});
@@ -0,0 +1,197 @@
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { BN, Program, utils, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account, unpackAccount } from "@solana/spl-token";
+import { fixtureInitialization } from "../fixtures/svm";
+import {
+  getCreateParimutuelTx,
+  getEnterParimutuelTx,
+  parseEvents,
+  pythPriceFeedIds,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { setSvmTimeTo } from "../helpers/time";
+import { Protocol } from "../../target/types/protocol";
+
+describe("parimutuel", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let contestMetadataPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[];
+  let pythSolanaReceiver: PythSolanaReceiver;
+  let contestEntryPdas: web3.PublicKey[] = [];
+
+  const currentTime = Math.floor(Date.now() / 1000);
+  const startTime = currentTime + 60 * 60; // 1 hour from now
+  const endTime = startTime + 60 * 60 * 24; // 1 day from now
+  const pricePostingWindow = 15 * 60;
+  const tokenIndexes = [0, 2, 2];
+  const amounts = [10, 25, 5].map((v) => BigInt(v * UNITS_PER_USDC));
+
+  const getBalance = (tokenAccount: Account) => {
+    const accInfo = svm.getAccount(tokenAccount.address);
+    return new BN(
+      unpackAccount(tokenAccount.address, accInfo as any).amount.toString()
+    );
+  };
+
+  const getCancelTx = async (signerId: number) => {
+    const signer = signers[signerId];
+    return pg.methods
+      .cancelParimutuelContest()
+      .accounts({ signer: signer.publicKey, contest: contestPda })
+      .transaction();
+  };
+
+  const priceFeedIds = [
+    pythPriceFeedIds.bonk,
+    pythPriceFeedIds.popcat,
+    pythPriceFeedIds.wif,
+  ];
+
+  before(async () => {
+    const res = await fixtureInitialization({ numSigners: 10 });
+
+    pg = res.program;
+    svm = res.svm;
+    signers = res.signers;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestMetadataPda = res.contestMetadataPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signerTokenAccounts = res.signerTokenAccounts;
+    pythSolanaReceiver = res.pythSolanaReceiver;
+
+    const { tx, contestPda: contestPda_ } = await getCreateParimutuelTx({
+      svm,
+      program: pg,
+      signer: signers[0],
+      contestMetadataPda,
+      pythSolanaReceiver,
+      contestParams: { startTime, endTime, priceFeedIds },
+    });
+    contestPda = contestPda_;
+
+    sendSvmTransaction(svm, signers[0], tx);
+  });
+
+  it("stake on the tokens of a parimutuel market", async () => {
+    for (let i = 0; i < tokenIndexes.length; i++) {
+      const { tx, contestEntryPda } = await getEnterParimutuelTx({
+        svm,
+        program: pg,
+        signer: signers[i],
+        configPda,
+        contestPda,
+        mint,
+        programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[i],
+        tokenIndex: tokenIndexes[i],
+        amount: amounts[i],
+      });
+      const info = sendSvmTransaction(svm, signers[i], tx);
+
+      const events = parseEvents(pg, info);
+      expect(events.map((v) => v.name)).deep.equal([
+        "parimutuelEntrySubmitted",
+      ]);
+      expect(events[0].data.tokenIndex).equal(tokenIndexes[i]);
+      contestEntryPdas.push(contestEntryPda);
+    }
+
+    const contestAccInfo = svm.getAccount(contestPda);
+    const contest = pg.coder.accounts.decode(
+      "parimutuelContest",
+      Buffer.from(contestAccInfo.data)
+    );
+    const tokenStakeAmounts = contest.tokenStakeAmounts.map((v) =>
+      v.toString()
+    );
+    expect(tokenStakeAmounts).deep.equal([
+      amounts[0].toString(),
+      "0",
+      (amounts[1] + amounts[2]).toString(),
+    ]);
+  });
+
+  it("reject a stake on a token outside the pool", async () => {
+    const signer = signers[3];
+    const { tx } = await getEnterParimutuelTx({
+      svm,
+      program: pg,
+      signer,
+      configPda,
+      contestPda,
+      mint,
+      programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[3],
+      tokenIndex: priceFeedIds.length,
+      amount: BigInt(10 * UNITS_PER_USDC),
+    });
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+
+  it("reject cancelling while start prices can still be posted", async () => {
+    setSvmTimeTo(svm, startTime + 1);
+    const tx = await getCancelTx(9);
+    expect(() => sendSvmTransaction(svm, signers[9], tx)).to.throw();
+  });
+
+  it("refund every stake once the posting window is missed", async () => {
+    setSvmTimeTo(svm, startTime + pricePostingWindow + 1);
+    svm.expireBlockhash();
+    const info = sendSvmTransaction(svm, signers[9], await getCancelTx(9));
+
+    const events = parseEvents(pg, info);
+    expect(events.map((v) => v.name)).deep.equal([
+      "parimutuelContestCancelled",
+    ]);
+    expect(events[0].data.poolAmount.toString()).equal(
+      amounts.reduce((a, b) => a + b).toString()
+    );
+
+    const contest = pg.coder.accounts.decode(
+      "parimutuelContest",
+      Buffer.from(svm.getAccount(contestPda).data)
+    );
+    expect(contest.isResolved).equal(true);
+    expect(contest.winningToken).equal(null);
+
+    for (let i = 0; i < tokenIndexes.length; i++) {
+      const balance = getBalance(signerTokenAccounts[i]);
+      const tx = await pg.methods
+        .claimParimutuelContest()
+        .accounts({
+          signer: signers[i].publicKey,
+          config: configPda,
+          contestMetadata: contestMetadataPda,
+          contest: contestPda,
+          contestEntry: contestEntryPdas[i],
+          mint,
+          programTokenAccount: programTokenAccountPda,
+          signerTokenAccount: signerTokenAccounts[i].address,
+          tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+        })
+        .transaction();
+      const claimInfo = sendSvmTransaction(svm, signers[i], tx);
+
+      expect(getBalance(signerTokenAccounts[i]).sub(balance).toString()).equal(
+        amounts[i].toString()
+      );
+      const claimEvents = parseEvents(pg, claimInfo);
+      expect(claimEvents.map((v) => v.name)).deep.equal(["rewardClaimed"]);
+      expect(claimEvents[0].data.amount.toString()).equal(
+        amounts[i].toString()
+      );
+    }
+  });
+});
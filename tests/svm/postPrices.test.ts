@@ -12,6 +12,7 @@ import { fixtureWithContest } from "../fixtures/svm";
 import { Protocol } from "../../target/types/protocol";
 import {
   ContestParams,
+  getFeedAccountMetas,
   now,
   pythPriceFeedIds,
   sendSvmTransaction,
@@ -105,17 +106,13 @@ describe("postPrices", () => {
         const accounts = {
           signer: signer.publicKey,
           contest: contestPda,
-          feed0: priceUpdateAccounts[0],
-          feed1: priceUpdateAccounts[1] || null,
-          feed2: priceUpdateAccounts[2] || null,
-          feed3: priceUpdateAccounts[3] || null,
-          feed4: priceUpdateAccounts[4] || null,
           tokenProgram: utils.token.TOKEN_PROGRAM_ID,
         };
 
         const txInstruction = await pg.methods
           .postTokenDraftContestPrices()
           .accounts(accounts)
+          .remainingAccounts(getFeedAccountMetas(priceUpdateAccounts))
           .instruction();
 
         const instruction: InstructionWithEphemeralSigners = {
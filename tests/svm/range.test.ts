@@ -0,0 +1,141 @@
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { Program, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account } from "@solana/spl-token";
+import { fixtureInitialization } from "../fixtures/svm";
+import {
+  getCreateRangeContestTx,
+  getEnterRangeContestTx,
+  parseEvents,
+  pythPriceFeedIds,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { Protocol } from "../../target/types/protocol";
+
+describe("range", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let contestMetadataPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let contestPredictionsPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[];
+  let pythSolanaReceiver: PythSolanaReceiver;
+
+  const entryFee = BigInt(5 * UNITS_PER_USDC);
+
+  before(async () => {
+    const res = await fixtureInitialization({ numSigners: 10 });
+
+    pg = res.program;
+    svm = res.svm;
+    signers = res.signers;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestMetadataPda = res.contestMetadataPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signerTokenAccounts = res.signerTokenAccounts;
+    pythSolanaReceiver = res.pythSolanaReceiver;
+  });
+
+  it("create a range prediction contest", async () => {
+    const signer = signers[0];
+    const currentTime = Math.floor(Date.now() / 1000);
+    const startTime = currentTime + 60 * 60; // 1 hour from now
+    const endTime = startTime + 60 * 60 * 24; // 1 day from now
+
+    const res = await getCreateRangeContestTx({
+      svm,
+      program: pg,
+      signer,
+      contestMetadataPda,
+      pythSolanaReceiver,
+      contestParams: {
+        startTime,
+        endTime,
+        entryFee,
+        maxEntries: 2,
+        priceFeedId: pythPriceFeedIds.wif,
+        target: { percentChange: {} },
+        rewardAllocation: [70, 30],
+      },
+    });
+    contestPda = res.contestPda;
+    contestPredictionsPda = res.contestPredictionsPda;
+
+    const info = sendSvmTransaction(svm, signer, res.tx);
+    expect(parseEvents(pg, info).map((v) => v.name)).deep.equal([
+      "rangeContestCreated",
+    ]);
+
+    const contestAccInfo = svm.getAccount(contestPda);
+    const contest = pg.coder.accounts.decode(
+      "rangeContest",
+      Buffer.from(contestAccInfo.data)
+    );
+    expect(contest.creator.toBase58()).equal(signer.publicKey.toBase58());
+    expect(contest.target).deep.equal({ percentChange: {} });
+    expect(contest.startPrice).equal(null);
+    expect([...contest.winnerRewardAllocation]).deep.equal([70, 30]);
+  });
+
+  it("submit predictions", async () => {
+    const predictions = [2.5, -10];
+
+    for (let i = 0; i < predictions.length; i++) {
+      const { tx } = await getEnterRangeContestTx({
+        svm,
+        program: pg,
+        signer: signers[i],
+        configPda,
+        contestPda,
+        mint,
+        programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[i],
+        prediction: predictions[i],
+      });
+      const info = sendSvmTransaction(svm, signers[i], tx);
+
+      const events = parseEvents(pg, info);
+      expect(events.map((v) => v.name)).deep.equal(["rangeEntrySubmitted"]);
+      expect(events[0].data.prediction).equal(predictions[i]);
+    }
+
+    const contestAccInfo = svm.getAccount(contestPda);
+    const contest = pg.coder.accounts.decode(
+      "rangeContest",
+      Buffer.from(contestAccInfo.data)
+    );
+    expect(contest.numEntries).equal(predictions.length);
+
+    const predictionsAccInfo = svm.getAccount(contestPredictionsPda);
+    const contestPredictions = pg.coder.accounts.decode(
+      "rangeContestPredictions",
+      Buffer.from(predictionsAccInfo.data)
+    );
+    expect(contestPredictions.predictions).deep.equal(predictions);
+  });
+
+  it("reject entering a full contest", async () => {
+    const signer = signers[2];
+    const { tx } = await getEnterRangeContestTx({
+      svm,
+      program: pg,
+      signer,
+      configPda,
+      contestPda,
+      mint,
+      programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[2],
+      prediction: 0,
+    });
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+});
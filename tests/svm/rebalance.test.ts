@@ -0,0 +1,125 @@
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { web3 } from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Account } from "@solana/spl-token";
+import { fixtureWithContest } from "../fixtures/svm";
+import {
+  getEnterContestTx,
+  pythPriceFeedIds,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { Protocol } from "../../target/types/protocol";
+
+describe("rebalance", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let contestCreditsPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[];
+
+  const currentTime = Math.floor(Date.now() / 1000);
+  const startTime = currentTime + 60 * 60; // 1 hour from now
+  const endTime = startTime + 60 * 60 * 24; // 1 day from now
+  const rebalanceTimes = [startTime + 60 * 60 * 8, startTime + 60 * 60 * 16];
+
+  before(async () => {
+    const contestParams = {
+      startTime,
+      endTime,
+      entryFee: BigInt(10 * UNITS_PER_USDC),
+      maxEntries: 100,
+      priceFeedIds: [pythPriceFeedIds.bonk, pythPriceFeedIds.popcat],
+      rewardAllocation: [50, 50],
+      rebalanceTimes,
+    };
+    const res = await fixtureWithContest({ contestParams, numSigners: 10 });
+
+    pg = res.program;
+    svm = res.svm;
+    signers = res.signers;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestPda = res.contestPda;
+    contestCreditsPda = res.contestCreditsPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signerTokenAccounts = res.signerTokenAccounts;
+  });
+
+  it("store the rebalance checkpoints", async () => {
+    const contestAccInfo = svm.getAccount(contestPda);
+    const contest = pg.coder.accounts.decode(
+      "tokenDraftContest",
+      Buffer.from(contestAccInfo.data)
+    );
+    expect(contest.rebalanceTimes.map((v) => v.toNumber())).deep.equal(
+      rebalanceTimes
+    );
+    expect(contest.checkpointPrices.length).equal(0);
+  });
+
+  it("enter with the allocation held for every segment", async () => {
+    const signer = signers[0];
+    const { tx } = await getEnterContestTx({
+      svm,
+      program: pg,
+      signer,
+      configPda,
+      contestPda,
+      mint,
+      programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[0],
+      creditAllocation: [40, 60],
+    });
+    sendSvmTransaction(svm, signer, tx);
+
+    const creditsAccInfo = svm.getAccount(contestCreditsPda);
+    const contestCredits = pg.coder.accounts.decode(
+      "tokenDraftContestCredits",
+      Buffer.from(creditsAccInfo.data)
+    );
+    expect([...contestCredits.creditAllocations]).deep.equal([
+      40, 60, 40, 60, 40, 60,
+    ]);
+  });
+
+  it("rebalance from the next checkpoint", async () => {
+    const signer = signers[0];
+    const tx = await pg.methods
+      .rebalanceTokenDraftContestEntry([90, 10])
+      .accounts({
+        signer: signer.publicKey,
+        contest: contestPda,
+      })
+      .transaction();
+    sendSvmTransaction(svm, signer, tx);
+
+    const creditsAccInfo = svm.getAccount(contestCreditsPda);
+    const contestCredits = pg.coder.accounts.decode(
+      "tokenDraftContestCredits",
+      Buffer.from(creditsAccInfo.data)
+    );
+    expect([...contestCredits.creditAllocations]).deep.equal([
+      40, 60, 90, 10, 90, 10,
+    ]);
+  });
+
+  it("reject an invalid rebalance", async () => {
+    const signer = signers[0];
+    const tx = await pg.methods
+      .rebalanceTokenDraftContestEntry([90, 20])
+      .accounts({
+        signer: signer.publicKey,
+        contest: contestPda,
+      })
+      .transaction();
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+});
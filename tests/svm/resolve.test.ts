@@ -9,6 +9,7 @@ import { HermesClient } from "@pythnetwork/hermes-client";
 import {
   ContestParams,
   getEnterContestTx,
+  getFeedAccountMetas,
   getPostPricesTxs,
   pythPriceFeedIds,
   sendSvmTransaction,
@@ -16,7 +17,7 @@ import {
 } from "../helpers";
 import { expect } from "chai";
 import { fixtureWithContest } from "../fixtures/svm";
-import { ONE_DAY, ONE_HOUR } from "../helpers";
+import { KEEPER_BOUNTY_BPS, ONE_DAY, ONE_HOUR } from "../helpers";
 import { LiteSVM } from "litesvm";
 import { Protocol } from "../../target/types/protocol";
 import { setSvmTimeTo } from "../helpers/time";
@@ -151,17 +152,13 @@ describe("resolve", () => {
           contestMetadata: contestMetadataPda,
           mint,
           programTokenAccount: programTokenAccountPda,
-          feed0: priceUpdateAccounts[0],
-          feed1: priceUpdateAccounts[1] || null,
-          feed2: priceUpdateAccounts[2] || null,
-          feed3: priceUpdateAccounts[3] || null,
-          feed4: priceUpdateAccounts[4] || null,
           tokenProgram: utils.token.TOKEN_PROGRAM_ID,
         };
 
         const txInstruction = await pg.methods
           .resolveTokenDraftContest()
           .accounts(accounts)
+          .remainingAccounts(getFeedAccountMetas(priceUpdateAccounts))
           .instruction();
 
         const instruction: InstructionWithEphemeralSigners = {
@@ -216,6 +213,9 @@ describe("resolve", () => {
     const totalPoolAmount = contest.entryFee.mul(new BN(contest.numEntries));
     const feePercent = contestMetadata.tokenDraftContestFeePercent;
     const feeAmount = totalPoolAmount.mul(new BN(feePercent)).div(new BN(100));
+    const bountyAmount = feeAmount
+      .mul(new BN(KEEPER_BOUNTY_BPS))
+      .div(new BN(10_000));
 
     expect(contest.isResolved).equal(true);
     expect(contest.numEntries).equal(numEntries);
@@ -224,8 +224,12 @@ describe("resolve", () => {
     expect(programTokenAccount.amount.toString()).equal(
       totalPoolAmount.toString()
     );
+    expect(contest.keeperBountyAmount.toString()).equal(
+      bountyAmount.toString()
+    );
+    expect(contest.resolver.toBase58()).equal(signers[0].publicKey.toBase58());
     expect(contestMetadata.tokenDraftContestFeeAmount.toString()).equal(
-      feeAmount.toString()
+      feeAmount.sub(bountyAmount.muln(2)).toString()
     );
   });
 });
@@ -0,0 +1,193 @@
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { BN, Program, web3 } from "@coral-xyz/anchor";
+import { fixtureWithContest } from "../fixtures/svm";
+import {
+  parseEvents,
+  pythPriceFeedIds,
+  sendSvmTransaction,
+  SEED_SEASON,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { setSvmTimeTo } from "../helpers/time";
+import { Protocol } from "../../target/types/protocol";
+
+const { PublicKey } = web3;
+
+describe("season", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+
+  let configPda: web3.PublicKey;
+  let contestMetadataPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let seasonPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+
+  const currentTime = Math.floor(Date.now() / 1000);
+  const startTime = currentTime + 60 * 60; // 1 hour from now
+  const endTime = startTime + 60 * 60 * 24; // 1 day from now
+  const seasonEndTime = endTime + 60 * 60 * 24 * 30;
+
+  before(async () => {
+    const res = await fixtureWithContest({
+      contestParams: {
+        startTime,
+        endTime,
+        entryFee: BigInt(5 * UNITS_PER_USDC),
+        maxEntries: 10,
+        priceFeedIds: [pythPriceFeedIds.bonk, pythPriceFeedIds.popcat],
+        rewardAllocation: [50, 30, 20],
+      },
+      numSigners: 10,
+    });
+
+    pg = res.program;
+    svm = res.svm;
+    signers = res.signers;
+    configPda = res.configPda;
+    contestMetadataPda = res.contestMetadataPda;
+    contestPda = res.contestPda;
+
+    [seasonPda] = PublicKey.findProgramAddressSync(
+      [SEED_SEASON, new BN(0).toArrayLike(Buffer, "le", 8)],
+      pg.programId
+    );
+  });
+
+  it("reject a season created by a non-admin", async () => {
+    const signer = signers[1];
+    const tx = await pg.methods
+      .createSeason(
+        new BN(currentTime),
+        new BN(seasonEndTime),
+        [10, 6, 3],
+        20,
+        Buffer.from([60, 40])
+      )
+      .accounts({
+        signer: signer.publicKey,
+        config: configPda,
+        contestMetadata: contestMetadataPda,
+        season: seasonPda,
+      })
+      .transaction();
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+
+  it("create a season", async () => {
+    const signer = signers[0];
+    const tx = await pg.methods
+      .createSeason(
+        new BN(currentTime),
+        new BN(seasonEndTime),
+        [10, 6, 3],
+        20,
+        Buffer.from([60, 40])
+      )
+      .accounts({
+        signer: signer.publicKey,
+        config: configPda,
+        contestMetadata: contestMetadataPda,
+        season: seasonPda,
+      })
+      .transaction();
+
+    const info = sendSvmTransaction(svm, signer, tx);
+
+    const events = parseEvents(pg, info);
+    expect(events.map((v) => v.name)).deep.equal(["seasonCreated"]);
+    expect(events[0].data.season.toBase58()).equal(seasonPda.toBase58());
+
+    const seasonAccInfo = svm.getAccount(seasonPda);
+    const season = pg.coder.accounts.decode(
+      "season",
+      Buffer.from(seasonAccInfo.data)
+    );
+    expect(season.pointsTable).deep.equal([10, 6, 3]);
+    expect(season.feeSharePercent).equal(20);
+    expect([...season.prizeAllocation]).deep.equal([60, 40]);
+    expect(season.leaders.length).equal(0);
+  });
+
+  it("link a contest to the season", async () => {
+    const signer = signers[0];
+    const tx = await pg.methods
+      .addSeasonContest()
+      .accounts({
+        signer: signer.publicKey,
+        config: configPda,
+        season: seasonPda,
+        contest: contestPda,
+      })
+      .transaction();
+
+    const info = sendSvmTransaction(svm, signer, tx);
+
+    const events = parseEvents(pg, info);
+    expect(events.map((v) => v.name)).deep.equal(["seasonContestAdded"]);
+    expect(events[0].data.contest.toBase58()).equal(contestPda.toBase58());
+
+    const contestAccInfo = svm.getAccount(contestPda);
+    const contest = pg.coder.accounts.decode(
+      "tokenDraftContest",
+      Buffer.from(contestAccInfo.data)
+    );
+    expect(contest.season.toBase58()).equal(seasonPda.toBase58());
+    expect(contest.seasonFeeSharePercent).equal(20);
+    expect(contest.seasonScoringPositions).equal(3);
+
+    const seasonAccInfo = svm.getAccount(seasonPda);
+    const season = pg.coder.accounts.decode(
+      "season",
+      Buffer.from(seasonAccInfo.data)
+    );
+    expect(season.numContests).equal(1);
+  });
+
+  it("reject linking a contest twice", async () => {
+    const signer = signers[0];
+    const tx = await pg.methods
+      .addSeasonContest()
+      .accounts({
+        signer: signer.publicKey,
+        config: configPda,
+        season: seasonPda,
+        contest: contestPda,
+      })
+      .transaction();
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+
+  it("reject settling a contest that is not resolved", async () => {
+    const signer = signers[1];
+    const tx = await pg.methods
+      .settleSeasonContest()
+      .accounts({
+        signer: signer.publicKey,
+        season: seasonPda,
+        contest: contestPda,
+      })
+      .transaction();
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+
+  it("reject finalizing while a linked contest is unsettled", async () => {
+    setSvmTimeTo(svm, seasonEndTime + 1);
+
+    const signer = signers[0];
+    const tx = await pg.methods
+      .finalizeSeason()
+      .accounts({
+        signer: signer.publicKey,
+        config: configPda,
+        season: seasonPda,
+      })
+      .transaction();
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+});
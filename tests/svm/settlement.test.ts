@@ -0,0 +1,244 @@
+import { BN, Program, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { HermesClient } from "@pythnetwork/hermes-client";
+import { Account } from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  FailedTransactionMetadata,
+  LiteSVM,
+  TransactionMetadata,
+} from "litesvm";
+import { Protocol } from "../../target/types/protocol";
+import { fixtureWithContest } from "../fixtures/svm";
+import {
+  ContestParams,
+  getEnterContestTx,
+  getPostPricesTxs,
+  getResolveContestTx,
+  KEEPER_BOUNTY_BPS,
+  ONE_DAY,
+  ONE_HOUR,
+  parseEvents,
+  pythPriceFeedIds,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { setSvmTimeTo } from "../helpers/time";
+
+// Both resolve instructions share one settlement engine, so every scenario runs through each
+const variants = [
+  { name: "base layer", er: false },
+  { name: "ephemeral rollup", er: true },
+];
+
+const MAGIC_PROGRAM_ID = new web3.PublicKey(
+  "Magic11111111111111111111111111111111111111"
+);
+const NATIVE_LOADER_ID = new web3.PublicKey(
+  "NativeLoader1111111111111111111111111111111"
+);
+
+const resolvedEvents: Record<string, any[]> = {};
+
+for (const variant of variants) {
+  describe(`settlement (${variant.name})`, () => {
+    let svm: LiteSVM;
+    let pg: Program<Protocol>;
+    let mint: web3.PublicKey;
+    let configPda: web3.PublicKey;
+    let contestMetadataPda: web3.PublicKey;
+    let contestCreditsPda: web3.PublicKey;
+    let contestPda: web3.PublicKey;
+    let programTokenAccountPda: web3.PublicKey;
+    let signers: web3.Keypair[];
+    let signerTokenAccounts: Account[];
+    let pythSolanaReceiver: PythSolanaReceiver;
+    let hermesClient: HermesClient;
+    let contestParams: ContestParams;
+
+    // The magic program only exists inside the rollup, so the ER variant is simulated against a
+    // stand-in program account and its settlement is read from the logs emitted before the commit
+    const resolve = async () => {
+      const { txs } = await getResolveContestTx({
+        svm,
+        program: pg,
+        signer: signers[0],
+        mint,
+        contestPda,
+        contestMetadataPda,
+        contestCreditsPda,
+        programTokenAccountPda,
+        hermesClient,
+        pythSolanaReceiver,
+        er: variant.er,
+      });
+
+      for (const tx of txs.slice(0, -1)) {
+        sendSvmTransaction(svm, signers[0], tx);
+      }
+
+      const tx = txs[txs.length - 1];
+      tx.sign([signers[0]]);
+      const info = variant.er
+        ? svm.simulateTransaction(tx)
+        : svm.sendTransaction(tx);
+      const meta = info instanceof TransactionMetadata ? info : info.meta();
+
+      return { failed: info instanceof FailedTransactionMetadata, meta };
+    };
+
+    const expectAnchorError = (logs: string[], code: string) => {
+      expect(logs.some((v) => v.includes(`Error Code: ${code}.`))).equal(
+        true
+      );
+    };
+
+    before(async () => {
+      const currentTime = Math.floor(Date.now() / 1000);
+      const startTime = currentTime - ONE_DAY;
+      const endTime = startTime + ONE_HOUR;
+      contestParams = {
+        startTime,
+        endTime,
+        entryFee: BigInt(10 * UNITS_PER_USDC),
+        maxEntries: 100,
+        priceFeedIds: [pythPriceFeedIds.bonk, pythPriceFeedIds.popcat],
+        rewardAllocation: [75, 25],
+      };
+
+      const res = await fixtureWithContest({ contestParams, numSigners: 4 });
+      svm = res.svm;
+      pg = res.program;
+      mint = res.mint;
+      configPda = res.configPda;
+      contestMetadataPda = res.contestMetadataPda;
+      contestCreditsPda = res.contestCreditsPda;
+      contestPda = res.contestPda;
+      programTokenAccountPda = res.programTokenAccountPda;
+      signers = res.signers;
+      signerTokenAccounts = res.signerTokenAccounts;
+      pythSolanaReceiver = res.pythSolanaReceiver;
+      hermesClient = res.priceServiceConnection;
+
+      if (variant.er) {
+        svm.setAccount(MAGIC_PROGRAM_ID, {
+          lamports: 1,
+          data: Buffer.alloc(0),
+          owner: NATIVE_LOADER_ID,
+          executable: true,
+        });
+      }
+
+      const creditAllocations = [
+        [25, 75],
+        [50, 50],
+        [90, 10],
+      ];
+      for (let i = 0; i < creditAllocations.length; i++) {
+        const { tx } = await getEnterContestTx({
+          svm,
+          program: pg,
+          configPda,
+          contestPda,
+          mint,
+          programTokenAccountPda,
+          signer: signers[i],
+          signerTokenAccount: signerTokenAccounts[i],
+          creditAllocation: creditAllocations[i],
+        });
+        sendSvmTransaction(svm, signers[i], tx);
+      }
+    });
+
+    it("reject resolving before start prices are posted", async () => {
+      setSvmTimeTo(svm, contestParams.endTime + 1);
+
+      const { failed, meta } = await resolve();
+      expect(failed).equal(true);
+      expectAnchorError(meta.logs(), "ContestPriceNotSet");
+    });
+
+    it("reject resolving before the end time", async () => {
+      setSvmTimeTo(svm, contestParams.startTime + 1);
+      const { txs } = await getPostPricesTxs({
+        svm,
+        program: pg,
+        signer: signers[0],
+        contestPda,
+        pythSolanaReceiver,
+        hermesClient,
+      });
+      for (const tx of txs) {
+        sendSvmTransaction(svm, signers[0], tx);
+      }
+
+      const { failed, meta } = await resolve();
+      expect(failed).equal(true);
+      expectAnchorError(meta.logs(), "ContestNotEnded");
+    });
+
+    it("settle the contest after the end time", async () => {
+      setSvmTimeTo(svm, contestParams.endTime + 1);
+
+      const { failed, meta } = await resolve();
+      const logs = meta.logs();
+      expect(logs.some((v) => v.includes("AnchorError"))).equal(false);
+
+      const events = parseEvents(pg, meta);
+      expect(events.map((v) => v.name)).deep.equal([
+        "pricesPosted",
+        "contestResolved",
+      ]);
+      const { data } = events[1];
+      expect(data.winnerIds.length).equal(
+        contestParams.rewardAllocation.length
+      );
+      expect(data.tokenRois.length).equal(contestParams.priceFeedIds.length);
+
+      const contestMetadataAccInfo = svm.getAccount(contestMetadataPda);
+      const contestMetadata = pg.coder.accounts.decode(
+        "contestMetadata",
+        Buffer.from(contestMetadataAccInfo.data)
+      );
+      const totalPoolAmount = new BN(contestParams.entryFee.toString()).mul(
+        new BN(3)
+      );
+      const grossFeeAmount = totalPoolAmount
+        .mul(new BN(contestMetadata.tokenDraftContestFeePercent))
+        .div(new BN(100));
+      const bountyAmount = grossFeeAmount
+        .mul(new BN(KEEPER_BOUNTY_BPS))
+        .div(new BN(10_000));
+      // Both keeper bounties come out of the fee
+      const feeAmount = grossFeeAmount.sub(bountyAmount.muln(2));
+      expect(data.feeAmount.toString()).equal(feeAmount.toString());
+
+      // Only the base layer persists the settlement, the rollup commits it back on undelegation
+      const contestAccInfo = svm.getAccount(contestPda);
+      const contest = pg.coder.accounts.decode(
+        "tokenDraftContest",
+        Buffer.from(contestAccInfo.data)
+      );
+      expect(failed).equal(variant.er);
+      expect(contest.isResolved).equal(!variant.er);
+      if (!variant.er) {
+        expect(contest.winnerIds).deep.equal(data.winnerIds);
+        expect(contestMetadata.tokenDraftContestFeeAmount.toString()).equal(
+          feeAmount.toString()
+        );
+      }
+
+      resolvedEvents[variant.name] = events;
+    });
+  });
+}
+
+describe("settlement (parity)", () => {
+  it("settle identically on the base layer and the ephemeral rollup", () => {
+    const [base, er] = variants.map((v) =>
+      JSON.stringify(resolvedEvents[v.name])
+    );
+    expect(base).not.equal(undefined);
+    expect(er).equal(base);
+  });
+});
@@ -0,0 +1,305 @@
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { BN, Program, utils, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account, unpackAccount } from "@solana/spl-token";
+import { fixtureInitialization } from "../fixtures/svm";
+import {
+  getCreateSnakeDraftContestTx,
+  getJoinSnakeDraftContestTx,
+  parseEvents,
+  pythPriceFeedIds,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { setSvmTimeTo } from "../helpers/time";
+import { Protocol } from "../../target/types/protocol";
+
+describe("snakeDraft", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let contestMetadataPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[];
+  let pythSolanaReceiver: PythSolanaReceiver;
+
+  const entryFee = BigInt(5 * UNITS_PER_USDC);
+  const fillDuration = 60 * 60;
+  const pricePostingWindow = 15 * 60;
+
+  const getBalance = (tokenAccount: Account) => {
+    const accInfo = svm.getAccount(tokenAccount.address);
+    return new BN(
+      unpackAccount(tokenAccount.address, accInfo as any).amount.toString()
+    );
+  };
+
+  const getRefundTx = (signerId: number, contest: web3.PublicKey) =>
+    pg.methods
+      .refundSnakeDraftContest()
+      .accounts({
+        signer: signers[signerId].publicKey,
+        config: configPda,
+        contest,
+        mint,
+        programTokenAccount: programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[signerId].address,
+        tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+      })
+      .transaction();
+
+  const pickToken = (signer: web3.Keypair, tokenIndex: number) =>
+    pg.methods
+      .pickSnakeDraftToken(tokenIndex)
+      .accounts({ signer: signer.publicKey, contest: contestPda })
+      .transaction();
+
+  const getContest = () => {
+    const contestAccInfo = svm.getAccount(contestPda);
+    return pg.coder.accounts.decode(
+      "snakeDraftContest",
+      Buffer.from(contestAccInfo.data)
+    );
+  };
+
+  before(async () => {
+    const res = await fixtureInitialization({ numSigners: 10 });
+
+    pg = res.program;
+    svm = res.svm;
+    signers = res.signers;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestMetadataPda = res.contestMetadataPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signerTokenAccounts = res.signerTokenAccounts;
+    pythSolanaReceiver = res.pythSolanaReceiver;
+  });
+
+  it("create a snake draft contest", async () => {
+    const signer = signers[0];
+
+    const res = await getCreateSnakeDraftContestTx({
+      svm,
+      program: pg,
+      signer,
+      contestMetadataPda,
+      pythSolanaReceiver,
+      contestParams: {
+        entryFee,
+        maxPlayers: 2,
+        picksPerPlayer: 2,
+        pickDuration: 60,
+        contestDuration: 60 * 60 * 24,
+        priceFeedIds: Object.values(pythPriceFeedIds),
+        rewardAllocation: [100],
+        fillDuration,
+      },
+    });
+    contestPda = res.contestPda;
+
+    const info = sendSvmTransaction(svm, signer, res.tx);
+    expect(parseEvents(pg, info).map((v) => v.name)).deep.equal([
+      "snakeDraftContestCreated",
+    ]);
+
+    const contest = getContest();
+    expect(contest.maxPlayers).equal(2);
+    expect(contest.tokenFeedIds.length).equal(
+      Object.values(pythPriceFeedIds).length
+    );
+    expect(contest.tokenOwners.every((owner) => owner === null)).equal(true);
+  });
+
+  it("join until the lobby is full", async () => {
+    for (let i = 0; i < 2; i++) {
+      const { tx } = await getJoinSnakeDraftContestTx({
+        svm,
+        program: pg,
+        signer: signers[i],
+        configPda,
+        contestPda,
+        mint,
+        programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[i],
+      });
+      const info = sendSvmTransaction(svm, signers[i], tx);
+
+      const events = parseEvents(pg, info);
+      expect(events.map((v) => v.name)).deep.equal(["snakeDraftPlayerJoined"]);
+      expect(events[0].data.playerId).equal(i);
+    }
+
+    const contest = getContest();
+    expect(contest.players.map((v) => v.toBase58())).deep.equal(
+      signers.slice(0, 2).map((v) => v.publicKey.toBase58())
+    );
+    expect(contest.pickDeadline.toNumber()).greaterThan(0);
+
+    const { tx } = await getJoinSnakeDraftContestTx({
+      svm,
+      program: pg,
+      signer: signers[2],
+      configPda,
+      contestPda,
+      mint,
+      programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[2],
+    });
+    expect(() => sendSvmTransaction(svm, signers[2], tx)).to.throw();
+  });
+
+  it("pick tokens in snake order", async () => {
+    // Picking out of turn is rejected
+    const outOfTurnTx = await pickToken(signers[1], 0);
+    expect(() => sendSvmTransaction(svm, signers[1], outOfTurnTx)).to.throw();
+
+    const info = sendSvmTransaction(
+      svm,
+      signers[0],
+      await pickToken(signers[0], 0)
+    );
+    const events = parseEvents(pg, info);
+    expect(events.map((v) => v.name)).deep.equal(["snakeDraftTokenPicked"]);
+    expect(events[0].data.isAutoPick).equal(false);
+
+    // A token can only be owned once
+    const ownedTx = await pickToken(signers[1], 0);
+    expect(() => sendSvmTransaction(svm, signers[1], ownedTx)).to.throw();
+
+    // The second player picks twice in a row at the turn of the round
+    sendSvmTransaction(svm, signers[1], await pickToken(signers[1], 2));
+    sendSvmTransaction(svm, signers[1], await pickToken(signers[1], 3));
+
+    const contest = getContest();
+    expect([...contest.picks]).deep.equal([0, 2, 3]);
+    expect(contest.tokenOwners.slice(0, 4)).deep.equal([0, null, 1, 1]);
+  });
+
+  it("auto-pick once the pick deadline passes", async () => {
+    const autoPick = () =>
+      pg.methods
+        .autoPickSnakeDraftToken()
+        .accounts({ signer: signers[2].publicKey, contest: contestPda })
+        .transaction();
+
+    // Auto-pick is rejected while the player still has time
+    const earlyTx = await autoPick();
+    expect(() => sendSvmTransaction(svm, signers[2], earlyTx)).to.throw();
+
+    // Pass the pick deadline
+    const clock = svm.getClock();
+    clock.unixTimestamp = BigInt(getContest().pickDeadline.toNumber() + 1);
+    svm.setClock(clock);
+
+    const info = sendSvmTransaction(svm, signers[2], await autoPick());
+    const events = parseEvents(pg, info);
+    expect(events.map((v) => v.name)).deep.equal(["snakeDraftTokenPicked"]);
+    expect(events[0].data.player.toBase58()).equal(
+      signers[0].publicKey.toBase58()
+    );
+    expect(events[0].data.isAutoPick).equal(true);
+
+    const contest = getContest();
+    expect([...contest.picks]).deep.equal([0, 2, 3, 1]);
+    expect(contest.tokenOwners[1]).equal(0);
+    expect(contest.startTime.toNumber()).equal(Number(clock.unixTimestamp));
+    expect(contest.endTime.toNumber()).equal(
+      Number(clock.unixTimestamp) + 60 * 60 * 24
+    );
+  });
+
+  it("refund both players once the posting window is missed", async () => {
+    const earlyTx = await getRefundTx(0, contestPda);
+    expect(() => sendSvmTransaction(svm, signers[0], earlyTx)).to.throw();
+
+    setSvmTimeTo(
+      svm,
+      getContest().startTime.toNumber() + pricePostingWindow + 1
+    );
+    svm.expireBlockhash();
+    for (let i = 0; i < 2; i++) {
+      const balance = getBalance(signerTokenAccounts[i]);
+      const tx = await getRefundTx(i, contestPda);
+      const info = sendSvmTransaction(svm, signers[i], tx);
+
+      expect(getBalance(signerTokenAccounts[i]).sub(balance).toString()).equal(
+        entryFee.toString()
+      );
+      const events = parseEvents(pg, info);
+      expect(events.map((v) => v.name)).deep.equal(["playerRefunded"]);
+    }
+    expect(getContest().hasClaimed).deep.equal([true, true]);
+
+    svm.expireBlockhash();
+    const replayTx = await getRefundTx(0, contestPda);
+    expect(() => sendSvmTransaction(svm, signers[0], replayTx)).to.throw();
+  });
+
+  it("refund a lobby that never fills", async () => {
+    const res = await getCreateSnakeDraftContestTx({
+      svm,
+      program: pg,
+      signer: signers[3],
+      contestMetadataPda,
+      pythSolanaReceiver,
+      contestParams: {
+        entryFee,
+        maxPlayers: 3,
+        picksPerPlayer: 1,
+        pickDuration: 60,
+        contestDuration: 60 * 60 * 24,
+        priceFeedIds: Object.values(pythPriceFeedIds),
+        rewardAllocation: [100],
+        fillDuration,
+      },
+    });
+    sendSvmTransaction(svm, signers[3], res.tx);
+    const { fillDeadline } = pg.coder.accounts.decode(
+      "snakeDraftContest",
+      Buffer.from(svm.getAccount(res.contestPda).data)
+    );
+
+    const { tx } = await getJoinSnakeDraftContestTx({
+      svm,
+      program: pg,
+      signer: signers[3],
+      configPda,
+      contestPda: res.contestPda,
+      mint,
+      programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[3],
+    });
+    sendSvmTransaction(svm, signers[3], tx);
+
+    const earlyTx = await getRefundTx(3, res.contestPda);
+    expect(() => sendSvmTransaction(svm, signers[3], earlyTx)).to.throw();
+
+    setSvmTimeTo(svm, fillDeadline.toNumber() + 1);
+
+    // Joining is closed once the fill deadline passes
+    const { tx: lateTx } = await getJoinSnakeDraftContestTx({
+      svm,
+      program: pg,
+      signer: signers[4],
+      configPda,
+      contestPda: res.contestPda,
+      mint,
+      programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[4],
+    });
+    expect(() => sendSvmTransaction(svm, signers[4], lateTx)).to.throw();
+
+    const balance = getBalance(signerTokenAccounts[3]);
+    svm.expireBlockhash();
+    sendSvmTransaction(svm, signers[3], await getRefundTx(3, res.contestPda));
+    expect(getBalance(signerTokenAccounts[3]).sub(balance).toString()).equal(
+      entryFee.toString()
+    );
+  });
+});
@@ -0,0 +1,227 @@
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { BN, Program, utils, web3 } from "@coral-xyz/anchor";
+import { PythSolanaReceiver } from "@pythnetwork/pyth-solana-receiver";
+import { Account, unpackAccount } from "@solana/spl-token";
+import { fixtureInitialization } from "../fixtures/svm";
+import {
+  getCreateSurvivorContestTx,
+  getEnterSurvivorContestTx,
+  parseEvents,
+  pythPriceFeedIds,
+  SEED_SURVIVOR_CONTEST_ENTRY,
+  sendSvmTransaction,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { setSvmTimeTo } from "../helpers/time";
+import { Protocol } from "../../target/types/protocol";
+
+describe("survivor", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let contestMetadataPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let contestCreditsPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[];
+  let pythSolanaReceiver: PythSolanaReceiver;
+
+  const currentTime = Math.floor(Date.now() / 1000);
+  const startTime = currentTime + 60 * 60; // 1 hour from now
+  const entryFee = BigInt(5 * UNITS_PER_USDC);
+  const pricePostingWindow = 15 * 60;
+
+  const getBalance = (tokenAccount: Account) => {
+    const accInfo = svm.getAccount(tokenAccount.address);
+    return new BN(
+      unpackAccount(tokenAccount.address, accInfo as any).amount.toString()
+    );
+  };
+
+  const getRefundTx = (signerId: number) => {
+    const signer = signers[signerId];
+    const [contestEntryPda] = web3.PublicKey.findProgramAddressSync(
+      [
+        SEED_SURVIVOR_CONTEST_ENTRY,
+        contestPda.toBuffer(),
+        signer.publicKey.toBuffer(),
+      ],
+      pg.programId
+    );
+    return pg.methods
+      .refundSurvivorContestEntry()
+      .accounts({
+        signer: signer.publicKey,
+        config: configPda,
+        contest: contestPda,
+        contestEntry: contestEntryPda,
+        mint,
+        programTokenAccount: programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[signerId].address,
+        tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+      })
+      .transaction();
+  };
+
+  before(async () => {
+    const res = await fixtureInitialization({ numSigners: 10 });
+
+    pg = res.program;
+    svm = res.svm;
+    signers = res.signers;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestMetadataPda = res.contestMetadataPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signerTokenAccounts = res.signerTokenAccounts;
+    pythSolanaReceiver = res.pythSolanaReceiver;
+  });
+
+  it("create a survivor contest", async () => {
+    const signer = signers[0];
+
+    const res = await getCreateSurvivorContestTx({
+      svm,
+      program: pg,
+      signer,
+      contestMetadataPda,
+      pythSolanaReceiver,
+      contestParams: {
+        startTime,
+        draftDuration: 60 * 60,
+        roundDuration: 60 * 60 * 24,
+        numRounds: 3,
+        eliminationPercent: 50,
+        entryFee,
+        maxEntries: 8,
+        priceFeedIds: [pythPriceFeedIds.bonk, pythPriceFeedIds.popcat],
+      },
+    });
+    contestPda = res.contestPda;
+    contestCreditsPda = res.contestCreditsPda;
+
+    const info = sendSvmTransaction(svm, signer, res.tx);
+    expect(parseEvents(pg, info).map((v) => v.name)).deep.equal([
+      "survivorContestCreated",
+    ]);
+
+    const contestAccInfo = svm.getAccount(contestPda);
+    const contest = pg.coder.accounts.decode(
+      "survivorContest",
+      Buffer.from(contestAccInfo.data)
+    );
+    expect(contest.numRounds).equal(3);
+    expect(contest.currentRound).equal(0);
+    expect(contest.roundStartTime.toNumber()).equal(startTime);
+    expect(contest.roundEndTime.toNumber()).equal(startTime + 60 * 60 * 24);
+  });
+
+  it("enter a survivor contest", async () => {
+    const allocations = [
+      [40, 60],
+      [100, 0],
+    ];
+
+    for (let i = 0; i < allocations.length; i++) {
+      const { tx } = await getEnterSurvivorContestTx({
+        svm,
+        program: pg,
+        signer: signers[i],
+        configPda,
+        contestPda,
+        mint,
+        programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[i],
+        creditAllocation: allocations[i],
+      });
+      const info = sendSvmTransaction(svm, signers[i], tx);
+
+      const events = parseEvents(pg, info);
+      expect(events.map((v) => v.name)).deep.equal(["survivorEntrySubmitted"]);
+      expect(events[0].data.entryId).equal(i);
+    }
+
+    const contestAccInfo = svm.getAccount(contestPda);
+    const contest = pg.coder.accounts.decode(
+      "survivorContest",
+      Buffer.from(contestAccInfo.data)
+    );
+    expect(contest.numSurvivors).equal(allocations.length);
+
+    const creditsAccInfo = svm.getAccount(contestCreditsPda);
+    const contestCredits = pg.coder.accounts.decode(
+      "survivorContestCredits",
+      Buffer.from(creditsAccInfo.data)
+    );
+    expect(contestCredits.isAlive).deep.equal([true, true]);
+    expect([...contestCredits.creditAllocations]).deep.equal(
+      allocations.flat()
+    );
+  });
+
+  it("re-draft before the round starts", async () => {
+    const signer = signers[0];
+    const tx = await pg.methods
+      .redraftSurvivorContest([70, 30])
+      .accounts({
+        signer: signer.publicKey,
+        contest: contestPda,
+      })
+      .transaction();
+
+    const info = sendSvmTransaction(svm, signer, tx);
+    const events = parseEvents(pg, info);
+    expect(events.map((v) => v.name)).deep.equal(["survivorEntryRedrafted"]);
+    expect(events[0].data.round).equal(0);
+
+    const creditsAccInfo = svm.getAccount(contestCreditsPda);
+    const contestCredits = pg.coder.accounts.decode(
+      "survivorContestCredits",
+      Buffer.from(creditsAccInfo.data)
+    );
+    expect([...contestCredits.creditAllocations]).deep.equal([40, 60, 100, 0]);
+    expect(contestCredits.redraftEntryIds).deep.equal([0]);
+    expect([...contestCredits.redraftRounds]).deep.equal([0]);
+    expect([...contestCredits.redraftAllocations]).deep.equal([70, 30]);
+  });
+
+  it("reject an invalid re-draft", async () => {
+    const signer = signers[1];
+    const tx = await pg.methods
+      .redraftSurvivorContest([50, 40])
+      .accounts({
+        signer: signer.publicKey,
+        contest: contestPda,
+      })
+      .transaction();
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+
+  it("refund every entry once the round prices are missed", async () => {
+    setSvmTimeTo(svm, startTime + 1);
+    const earlyTx = await getRefundTx(0);
+    expect(() => sendSvmTransaction(svm, signers[0], earlyTx)).to.throw();
+
+    setSvmTimeTo(svm, startTime + pricePostingWindow + 1);
+    svm.expireBlockhash();
+    for (let i = 0; i < 2; i++) {
+      const balance = getBalance(signerTokenAccounts[i]);
+      const info = sendSvmTransaction(svm, signers[i], await getRefundTx(i));
+
+      expect(getBalance(signerTokenAccounts[i]).sub(balance).toString()).equal(
+        entryFee.toString()
+      );
+      const events = parseEvents(pg, info);
+      expect(events.map((v) => v.name)).deep.equal(["entryRefunded"]);
+    }
+
+    svm.expireBlockhash();
+    const replayTx = await getRefundTx(0);
+    expect(() => sendSvmTransaction(svm, signers[0], replayTx)).to.throw();
+  });
+});
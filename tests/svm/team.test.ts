@@ -0,0 +1,185 @@
+import { expect } from "chai";
+import { LiteSVM } from "litesvm";
+import { web3 } from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Account } from "@solana/spl-token";
+import { fixtureWithContest } from "../fixtures/svm";
+import {
+  getEnterContestTx,
+  parseEvents,
+  pythPriceFeedIds,
+  sendSvmTransaction,
+  SEED_TEAM,
+  UNITS_PER_USDC,
+} from "../helpers";
+import { Protocol } from "../../target/types/protocol";
+
+const { PublicKey } = web3;
+
+describe("team", () => {
+  let svm: LiteSVM;
+  let pg: Program<Protocol>;
+
+  let mint: web3.PublicKey;
+  let configPda: web3.PublicKey;
+  let programTokenAccountPda: web3.PublicKey;
+  let contestPda: web3.PublicKey;
+  let contestCreditsPda: web3.PublicKey;
+  let teamPda: web3.PublicKey;
+  let signers: web3.Keypair[];
+  let signerTokenAccounts: Account[];
+
+  before(async () => {
+    const currentTime = Math.floor(Date.now() / 1000);
+    const startTime = currentTime + 60 * 60; // 1 hour from now
+    const endTime = startTime + 60 * 60 * 24; // 1 day from now
+    const contestParams = {
+      startTime,
+      endTime,
+      entryFee: BigInt(10 * UNITS_PER_USDC),
+      maxEntries: 100,
+      priceFeedIds: [pythPriceFeedIds.bonk, pythPriceFeedIds.popcat],
+      rewardAllocation: [50, 50],
+      teamConfig: { maxTeamSize: 2, scoring: { average: {} } },
+    };
+    const res = await fixtureWithContest({ contestParams, numSigners: 10 });
+
+    pg = res.program;
+    svm = res.svm;
+    signers = res.signers;
+    mint = res.mint;
+    configPda = res.configPda;
+    contestPda = res.contestPda;
+    contestCreditsPda = res.contestCreditsPda;
+    programTokenAccountPda = res.programTokenAccountPda;
+    signerTokenAccounts = res.signerTokenAccounts;
+  });
+
+  it("reject a team larger than the contest allows", async () => {
+    const signer = signers[2];
+    const tx = await pg.methods
+      .createTeam(
+        [signers[2], signers[3], signers[4]].map((v) => v.publicKey),
+        Buffer.from([40, 30, 30])
+      )
+      .accounts({ signer: signer.publicKey, contest: contestPda })
+      .transaction();
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+
+  it("create a team", async () => {
+    const signer = signers[0];
+    const members = [signers[0].publicKey, signers[1].publicKey];
+    const tx = await pg.methods
+      .createTeam(members, Buffer.from([60, 40]))
+      .accounts({ signer: signer.publicKey, contest: contestPda })
+      .transaction();
+
+    const info = sendSvmTransaction(svm, signer, tx);
+
+    [teamPda] = PublicKey.findProgramAddressSync(
+      [SEED_TEAM, contestPda.toBuffer(), signer.publicKey.toBuffer()],
+      pg.programId
+    );
+    const events = parseEvents(pg, info);
+    expect(events.map((v) => v.name)).deep.equal(["teamCreated"]);
+    expect(events[0].data.team.toBase58()).equal(teamPda.toBase58());
+    expect([...events[0].data.memberWeights]).deep.equal([60, 40]);
+    const teamAccInfo = svm.getAccount(teamPda);
+    const team = pg.coder.accounts.decode("team", Buffer.from(teamAccInfo.data));
+    expect(team.id).equal(0);
+    expect(team.members.map((v) => v.toBase58())).deep.equal(
+      members.map((v) => v.toBase58())
+    );
+    expect([...team.memberWeights]).deep.equal([60, 40]);
+  });
+
+  it("reject entering a team contest without a team", async () => {
+    const signer = signers[2];
+    const { tx } = await getEnterContestTx({
+      svm,
+      program: pg,
+      signer,
+      configPda,
+      contestPda,
+      mint,
+      programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[2],
+      creditAllocation: [50, 50],
+    });
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+
+  it("reject entering through another team", async () => {
+    const signer = signers[2];
+    const { tx } = await getEnterContestTx({
+      svm,
+      program: pg,
+      signer,
+      configPda,
+      contestPda,
+      mint,
+      programTokenAccountPda,
+      signerTokenAccount: signerTokenAccounts[2],
+      creditAllocation: [50, 50],
+      teamPda,
+    });
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+
+  it("enter as team members", async () => {
+    for (let i = 0; i < 2; i++) {
+      const { tx, contestEntryPda } = await getEnterContestTx({
+        svm,
+        program: pg,
+        signer: signers[i],
+        configPda,
+        contestPda,
+        mint,
+        programTokenAccountPda,
+        signerTokenAccount: signerTokenAccounts[i],
+        creditAllocation: [30, 70],
+        teamPda,
+      });
+      sendSvmTransaction(svm, signers[i], tx);
+
+      const entryAccInfo = svm.getAccount(contestEntryPda);
+      const entry = pg.coder.accounts.decode(
+        "tokenDraftContestEntry",
+        Buffer.from(entryAccInfo.data)
+      );
+      expect(entry.teamId).equal(0);
+
+      // The prize is split over the weights of the members who entered
+      const team = pg.coder.accounts.decode(
+        "team",
+        Buffer.from(svm.getAccount(teamPda).data)
+      );
+      expect(team.enteredWeight).equal([60, 100][i]);
+    }
+
+    const creditsAccInfo = svm.getAccount(contestCreditsPda);
+    const contestCredits = pg.coder.accounts.decode(
+      "tokenDraftContestCredits",
+      Buffer.from(creditsAccInfo.data)
+    );
+    expect(contestCredits.entryTeams).deep.equal([0, 0]);
+  });
+
+  it("reject closing a team while its contest is open", async () => {
+    const signer = signers[0];
+    const tx = await pg.methods
+      .closeTeam()
+      .accounts({
+        signer: signer.publicKey,
+        contest: contestPda,
+        team: teamPda,
+      })
+      .transaction();
+
+    expect(() => sendSvmTransaction(svm, signer, tx)).to.throw();
+  });
+});
@@ -91,7 +91,7 @@ describe("withdrawFee", () => {
       sendSvmTransaction(svm, signers[i], tx);
     }
 
-    setSvmTimeTo(svm, contestParams.endTime + 1);
+    setSvmTimeTo(svm, contestParams.startTime + 1);
 
     // Post prices
     const { txs: txsPostPrices } = await getPostPricesTxs({
@@ -106,6 +106,8 @@ describe("withdrawFee", () => {
       sendSvmTransaction(svm, signers[0], tx);
     }
 
+    setSvmTimeTo(svm, contestParams.endTime + 1);
+
     // Resolve contest
     const { txs: txResolve } = await getResolveContestTx({
       svm,
@@ -17,7 +17,12 @@ import {
 } from "@solana/spl-token";
 import { HermesClient } from "@pythnetwork/hermes-client";
 import { Protocol } from "../target/types/protocol";
-import { enterContest, pythPriceFeedIds, UNITS_PER_USDC } from "./helpers";
+import {
+  enterContest,
+  getFeedAccountMetas,
+  pythPriceFeedIds,
+  UNITS_PER_USDC,
+} from "./helpers";
 import { fixtureWithContest } from "./fixtures";
 import { expect } from "chai";
 
@@ -129,17 +134,13 @@ describe.skip("withdrawFee", () => {
           mint,
           escrowTokenAccount: escrowTokenAccountPda,
           feeTokenAccount: feeTokenAccountPda,
-          feed0: priceUpdateAccounts[0],
-          feed1: priceUpdateAccounts[1] || null,
-          feed2: priceUpdateAccounts[2] || null,
-          feed3: priceUpdateAccounts[3] || null,
-          feed4: priceUpdateAccounts[4] || null,
           tokenProgram: utils.token.TOKEN_PROGRAM_ID,
         };
 
         const txInstruction = await pg.methods
           .resolveTokenDraftContest()
           .accounts(accounts)
+          .remainingAccounts(getFeedAccountMetas(priceUpdateAccounts))
           .instruction();
 
         const instruction: InstructionWithEphemeralSigners = {